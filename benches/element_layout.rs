@@ -0,0 +1,85 @@
+//! Reports the in-memory size of the `Element` model's types and measures the cost of looking up
+//! struct fields by name at a variety of struct sizes.
+//!
+//! Run with `cargo bench --bench element_layout`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ion_rs::element::{Annotations, Element, Sequence, Struct, Value};
+use ion_rs::{Decimal, Int, Str, Symbol, Timestamp};
+use std::mem::size_of;
+
+// The sizes the `Element` model is laid out for on 64-bit targets, which the `element_layout`
+// unit test asserts. `Int` stores its `i128` as two `u64` halves so that it is only 8-byte
+// aligned; a 16-byte aligned `Int` would pad `Value` to 32 bytes and `Element` to 64.
+const INTENDED_ELEMENT_SIZE: usize = 48;
+const INTENDED_VALUE_SIZE: usize = 24;
+const INTENDED_INT_SIZE: usize = 24;
+
+fn report_type_sizes() {
+    println!("Type sizes (bytes):");
+    println!(
+        "  Element:     {:>3} (intended: {INTENDED_ELEMENT_SIZE})",
+        size_of::<Element>()
+    );
+    println!(
+        "  Value:       {:>3} (intended: {INTENDED_VALUE_SIZE})",
+        size_of::<Value>()
+    );
+    println!("  Annotations: {:>3}", size_of::<Annotations>());
+    println!("  Struct:      {:>3}", size_of::<Struct>());
+    println!("  Sequence:    {:>3}", size_of::<Sequence>());
+    println!("  Symbol:      {:>3}", size_of::<Symbol>());
+    println!("  Str:         {:>3}", size_of::<Str>());
+    println!(
+        "  Int:         {:>3} (intended: {INTENDED_INT_SIZE})",
+        size_of::<Int>()
+    );
+    println!(
+        "  Decimal:     {:>3} (boxed in Value)",
+        size_of::<Decimal>()
+    );
+    println!(
+        "  Timestamp:   {:>3} (boxed in Value)",
+        size_of::<Timestamp>()
+    );
+}
+
+fn struct_lookups(c: &mut Criterion) {
+    report_type_sizes();
+
+    let mut group = c.benchmark_group("struct_get");
+    for num_fields in [2usize, 4, 8, 9, 16, 64] {
+        let s: Struct = (0..num_fields)
+            .map(|i| (format!("field_{i}"), Element::integer(i as i64)))
+            .collect();
+        let last_field = format!("field_{}", num_fields - 1);
+        group.bench_with_input(BenchmarkId::new("last_field", num_fields), &s, |b, s| {
+            b.iter(|| s.get(black_box(last_field.as_str())))
+        });
+        group.bench_with_input(BenchmarkId::new("missing_field", num_fields), &s, |b, s| {
+            b.iter(|| s.get(black_box("missing")))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("struct_build");
+    for num_fields in [2usize, 8, 64] {
+        let fields: Vec<(Symbol, Element)> = (0..num_fields)
+            .map(|i| {
+                (
+                    Symbol::owned(format!("field_{i}")),
+                    Element::integer(i as i64),
+                )
+            })
+            .collect();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(num_fields),
+            &fields,
+            |b, fields| b.iter(|| fields.iter().cloned().collect::<Struct>()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, struct_lookups);
+criterion_main!(benches);
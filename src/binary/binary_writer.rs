@@ -1,33 +1,206 @@
+#[cfg(feature = "std")]
+use crate::binary::buffer_pool::BufferPool;
 use crate::binary::raw_binary_writer::{RawBinaryWriter, RawBinaryWriterBuilder};
+use crate::binary::spill::SpillStorage;
 use crate::constants::v1_0::system_symbol_ids;
+use crate::io::Write;
 use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
 use crate::result::{illegal_operation, IonResult};
-use crate::types::{Decimal, Int, IonType, SymbolId, Timestamp};
+use crate::types::{Decimal, FloatWidth, Int, IonType, SymbolHandle, SymbolId, Timestamp};
 use crate::writer::IonWriter;
-use crate::SymbolTable;
+use crate::{SharedSymbolTable, SymbolTable};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem;
+use core::sync::atomic::{AtomicUsize, Ordering};
 use delegate::delegate;
-use std::io::Write;
+use smallvec::SmallVec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(feature = "std")]
+type SymbolValueCounts = HashMap<String, usize>;
+#[cfg(not(feature = "std"))]
+type SymbolValueCounts = alloc::collections::BTreeMap<String, usize>;
+
+// The largest number of distinct symbol values that the writer will count while deciding whether
+// to add them to the symbol table. When it is reached, the counts start over.
+const MAX_COUNTED_SYMBOL_VALUES: usize = 1024;
+
+// Each local symbol table that a BinaryWriter starts is assigned the next epoch from this counter,
+// which is shared by all writers so that a handle cannot be used with a writer other than the
+// one that registered it.
+static NEXT_SYMBOL_TABLE_EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+fn next_symbol_table_epoch() -> usize {
+    NEXT_SYMBOL_TABLE_EPOCH.fetch_add(1, Ordering::Relaxed)
+}
+
+/// How a [`BinaryWriter`] starts over with a new local symbol table when one of the policies
+/// configured on its [`BinaryWriterBuilder`] calls for it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SymbolTableReset {
+    /// Writes a local symbol table that replaces the current one instead of appending to it.
+    /// The new table imports the same shared symbol tables as the old one.
+    #[default]
+    NewTable,
+    /// Writes an Ion version marker, which resets the symbol table to the system symbol table.
+    /// If the writer imports any shared symbol tables, the marker is followed by a local symbol
+    /// table that imports them.
+    VersionMarker,
+}
+
+// The settings that control when a BinaryWriter resets its local symbol table.
+#[derive(Clone, Copy, Debug, Default)]
+struct SymbolTablePolicies {
+    max_local_symbols: Option<usize>,
+    reset_interval: Option<usize>,
+    reset: SymbolTableReset,
+    min_symbol_value_occurrences: usize,
+}
+
+impl SymbolTablePolicies {
+    // Returns true if the writer needs to count the top-level values it writes.
+    fn apply_at_top_level(&self) -> bool {
+        self.max_local_symbols.is_some() || self.reset_interval.is_some()
+    }
+}
 
 pub struct BinaryWriterBuilder {
-    // Nothing yet
+    imports: Vec<SharedSymbolTable>,
+    raw_writer_builder: RawBinaryWriterBuilder,
+    #[cfg(feature = "std")]
+    buffer_pool: Option<BufferPool>,
+    symbol_table_policies: SymbolTablePolicies,
 }
 
 impl BinaryWriterBuilder {
     pub fn new() -> Self {
-        BinaryWriterBuilder {}
+        BinaryWriterBuilder {
+            imports: Vec::new(),
+            raw_writer_builder: RawBinaryWriterBuilder::new(),
+            #[cfg(feature = "std")]
+            buffer_pool: None,
+            symbol_table_policies: SymbolTablePolicies::default(),
+        }
+    }
+
+    /// Imports the symbols of `table` into the stream's local symbol table. The symbols defined
+    /// by `table` will be written using their imported symbol IDs, including symbols with
+    /// unknown text whose [`ImportLocation`](crate::types::ImportLocation) is in `table`.
+    /// Readers of the stream will need access to `table` to resolve the text of those symbols.
+    ///
+    /// This can be called more than once; tables are imported in the order they are added.
+    pub fn with_import(mut self, table: SharedSymbolTable) -> Self {
+        self.imports.push(table);
+        self
+    }
+
+    /// Limits the amount of encoded data that the writer buffers in memory while writing a large
+    /// top-level value by moving it to `storage`. See
+    /// [`RawBinaryWriterBuilder::with_spill_storage`] for details.
+    pub fn with_spill_storage<S>(mut self, max_buffer_size: usize, storage: S) -> Self
+    where
+        S: SpillStorage + Send + Sync + 'static,
+    {
+        self.raw_writer_builder = self
+            .raw_writer_builder
+            .with_spill_storage(max_buffer_size, storage);
+        self
+    }
+
+    /// Causes the writer to take its encoding buffers from `pool` and to return them to `pool`
+    /// when it is dropped. See [`BufferPool`] for details.
+    #[cfg(feature = "std")]
+    pub fn with_buffer_pool(mut self, pool: BufferPool) -> Self {
+        self.raw_writer_builder = self.raw_writer_builder.with_buffer_pool(pool.clone());
+        self.buffer_pool = Some(pool);
+        self
+    }
+
+    /// Causes `flush()` to write to the sink using [`write_vectored`](std::io::Write::write_vectored).
+    /// See [`RawBinaryWriterBuilder::with_vectored_writes`] for details.
+    #[cfg(feature = "std")]
+    pub fn with_vectored_writes(mut self, enabled: bool) -> Self {
+        self.raw_writer_builder = self.raw_writer_builder.with_vectored_writes(enabled);
+        self
+    }
+
+    /// Causes the writer to reset its local symbol table once the table defines more than
+    /// `max_symbols` symbols, not counting system and imported symbols. The table is reset after
+    /// the top-level value that exceeded the limit is complete, so a single value can still use
+    /// any number of symbols.
+    ///
+    /// Symbol IDs are only valid until the table is reset, so resetting it flushes the writer.
+    pub fn with_max_local_symbols(mut self, max_symbols: usize) -> Self {
+        self.symbol_table_policies.max_local_symbols = Some(max_symbols);
+        self
+    }
+
+    /// Causes the writer to reset its local symbol table after every `values` top-level values.
+    /// In addition to bounding the size of the table, this gives readers that start partway
+    /// through the stream regular places from which they can begin reading it.
+    ///
+    /// Symbol IDs are only valid until the table is reset, so resetting it flushes the writer.
+    pub fn with_symbol_table_reset_interval(mut self, values: usize) -> Self {
+        self.symbol_table_policies.reset_interval = Some(values.max(1));
+        self
+    }
+
+    /// Sets how the writer resets its local symbol table when [`with_max_local_symbols`] or
+    /// [`with_symbol_table_reset_interval`] calls for it. The default is
+    /// [`SymbolTableReset::NewTable`].
+    ///
+    /// [`with_max_local_symbols`]: Self::with_max_local_symbols
+    /// [`with_symbol_table_reset_interval`]: Self::with_symbol_table_reset_interval
+    pub fn with_symbol_table_reset(mut self, reset: SymbolTableReset) -> Self {
+        self.symbol_table_policies.reset = reset;
+        self
+    }
+
+    /// Causes the writer to write each symbol value as a string until its text has been written
+    /// `min_occurrences` times since the local symbol table was last reset, and only then to add
+    /// it to the table. This keeps text that appears rarely, such as IDs that were written as
+    /// symbols by mistake, from filling up the symbol table.
+    ///
+    /// Note that this changes the Ion type of those values from symbol to string, so it should
+    /// only be used if the stream's readers treat the two alike. Field names and annotations are
+    /// always added to the symbol table.
+    pub fn with_rare_symbol_values_as_strings(mut self, min_occurrences: usize) -> Self {
+        self.symbol_table_policies.min_symbol_value_occurrences = min_occurrences;
+        self
     }
 
     pub fn build<W: Write>(self, sink: W) -> IonResult<BinaryWriter<W>> {
-        let mut raw_writer = RawBinaryWriterBuilder::new().build(sink)?;
-        let symbol_table_writer = RawBinaryWriterBuilder::new().build(Vec::new())?;
+        let symbol_table_writer_builder = RawBinaryWriterBuilder::new();
+        // The symbol table writer's buffers come from the same pool as the raw writer's.
+        #[cfg(feature = "std")]
+        let symbol_table_writer_builder = match self.buffer_pool {
+            Some(pool) => symbol_table_writer_builder.with_buffer_pool(pool),
+            None => symbol_table_writer_builder,
+        };
+        let mut raw_writer = self.raw_writer_builder.build(sink)?;
+        let symbol_table_writer = symbol_table_writer_builder.build(Vec::new())?;
         // TODO: Track whether we've written an IVM and emit it at flush time instead
         raw_writer.write_ion_version_marker(1, 0)?;
-        let binary_writer = BinaryWriter {
+        let mut binary_writer = BinaryWriter {
             raw_writer,
             symbol_table: Default::default(),
             num_pending_symbols: 0,
             symbol_table_writer,
+            imports: Vec::new(),
+            first_local_symbol_id: 0,
+            policies: self.symbol_table_policies,
+            top_level_values_since_reset: 0,
+            symbol_value_counts: SymbolValueCounts::new(),
+            symbol_table_epoch: next_symbol_table_epoch(),
         };
+        if !self.imports.is_empty() {
+            binary_writer.write_symbol_table_with_imports(&self.imports)?;
+        }
+        binary_writer.imports = self.imports;
+        binary_writer.first_local_symbol_id = binary_writer.symbol_table.len();
         Ok(binary_writer)
     }
 }
@@ -53,9 +226,55 @@ pub struct BinaryWriter<W: Write> {
     // that any symbols referenced in the `raw_writer`'s contents will be defined in the Ion stream
     // before the reference appears.
     symbol_table_writer: RawBinaryWriter<Vec<u8>>,
+    // The shared symbol tables that every local symbol table written by this writer imports.
+    imports: Vec<SharedSymbolTable>,
+    // The ID of the first symbol defined by the current local symbol table.
+    first_local_symbol_id: SymbolId,
+    policies: SymbolTablePolicies,
+    top_level_values_since_reset: usize,
+    // The number of times that symbol values whose text is not in the symbol table have been
+    // written. Only used if `policies.min_symbol_value_occurrences` is set.
+    symbol_value_counts: SymbolValueCounts,
+    // Identifies the current local symbol table. Handles registered with an earlier table are
+    // rejected.
+    symbol_table_epoch: usize,
 }
 
 impl<W: Write> BinaryWriter<W> {
+    /// Adds `text` to the local symbol table if it is not already there and returns a
+    /// [`SymbolHandle`] for it. Writing the handle instead of the text skips looking the text up
+    /// in the symbol table each time it is written.
+    ///
+    /// ```
+    /// use ion_rs::{BinaryWriterBuilder, IonResult, IonType, IonWriter};
+    /// # fn main() -> IonResult<()> {
+    /// let mut writer = BinaryWriterBuilder::new().build(Vec::new())?;
+    /// let name = writer.register_symbol("name");
+    /// let id = writer.register_symbol("id");
+    /// for (i, person) in ["Alice", "Bob"].iter().enumerate() {
+    ///     writer.step_in(IonType::Struct)?;
+    ///     writer.set_field_name(name)?;
+    ///     writer.write_string(person)?;
+    ///     writer.set_field_name(id)?;
+    ///     writer.write_i64(i as i64)?;
+    ///     writer.step_out()?;
+    /// }
+    /// writer.flush()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_symbol<A: AsRef<str>>(&mut self, text: A) -> SymbolHandle {
+        let symbol_id = self.get_or_create_symbol_id(text.as_ref());
+        SymbolHandle::new(symbol_id, self.symbol_table_epoch)
+    }
+
+    /// Returns `true` if `handle` was registered with this writer's current local symbol table.
+    /// Handles are invalidated when the writer resets its symbol table and must be registered
+    /// again.
+    pub fn symbol_handle_is_valid(&self, handle: SymbolHandle) -> bool {
+        handle.epoch() == self.symbol_table_epoch
+    }
+
     fn get_or_create_symbol_id(&mut self, text: &str) -> SymbolId {
         if let Some(symbol_id) = self.symbol_table.sid_for(&text) {
             // If the provided text is in the symbol table, use the associated symbol ID...
@@ -67,6 +286,68 @@ impl<W: Write> BinaryWriter<W> {
         }
     }
 
+    /// If `token` has unknown text and its import location is in one of the shared symbol tables
+    /// that this writer imports, returns the corresponding symbol ID.
+    fn imported_symbol_id<A: AsRawSymbolTokenRef>(&self, token: &A) -> Option<SymbolId> {
+        token
+            .import_location()
+            .and_then(|location| self.symbol_table.sid_for_import_location(location))
+    }
+
+    /// If `token` is a [`SymbolHandle`] or an imported symbol, returns its symbol ID without
+    /// looking up its text. Returns an error if `token` is a handle that is no longer valid.
+    fn known_symbol_id<A: AsRawSymbolTokenRef>(&self, token: &A) -> IonResult<Option<SymbolId>> {
+        if let Some(&handle) = token.symbol_handle() {
+            if !self.symbol_handle_is_valid(handle) {
+                return illegal_operation(format!(
+                    "The handle for symbol ID ${} was registered with a different symbol table.",
+                    handle.symbol_id()
+                ));
+            }
+            return Ok(Some(handle.symbol_id()));
+        }
+        Ok(self.imported_symbol_id(token))
+    }
+
+    /// Writes a local symbol table that imports each of the provided shared symbol tables and
+    /// adds their symbols to the writer's symbol table.
+    fn write_symbol_table_with_imports(&mut self, imports: &[SharedSymbolTable]) -> IonResult<()> {
+        self.symbol_table_writer
+            .add_annotation(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        self.symbol_table_writer.step_in(IonType::Struct)?;
+        self.symbol_table_writer
+            .set_field_name(system_symbol_ids::IMPORTS)?;
+        self.symbol_table_writer.step_in(IonType::List)?;
+        for table in imports {
+            let max_id = table.symbols().len();
+            self.symbol_table_writer.step_in(IonType::Struct)?;
+            self.symbol_table_writer
+                .set_field_name(system_symbol_ids::NAME)?;
+            self.symbol_table_writer.write_string(table.name())?;
+            self.symbol_table_writer
+                .set_field_name(system_symbol_ids::VERSION)?;
+            self.symbol_table_writer.write_u64(table.version() as u64)?;
+            self.symbol_table_writer
+                .set_field_name(system_symbol_ids::MAX_ID)?;
+            self.symbol_table_writer.write_u64(max_id as u64)?;
+            self.symbol_table_writer.step_out()?;
+            self.symbol_table
+                .add_import(table.name(), max_id, table.symbols());
+        }
+        self.symbol_table_writer.step_out()?; // End imports list
+        self.symbol_table_writer.step_out()?; // End $ion_symbol_table::{...}
+        self.flush_symbol_table_writer()
+    }
+
+    // Writes the symbol_table_writer's encoded bytes to the raw_writer's output
+    fn flush_symbol_table_writer(&mut self) -> IonResult<()> {
+        self.symbol_table_writer.flush()?;
+        let bytes = &self.symbol_table_writer.output()[..];
+        self.raw_writer.output_mut().write_all(bytes)?;
+        self.symbol_table_writer.output_mut().clear();
+        Ok(())
+    }
+
     fn write_symbol_table_for_pending_symbols(&mut self) -> IonResult<()> {
         let pending_symbols_starting_index = self.symbol_table.len() - self.num_pending_symbols;
         let pending_symbols = self
@@ -74,16 +355,16 @@ impl<W: Write> BinaryWriter<W> {
             .symbols_tail(pending_symbols_starting_index);
 
         self.symbol_table_writer
-            .add_annotation(system_symbol_ids::ION_SYMBOL_TABLE);
+            .add_annotation(system_symbol_ids::ION_SYMBOL_TABLE)?;
         self.symbol_table_writer.step_in(IonType::Struct)?;
 
         self.symbol_table_writer
-            .set_field_name(system_symbol_ids::IMPORTS);
+            .set_field_name(system_symbol_ids::IMPORTS)?;
         self.symbol_table_writer
             .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
 
         self.symbol_table_writer
-            .set_field_name(system_symbol_ids::SYMBOLS);
+            .set_field_name(system_symbol_ids::SYMBOLS)?;
         self.symbol_table_writer.step_in(IonType::List)?;
         for symbol in pending_symbols {
             match symbol.text() {
@@ -94,15 +375,70 @@ impl<W: Write> BinaryWriter<W> {
         self.symbol_table_writer.step_out()?; // End symbols list
 
         self.symbol_table_writer.step_out()?; // End $ion_symbol_table::{...}
-        self.symbol_table_writer.flush()?;
+        self.flush_symbol_table_writer()
+    }
 
-        // Write the symbol_table_writer's encoded bytes to the raw_writer's output
-        let bytes = &self.symbol_table_writer.output()[..];
-        self.raw_writer.output_mut().write_all(bytes)?;
-        self.symbol_table_writer.output_mut().clear();
+    // Called after each value is written. If the value was at the top level, resets the symbol
+    // table if the configured policies call for it.
+    fn value_written(&mut self) -> IonResult<()> {
+        if self.depth() > 0 || !self.policies.apply_at_top_level() {
+            return Ok(());
+        }
+        self.top_level_values_since_reset += 1;
+        let num_local_symbols = self.symbol_table.len() - self.first_local_symbol_id;
+        let table_is_full =
+            matches!(self.policies.max_local_symbols, Some(max) if num_local_symbols > max);
+        let interval_has_elapsed = matches!(self.policies.reset_interval, Some(values) if self.top_level_values_since_reset >= values);
+        if table_is_full || interval_has_elapsed {
+            self.reset_symbol_table()?;
+        }
+        Ok(())
+    }
 
+    // Flushes the values that use the current local symbol table, then starts a new one.
+    fn reset_symbol_table(&mut self) -> IonResult<()> {
+        self.flush()?;
+        self.symbol_table.reset();
+        self.symbol_value_counts.clear();
+        self.top_level_values_since_reset = 0;
+        if self.policies.reset == SymbolTableReset::VersionMarker {
+            self.raw_writer.write_ion_version_marker(1, 0)?;
+        }
+        // A version marker already resets the table, so it only needs to be followed by a new
+        // table if there are imports.
+        if self.policies.reset == SymbolTableReset::NewTable || !self.imports.is_empty() {
+            let imports = mem::take(&mut self.imports);
+            let result = self.write_symbol_table_with_imports(&imports);
+            self.imports = imports;
+            result?;
+        }
+        self.first_local_symbol_id = self.symbol_table.len();
+        self.symbol_table_epoch = next_symbol_table_epoch();
         Ok(())
     }
+
+    // Returns true if a symbol value with the given text should be written as a string because
+    // it has not been written often enough to add to the symbol table.
+    fn is_rare_symbol_value(&mut self, text: &str) -> bool {
+        if self.policies.min_symbol_value_occurrences <= 1
+            || self.symbol_table.sid_for(&text).is_some()
+        {
+            return false;
+        }
+        if let Some(count) = self.symbol_value_counts.get_mut(text) {
+            *count += 1;
+            if *count < self.policies.min_symbol_value_occurrences {
+                return true;
+            }
+            self.symbol_value_counts.remove(text);
+            return false;
+        }
+        if self.symbol_value_counts.len() >= MAX_COUNTED_SYMBOL_VALUES {
+            self.symbol_value_counts.clear();
+        }
+        self.symbol_value_counts.insert(String::from(text), 1);
+        true
+    }
 }
 
 impl<W: Write> IonWriter for BinaryWriter<W> {
@@ -114,27 +450,45 @@ impl<W: Write> IonWriter for BinaryWriter<W> {
         true
     }
 
-    fn set_annotations<I, A>(&mut self, annotations: I)
+    fn set_annotations<I, A>(&mut self, annotations: I) -> IonResult<()>
     where
         A: AsRawSymbolTokenRef,
         I: IntoIterator<Item = A>,
     {
-        for annotation in annotations {
-            let symbol_id = match annotation.as_raw_symbol_token_ref() {
-                RawSymbolTokenRef::SymbolId(symbol_id) => {
-                    if self.symbol_table.sid_is_valid(symbol_id) {
-                        symbol_id
-                    } else {
-                        panic!("Cannot set symbol ID ${symbol_id} as annotation. It is undefined.");
-                    }
+        // Validate all of the annotations before applying any of them so that an invalid
+        // annotation does not leave the next value partially annotated, and before interning any
+        // of them so that it does not leave unused symbols in the symbol table.
+        let annotations: SmallVec<[A; 4]> = annotations.into_iter().collect();
+        for annotation in &annotations {
+            if self.known_symbol_id(annotation)?.is_some() {
+                continue;
+            }
+            if let RawSymbolTokenRef::SymbolId(symbol_id) = annotation.as_raw_symbol_token_ref() {
+                if !self.symbol_table.sid_is_valid(symbol_id) {
+                    return illegal_operation(format!(
+                        "Cannot set symbol ID ${symbol_id} as annotation. It is undefined."
+                    ));
                 }
-                RawSymbolTokenRef::Text(text) => self.get_or_create_symbol_id(text),
+            }
+        }
+        for annotation in &annotations {
+            let symbol_id = match self.known_symbol_id(annotation)? {
+                Some(symbol_id) => symbol_id,
+                None => match annotation.as_raw_symbol_token_ref() {
+                    RawSymbolTokenRef::SymbolId(symbol_id) => symbol_id,
+                    RawSymbolTokenRef::Text(text) => self.get_or_create_symbol_id(text),
+                },
             };
-            self.raw_writer.add_annotation(symbol_id);
+            self.raw_writer.add_annotation(symbol_id)?;
         }
+        Ok(())
     }
 
     fn write_symbol<A: AsRawSymbolTokenRef>(&mut self, value: A) -> IonResult<()> {
+        if let Some(symbol_id) = self.known_symbol_id(&value)? {
+            self.raw_writer.write_symbol(symbol_id)?;
+            return self.value_written();
+        }
         let symbol_id = match value.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(symbol_id) => {
                 if self.symbol_table.sid_is_valid(symbol_id) {
@@ -145,23 +499,38 @@ impl<W: Write> IonWriter for BinaryWriter<W> {
                     ));
                 }
             }
+            RawSymbolTokenRef::Text(text) if self.is_rare_symbol_value(text) => {
+                self.raw_writer.write_string(text)?;
+                return self.value_written();
+            }
             RawSymbolTokenRef::Text(text) => self.get_or_create_symbol_id(text),
         };
-        self.raw_writer.write_symbol(symbol_id)
+        self.raw_writer.write_symbol(symbol_id)?;
+        self.value_written()
     }
 
-    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) {
+    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) -> IonResult<()> {
+        if self.parent_type() != Some(IonType::Struct) {
+            return illegal_operation(
+                "Attempted to set field name when the writer was not in a struct.",
+            );
+        }
+        if let Some(symbol_id) = self.known_symbol_id(&name)? {
+            return self.raw_writer.set_field_name(symbol_id);
+        }
         let text = match name.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(symbol_id) => {
                 if self.symbol_table.sid_is_valid(symbol_id) {
                     symbol_id
                 } else {
-                    panic!("Cannot set symbol ID ${symbol_id} as field name. It is undefined.");
+                    return illegal_operation(format!(
+                        "Cannot set symbol ID ${symbol_id} as field name. It is undefined."
+                    ));
                 }
             }
             RawSymbolTokenRef::Text(text) => self.get_or_create_symbol_id(text),
         };
-        self.raw_writer.set_field_name(text);
+        self.raw_writer.set_field_name(text)
     }
 
     fn flush(&mut self) -> IonResult<()> {
@@ -173,45 +542,111 @@ impl<W: Write> IonWriter for BinaryWriter<W> {
         self.raw_writer.flush()
     }
 
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        self.raw_writer.write_null(ion_type)?;
+        self.value_written()
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        self.raw_writer.write_bool(value)?;
+        self.value_written()
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        self.raw_writer.write_i64(value)?;
+        self.value_written()
+    }
+
+    fn write_u64(&mut self, value: u64) -> IonResult<()> {
+        self.raw_writer.write_u64(value)?;
+        self.value_written()
+    }
+
+    fn write_i128(&mut self, value: i128) -> IonResult<()> {
+        self.raw_writer.write_i128(value)?;
+        self.value_written()
+    }
+
+    fn write_int(&mut self, value: &Int) -> IonResult<()> {
+        self.raw_writer.write_int(value)?;
+        self.value_written()
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        self.raw_writer.write_f32(value)?;
+        self.value_written()
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        self.raw_writer.write_f64(value)?;
+        self.value_written()
+    }
+
+    fn write_float_with_width(&mut self, value: f64, width: FloatWidth) -> IonResult<()> {
+        self.raw_writer.write_float_with_width(value, width)?;
+        self.value_written()
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        self.raw_writer.write_decimal(value)?;
+        self.value_written()
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        self.raw_writer.write_timestamp(value)?;
+        self.value_written()
+    }
+
+    fn write_string<A: AsRef<str>>(&mut self, value: A) -> IonResult<()> {
+        self.raw_writer.write_string(value)?;
+        self.value_written()
+    }
+
+    fn write_clob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        self.raw_writer.write_clob(value)?;
+        self.value_written()
+    }
+
+    fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        self.raw_writer.write_blob(value)?;
+        self.value_written()
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        self.raw_writer.step_out()?;
+        self.value_written()
+    }
+
     delegate! {
         to self.raw_writer {
             fn ion_version(&self) -> (u8, u8);
             fn write_ion_version_marker(&mut self, major: u8, minor: u8) -> IonResult<()>;
-            fn write_null(&mut self, ion_type: IonType) -> IonResult<()>;
-            fn write_bool(&mut self, value: bool) -> IonResult<()>;
-            fn write_i64(&mut self, value: i64) -> IonResult<()>;
-            fn write_int(&mut self, value: &Int) -> IonResult<()>;
-            fn write_f32(&mut self, value: f32) -> IonResult<()>;
-            fn write_f64(&mut self, value: f64) -> IonResult<()>;
-            fn write_decimal(&mut self, value: &Decimal) -> IonResult<()>;
-            fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()>;
-            fn write_string<A: AsRef<str>>(&mut self, value: A) -> IonResult<()>;
-            fn write_clob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()>;
-            fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()>;
             fn step_in(&mut self, container_type: IonType) -> IonResult<()>;
             fn parent_type(&self) -> Option<IonType>;
             fn depth(&self) -> usize;
-            fn step_out(&mut self) -> IonResult<()>;
             fn output(&self) -> &Self::Output;
             fn output_mut(&mut self) -> &mut Self::Output;
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use crate::reader::ReaderBuilder;
     use crate::stream_reader::IonReader;
 
+    use crate::binary::constants::v1_0::IVM;
+    use crate::types::{ImportLocation, Symbol};
     use crate::StreamItem::Value;
+    use rstest::*;
 
     #[test]
     fn intern_field_names() -> IonResult<()> {
         let mut buffer = Vec::new();
         let mut binary_writer = BinaryWriterBuilder::new().build(&mut buffer)?;
         binary_writer.step_in(IonType::Struct)?;
-        binary_writer.set_field_name("foo");
+        binary_writer.set_field_name("foo")?;
         binary_writer.write_symbol("bar")?;
         binary_writer.step_out()?;
         binary_writer.flush()?;
@@ -226,11 +661,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn undefined_symbol_ids_are_errors() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        assert!(binary_writer.set_annotations(["foo", "baz"]).is_ok());
+        assert!(binary_writer.set_annotations([4, 100]).is_err());
+        // Text annotations that precede an invalid symbol ID are not added to the symbol table.
+        let num_pending_symbols = binary_writer.num_pending_symbols;
+        let annotations = [
+            RawSymbolTokenRef::Text("quux"),
+            RawSymbolTokenRef::SymbolId(100),
+        ];
+        assert!(binary_writer.set_annotations(annotations).is_err());
+        assert_eq!(binary_writer.num_pending_symbols, num_pending_symbols);
+        assert!(binary_writer.symbol_table.sid_for(&"quux").is_none());
+        assert!(binary_writer.write_symbol(100).is_err());
+        // The annotations before the invalid symbol ID are not applied either.
+        binary_writer.write_i64(5)?;
+        binary_writer.step_in(IonType::Struct)?;
+        assert!(binary_writer.set_field_name(100).is_err());
+        binary_writer.set_field_name("bar")?;
+        binary_writer.write_bool(true)?;
+        binary_writer.step_out()?;
+        binary_writer.flush()?;
+
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        assert_eq!(Value(IonType::Int), reader.next()?);
+        let annotations: Vec<Symbol> = reader.annotations().collect::<IonResult<_>>()?;
+        assert_eq!(annotations, ["foo", "baz"]);
+        assert_eq!(Value(IonType::Struct), reader.next()?);
+        reader.step_in()?;
+        assert_eq!(Value(IonType::Bool), reader.next()?);
+        assert_eq!("bar", reader.field_name()?);
+        Ok(())
+    }
+
+    #[test]
+    fn field_name_outside_of_struct_is_an_error() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        assert!(binary_writer.set_field_name("foo").is_err());
+        binary_writer.step_in(IonType::List)?;
+        assert!(binary_writer.set_field_name("foo").is_err());
+        binary_writer.step_out()?;
+        binary_writer.flush()?;
+
+        // The rejected field name was not added to the symbol table.
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        assert_eq!(Value(IonType::List), reader.next()?);
+        assert_eq!(reader.symbol_table().len(), 10);
+        Ok(())
+    }
+
     #[test]
     fn intern_annotations() -> IonResult<()> {
         let mut buffer = Vec::new();
         let mut binary_writer = BinaryWriterBuilder::new().build(&mut buffer)?;
-        binary_writer.set_annotations(["foo", "bar"]);
+        binary_writer.set_annotations(["foo", "bar"])?;
         binary_writer.write_i64(5)?;
         binary_writer.flush()?;
 
@@ -262,4 +750,232 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn symbols_from_imports() -> IonResult<()> {
+        let shared_table =
+            SharedSymbolTable::new("shared".to_string(), 1, vec![Some("foo".to_string()), None])?;
+        let location = |sid| Symbol::with_import_location(ImportLocation::new("shared", sid));
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_import(shared_table)
+            .build(&mut buffer)?;
+        binary_writer.set_annotations([location(2)])?;
+        binary_writer.step_in(IonType::Struct)?;
+        binary_writer.set_field_name(location(2))?;
+        binary_writer.write_symbol(location(1))?;
+        binary_writer.set_field_name("foo")?;
+        binary_writer.write_symbol(location(3))?;
+        binary_writer.set_field_name("bar")?;
+        binary_writer.write_symbol(Symbol::with_import_location(ImportLocation::new(
+            "other", 1,
+        )))?;
+        binary_writer.step_out()?;
+        binary_writer.flush()?;
+
+        // The reader does not have access to the shared table, so symbols that were written using
+        // their imported symbol IDs are read with their import locations, even if the writer
+        // was given their text.
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        assert_eq!(Value(IonType::Struct), reader.next()?);
+        assert_eq!(reader.annotations().next().unwrap()?, location(2));
+        reader.step_in()?;
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert_eq!(reader.field_name()?, location(2));
+        assert_eq!(reader.read_symbol()?, location(1));
+        // Import locations that are not in an imported table are written as `$0`.
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert_eq!(reader.field_name()?, location(1));
+        assert_eq!(reader.read_symbol()?, Symbol::unknown_text());
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert_eq!(reader.field_name()?, "bar");
+        assert_eq!(reader.read_symbol()?, Symbol::unknown_text());
+        Ok(())
+    }
+
+    #[test]
+    fn spilled_values_are_written_after_their_symbol_table() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_spill_storage(16, tempfile::tempfile()?)
+            .build(&mut buffer)?;
+        binary_writer.step_in(IonType::List)?;
+        for i in 0..100 {
+            binary_writer.write_symbol(format!("symbol {i}"))?;
+        }
+        binary_writer.step_out()?;
+        binary_writer.flush()?;
+
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        assert_eq!(Value(IonType::List), reader.next()?);
+        reader.step_in()?;
+        for i in 0..100 {
+            assert_eq!(Value(IonType::Symbol), reader.next()?);
+            assert_eq!(reader.read_symbol()?, format!("symbol {i}").as_str());
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::max_local_symbols(BinaryWriterBuilder::new().with_max_local_symbols(8))]
+    #[case::reset_interval(BinaryWriterBuilder::new().with_symbol_table_reset_interval(4))]
+    #[case::version_marker(
+        BinaryWriterBuilder::new()
+            .with_max_local_symbols(8)
+            .with_symbol_table_reset(SymbolTableReset::VersionMarker)
+    )]
+    fn symbol_table_resets(
+        #[case] builder: BinaryWriterBuilder,
+        #[values(false, true)] with_import: bool,
+    ) -> IonResult<()> {
+        let shared_table =
+            SharedSymbolTable::new("shared".to_string(), 1, vec![Some("foo".to_string())])?;
+        let location = Symbol::with_import_location(ImportLocation::new("shared", 1));
+        let builder = if with_import {
+            builder.with_import(shared_table)
+        } else {
+            builder
+        };
+        let num_imported_symbols = usize::from(with_import);
+        let reset = builder.symbol_table_policies.reset;
+        let mut buffer = Vec::new();
+        let mut binary_writer = builder.build(&mut buffer)?;
+        for i in 0..100 {
+            binary_writer.step_in(IonType::Struct)?;
+            binary_writer.set_field_name(format!("field {i}"))?;
+            binary_writer.write_symbol(format!("value {i}"))?;
+            if with_import {
+                binary_writer.set_field_name("imported")?;
+                binary_writer.write_symbol(location.clone())?;
+            }
+            binary_writer.step_out()?;
+        }
+        binary_writer.flush()?;
+
+        // Only the `VersionMarker` policy writes IVMs after the one that begins the stream.
+        let num_ivms = buffer.windows(4).filter(|bytes| *bytes == IVM).count();
+        match reset {
+            SymbolTableReset::NewTable => assert_eq!(num_ivms, 1),
+            SymbolTableReset::VersionMarker => assert!(num_ivms > 1, "{num_ivms} IVMs"),
+        }
+
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        for i in 0..100 {
+            assert_eq!(Value(IonType::Struct), reader.next()?);
+            // Each value adds at most three local symbols to the table before it is reset.
+            let num_local_symbols = reader.symbol_table().len() - 10 - num_imported_symbols;
+            assert!(num_local_symbols <= 11, "{num_local_symbols} local symbols");
+            reader.step_in()?;
+            assert_eq!(Value(IonType::Symbol), reader.next()?);
+            assert_eq!(reader.field_name()?, format!("field {i}").as_str());
+            assert_eq!(reader.read_symbol()?, format!("value {i}").as_str());
+            if with_import {
+                assert_eq!(Value(IonType::Symbol), reader.next()?);
+                assert_eq!(reader.read_symbol()?, location);
+            }
+            reader.step_out()?;
+        }
+        assert_eq!(crate::StreamItem::Nothing, reader.next()?);
+        Ok(())
+    }
+
+    #[test]
+    fn rare_symbol_values_are_written_as_strings() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_rare_symbol_values_as_strings(3)
+            .build(&mut buffer)?;
+        binary_writer.step_in(IonType::List)?;
+        binary_writer.write_symbol("rare")?;
+        for _ in 0..5 {
+            binary_writer.write_symbol("common")?;
+        }
+        binary_writer.write_symbol("name")?;
+        binary_writer.step_out()?;
+        binary_writer.flush()?;
+
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        assert_eq!(Value(IonType::List), reader.next()?);
+        reader.step_in()?;
+        assert_eq!(Value(IonType::String), reader.next()?);
+        assert_eq!(reader.read_string()?, "rare");
+        for _ in 0..2 {
+            assert_eq!(Value(IonType::String), reader.next()?);
+            assert_eq!(reader.read_string()?, "common");
+        }
+        for _ in 0..3 {
+            assert_eq!(Value(IonType::Symbol), reader.next()?);
+            assert_eq!(reader.read_symbol()?, "common");
+        }
+        // Text that is already in the symbol table is always written as a symbol.
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert_eq!(reader.read_symbol()?, "name");
+        Ok(())
+    }
+
+    #[test]
+    fn registered_symbol_handles() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        let foo = binary_writer.register_symbol("foo");
+        let bar = binary_writer.register_symbol("bar");
+        assert_eq!(foo, binary_writer.register_symbol("foo"));
+        binary_writer.set_annotations([foo, bar])?;
+        binary_writer.step_in(IonType::Struct)?;
+        binary_writer.set_field_name(bar)?;
+        binary_writer.write_symbol(foo)?;
+        binary_writer.step_out()?;
+        binary_writer.flush()?;
+
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        assert_eq!(Value(IonType::Struct), reader.next()?);
+        let annotations: Vec<Symbol> = reader.annotations().collect::<IonResult<_>>()?;
+        assert_eq!(
+            annotations,
+            vec![Symbol::owned("foo"), Symbol::owned("bar")]
+        );
+        reader.step_in()?;
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert_eq!(reader.field_name()?, "bar");
+        assert_eq!(reader.read_symbol()?, "foo");
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_handles_are_invalidated_by_resets() -> IonResult<()> {
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_symbol_table_reset_interval(1)
+            .build(Vec::new())?;
+        let foo = binary_writer.register_symbol("foo");
+        binary_writer.write_symbol(foo)?;
+        // Writing the top-level value reset the symbol table.
+        assert!(!binary_writer.symbol_handle_is_valid(foo));
+        assert!(binary_writer.write_symbol(foo).is_err());
+        assert!(binary_writer.set_annotations([foo]).is_err());
+        binary_writer.step_in(IonType::Struct)?;
+        assert!(binary_writer.set_field_name(foo).is_err());
+        binary_writer.step_out()?;
+        let foo = binary_writer.register_symbol("foo");
+        assert!(binary_writer.symbol_handle_is_valid(foo));
+        binary_writer.write_symbol(foo)?;
+        binary_writer.flush()?;
+
+        let mut reader = ReaderBuilder::new().build(binary_writer.output().as_slice())?;
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert_eq!(reader.read_symbol()?, "foo");
+        assert_eq!(Value(IonType::Struct), reader.next()?);
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert_eq!(reader.read_symbol()?, "foo");
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_handles_from_other_writers_are_errors() -> IonResult<()> {
+        let mut writer1 = BinaryWriterBuilder::new().build(Vec::new())?;
+        let mut writer2 = BinaryWriterBuilder::new().build(Vec::new())?;
+        let foo = writer1.register_symbol("foo");
+        assert!(!writer2.symbol_handle_is_valid(foo));
+        assert!(writer2.write_symbol(foo).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,112 @@
+//! A pool of encoding buffers that binary writers can share to avoid reallocating them.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::binary::raw_binary_writer::EncodingBuffers;
+
+/// A pool of the buffers that a [`RawBinaryWriter`](crate::RawBinaryWriter) uses to encode its
+/// data. A writer that was built with a pool takes its buffers from the pool if any are
+/// available and returns them to the pool when it is dropped, which saves an application that
+/// creates many short-lived writers (for example, one per response) from allocating them each
+/// time.
+///
+/// Buffers that grew to encode a large value are shrunk to the pool's
+/// [maximum retained capacity](Self::with_max_retained_capacity) when they are returned, so a
+/// single large value does not permanently increase the pool's memory use.
+///
+/// Cloning a `BufferPool` produces another handle to the same pool, which may be shared between
+/// threads.
+///
+/// ```
+/// use ion_rs::binary::buffer_pool::BufferPool;
+/// use ion_rs::{BinaryWriterBuilder, IonResult, IonWriter};
+/// # fn main() -> IonResult<()> {
+/// let pool = BufferPool::new(16);
+/// for response in ["foo", "bar", "baz"] {
+///     let mut writer = BinaryWriterBuilder::new()
+///         .with_buffer_pool(pool.clone())
+///         .build(Vec::new())?;
+///     writer.write_string(response)?;
+///     writer.flush()?;
+/// }
+/// // Each writer returned the buffers used by the writer itself and by its symbol table writer.
+/// assert_eq!(pool.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BufferPool {
+    shared: Arc<SharedPool>,
+}
+
+// The state shared by every handle to a pool.
+#[derive(Debug)]
+struct SharedPool {
+    buffers: Mutex<Vec<EncodingBuffers>>,
+    capacity: usize,
+    max_retained_capacity: AtomicUsize,
+}
+
+impl BufferPool {
+    /// The default for [`with_max_retained_capacity`](Self::with_max_retained_capacity): eight
+    /// times the initial size of a writer's encoding buffer.
+    pub const DEFAULT_MAX_RETAINED_CAPACITY: usize = 64 * 1024;
+
+    /// Creates an empty pool that holds at most `capacity` writers' buffers. Buffers that are
+    /// returned to a full pool are dropped.
+    pub fn new(capacity: usize) -> BufferPool {
+        BufferPool {
+            shared: Arc::new(SharedPool {
+                buffers: Mutex::new(Vec::new()),
+                capacity,
+                max_retained_capacity: AtomicUsize::new(Self::DEFAULT_MAX_RETAINED_CAPACITY),
+            }),
+        }
+    }
+
+    /// Sets the largest capacity, in bytes, that each of a writer's buffers may keep while it is
+    /// in the pool. Larger buffers are shrunk to this size when they are returned. The default is
+    /// [`DEFAULT_MAX_RETAINED_CAPACITY`](Self::DEFAULT_MAX_RETAINED_CAPACITY).
+    ///
+    /// This applies to every handle to the pool, including clones that were made before it was
+    /// called. Buffers that are already in the pool are not shrunk until they are next returned.
+    pub fn with_max_retained_capacity(self, max_retained_capacity: usize) -> BufferPool {
+        self.shared
+            .max_retained_capacity
+            .store(max_retained_capacity, Ordering::Relaxed);
+        self
+    }
+
+    /// Returns the number of writers' buffers that are currently in the pool.
+    pub fn len(&self) -> usize {
+        self.shared
+            .buffers
+            .lock()
+            .map(|buffers| buffers.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if the pool does not currently hold any buffers.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Removes a set of buffers from the pool, if it holds any.
+    pub(crate) fn take(&self) -> Option<EncodingBuffers> {
+        // The pool is only a cache, so a poisoned lock is treated as an empty pool.
+        self.shared.buffers.lock().ok()?.pop()
+    }
+
+    // Clears `buffers`, shrinks any that exceed the maximum retained capacity and adds them to the
+    // pool if it has room.
+    pub(crate) fn put(&self, mut buffers: EncodingBuffers) {
+        if let Ok(mut pooled) = self.shared.buffers.lock() {
+            if pooled.len() < self.shared.capacity {
+                buffers.clear();
+                buffers.shrink_to(self.shared.max_retained_capacity.load(Ordering::Relaxed));
+                pooled.push(buffers);
+            }
+        }
+    }
+}
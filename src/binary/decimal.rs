@@ -1,12 +1,14 @@
 // Copyright Amazon.com, Inc. or its affiliates.
 
-use std::io::Write;
+use crate::io::Write;
+use alloc::string::ToString;
 
 use arrayvec::ArrayVec;
-use bigdecimal::Zero;
+use num_traits::Zero;
 
 use crate::binary::int::DecodedInt;
 use crate::binary::raw_binary_writer::MAX_INLINE_LENGTH;
+use crate::binary::uint;
 use crate::binary::var_int::VarInt;
 use crate::binary::var_uint::VarUInt;
 use crate::ion_data::IonEq;
@@ -71,6 +73,7 @@ where
             // Otherwise, allocate a Vec<u8> with the necessary representation.
             let mut coefficient_bytes = match decimal.coefficient.magnitude() {
                 UInt::U64(unsigned) => unsigned.to_be_bytes().into(),
+                UInt::U128(unsigned) => uint::encode_u128(*unsigned).as_bytes().into(),
                 UInt::BigUInt(big) => big.to_bytes_be(),
             };
 
@@ -1,4 +1,5 @@
-use std::convert::TryFrom;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
 
 use crate::binary::constants::v1_0::length_codes;
 use crate::binary::nibbles::nibbles_from_byte;
@@ -1,12 +1,20 @@
-use std::mem;
+#[cfg(feature = "std")]
+use alloc::format;
+#[cfg(feature = "std")]
+use alloc::vec;
+use core::mem;
 
+#[cfg(feature = "std")]
 use crate::data_source::IonDataSource;
-use crate::result::{decoding_error, IonResult};
+use crate::io::Write;
+#[cfg(feature = "std")]
+use crate::result::decoding_error;
+use crate::result::IonResult;
 use crate::types;
 use crate::types::{Coefficient, Int};
+#[cfg(feature = "std")]
 use num_bigint::{BigInt, Sign};
 use num_traits::Zero;
-use std::io::Write;
 
 type IntStorage = i64;
 const INT_NEGATIVE_ZERO: u8 = 0x80;
@@ -37,7 +45,26 @@ impl DecodedInt {
         }
     }
 
+    /// Interprets the provided slice as the bytes of an Int encoding primitive, returning its
+    /// value and whether its sign bit was set. The caller must confirm that `int_bytes` is not
+    /// empty and is no longer than 16 bytes long; otherwise, overflow may quietly occur.
+    pub(crate) fn i128_from_slice(int_bytes: &[u8]) -> (i128, bool) {
+        let first_byte = int_bytes[0];
+        let is_negative = first_byte & 0b1000_0000 != 0;
+        let mut magnitude: i128 = i128::from(first_byte & 0b0111_1111);
+        for &byte in &int_bytes[1..] {
+            magnitude <<= 8;
+            magnitude |= i128::from(byte);
+        }
+        if is_negative {
+            (-magnitude, true)
+        } else {
+            (magnitude, false)
+        }
+    }
+
     /// Reads an Int with `length` bytes from the provided data source.
+    #[cfg(feature = "std")]
     pub fn read<R: IonDataSource>(data_source: &mut R, length: usize) -> IonResult<DecodedInt> {
         if length == 0 {
             return Ok(DecodedInt {
@@ -61,6 +88,7 @@ impl DecodedInt {
         }
     }
 
+    #[cfg(feature = "std")]
     pub fn read_using_buffer<R: IonDataSource>(
         data_source: &mut R,
         length: usize,
@@ -90,8 +118,13 @@ impl DecodedInt {
                 magnitude |= byte;
             }
             Int::I64(sign * magnitude)
+        } else if length <= mem::size_of::<i128>() {
+            // This Int will fit in an i128, which does not require an allocation.
+            let (value, sign_bit_is_set) = DecodedInt::i128_from_slice(buffer);
+            is_negative = sign_bit_is_set;
+            Int::from(value)
         } else {
-            // This Int is too big for an i64, we'll need to use a BigInt
+            // This Int is too big for an i128, we'll need to use a BigInt
             let sign: num_bigint::Sign = if buffer[0] & 0b1000_0000 == 0 {
                 Sign::Plus
             } else {
@@ -103,7 +136,7 @@ impl DecodedInt {
             // in the buffer to zero.
             buffer[0] &= 0b0111_1111;
             let value = BigInt::from_bytes_be(sign, buffer);
-            Int::BigInt(value)
+            Int::from(value)
         };
 
         Ok(DecodedInt {
@@ -201,7 +234,7 @@ impl From<DecodedInt> for Coefficient {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use crate::result::IonResult;
@@ -227,6 +260,20 @@ mod tests {
         assert_eq!(int.value(), &Int::I64(-3_966_849));
     }
 
+    #[test]
+    fn test_read_sixteen_byte_negative_int() {
+        let mut data = [0xFFu8; 16];
+        let int = DecodedInt::read(&mut Cursor::new(data), data.len()).expect(READ_ERROR_MESSAGE);
+        assert_eq!(int.size_in_bytes(), 16);
+        assert!(matches!(int.value(), Int::I128(value) if value.get() == -i128::MAX));
+        assert!(int.is_negative);
+
+        // A nine-byte Int whose magnitude fits in an i64 is stored as an I64.
+        data[..9].copy_from_slice(&[0x80, 0, 0, 0, 0, 0, 0, 0, 0x05]);
+        let int = DecodedInt::read(&mut Cursor::new(&data[..9]), 9).expect(READ_ERROR_MESSAGE);
+        assert_eq!(int.value(), &Int::I64(-5));
+    }
+
     #[test]
     fn test_read_int_negative_zero() {
         let data = &[0b1000_0000]; // Negative zero
@@ -7,12 +7,15 @@
 pub mod constants;
 
 pub mod binary_writer;
+#[cfg(feature = "std")]
+pub mod buffer_pool;
 pub mod decimal;
 mod header;
 pub mod int;
 mod nibbles;
 pub mod non_blocking;
 pub mod raw_binary_writer;
+pub mod spill;
 pub mod timestamp;
 mod type_code;
 pub mod uint;
@@ -6,12 +6,14 @@ use crate::binary::non_blocking::type_descriptor::{
 use crate::binary::uint::DecodedUInt;
 use crate::binary::var_int::VarInt;
 use crate::binary::var_uint::VarUInt;
+use crate::io::Read;
 use crate::result::{decoding_error, incomplete_data_error, incomplete_data_error_raw};
 use crate::types::{Int, UInt};
 use crate::{IonResult, IonType};
+use alloc::format;
+use alloc::vec::Vec;
+use core::mem;
 use num_bigint::{BigInt, BigUint, Sign};
-use std::io::Read;
-use std::mem;
 
 // This limit is used for stack-allocating buffer space to encode/decode UInts.
 const UINT_STACK_BUFFER_SIZE: usize = 16;
@@ -269,9 +271,11 @@ impl<A: AsRef<[u8]>> BinaryBuffer<A> {
     pub fn read_uint(&mut self, length: usize) -> IonResult<DecodedUInt> {
         if length <= mem::size_of::<u64>() {
             return self.read_small_uint(length);
+        } else if length <= mem::size_of::<u128>() {
+            return self.read_u128_uint(length);
         }
 
-        // The UInt is too large to fit in a u64; read it as a BigUInt instead.
+        // The UInt is too large to fit in a u128; read it as a BigUInt instead.
         self.read_big_uint(length)
     }
 
@@ -287,6 +291,17 @@ impl<A: AsRef<[u8]>> BinaryBuffer<A> {
         Ok(DecodedUInt::new(UInt::U64(magnitude), length))
     }
 
+    /// Reads the first `length` bytes from the buffer as a `UInt`. The caller must confirm that
+    /// `length` is small enough to fit in a `u128`.
+    fn read_u128_uint(&mut self, length: usize) -> IonResult<DecodedUInt> {
+        let uint_bytes = self
+            .peek_n_bytes(length)
+            .ok_or_else(|| incomplete_data_error_raw("a UInt", self.total_consumed()))?;
+        let magnitude = DecodedUInt::u128_from_slice(uint_bytes);
+        self.consume(length);
+        Ok(DecodedUInt::new(UInt::from(magnitude), length))
+    }
+
     /// Reads the first `length` bytes from the buffer as a `UInt`. If `length` is small enough
     /// that the value can fit in a `usize`, it is strongly recommended that you use
     /// `read_small_uint` instead as it will be much faster.
@@ -353,8 +368,13 @@ impl<A: AsRef<[u8]>> BinaryBuffer<A> {
                 magnitude |= byte;
             }
             Int::I64(sign * magnitude)
+        } else if length <= mem::size_of::<i128>() {
+            // This Int will fit in an i128, which does not require an allocation.
+            let (value, sign_bit_is_set) = DecodedInt::i128_from_slice(int_bytes);
+            is_negative = sign_bit_is_set;
+            Int::from(value)
         } else {
-            // This Int is too big for an i64, we'll need to use a BigInt
+            // This Int is too big for an i128, we'll need to use a BigInt
             let value = if int_bytes[0] & 0b1000_0000 == 0 {
                 BigInt::from_bytes_be(Sign::Plus, int_bytes)
             } else {
@@ -367,7 +387,7 @@ impl<A: AsRef<[u8]>> BinaryBuffer<A> {
                 BigInt::from_bytes_be(Sign::Minus, owned_int_bytes.as_slice())
             };
 
-            Int::BigInt(value)
+            Int::from(value)
         };
         self.consume(length);
         Ok(DecodedInt::new(value, is_negative, length))
@@ -456,6 +476,18 @@ impl<A: AsRef<[u8]>> BinaryBuffer<A> {
     }
 }
 
+/// These methods are only available to `BinaryBuffer`s that wrap a borrowed byte slice, whose
+/// contents outlive the buffer itself.
+impl<'a> BinaryBuffer<&'a [u8]> {
+    /// Like [`bytes_range`](Self::bytes_range), but the returned slice borrows from the wrapped
+    /// input rather than from the buffer itself, allowing it to outlive the buffer.
+    pub fn source_bytes_range(&self, offset: usize, length: usize) -> &'a [u8] {
+        let from = self.start + offset;
+        let to = from + length;
+        &self.data[from..to]
+    }
+}
+
 /// These methods are only available to `BinaryBuffer`s that wrap a `Vec<u8>`. That is: buffers
 /// that own a growable array into which more data can be appended.
 // TODO: Instead of pinning this to Vec<u8>, we should define a trait that allows any owned/growable
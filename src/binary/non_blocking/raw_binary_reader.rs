@@ -5,19 +5,21 @@ use crate::binary::non_blocking::type_descriptor::{Header, TypeDescriptor};
 use crate::binary::uint::DecodedUInt;
 use crate::binary::var_uint::VarUInt;
 use crate::binary::IonTypeCode;
+use crate::io::Read;
 use crate::raw_reader::BufferedRawReader;
 use crate::result::{
     decoding_error, decoding_error_raw, illegal_operation, illegal_operation_raw,
-    incomplete_data_error,
+    incomplete_data_error, Position,
 };
-use crate::types::{Blob, Clob, Decimal, IntAccess, Str, SymbolId};
+use crate::types::{Blob, Clob, Decimal, Encoding, FloatWidth, IntAccess, Span, Str, SymbolId};
 use crate::{Int, IonReader, IonResult, IonType, RawStreamItem, RawSymbolToken, Timestamp};
-use bytes::{BigEndian, Buf, ByteOrder};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+use core::mem;
+use core::ops::Range;
 use num_bigint::BigUint;
 use num_traits::Zero;
-use std::io::Read;
-use std::mem;
-use std::ops::Range;
 
 /// Type, offset, and length information about the serialized value over which the
 /// NonBlockingRawBinaryReader is currently positioned.
@@ -351,6 +353,38 @@ impl From<Vec<u8>> for RawBinaryReader<Vec<u8>> {
     }
 }
 
+impl<'a> RawBinaryReader<&'a [u8]> {
+    /// Like [`value_and_bytes`](Self::value_and_bytes), but the returned slice borrows from the
+    /// reader's input rather than from the reader itself.
+    fn source_bytes(&self, expected_ion_type: IonType) -> IonResult<&'a [u8]> {
+        let (encoded_value, _bytes) = self.value_and_bytes(expected_ion_type)?;
+        let value_length = encoded_value.value_length();
+        let value_offset = encoded_value.total_length() - value_length;
+        Ok(self.buffer.source_bytes_range(value_offset, value_length))
+    }
+
+    /// If the reader is currently positioned on a string, returns a `&str` that borrows its text
+    /// directly from the input slice.
+    pub fn read_str_ref(&mut self) -> IonResult<&'a str> {
+        self.source_bytes(IonType::String).and_then(|bytes| {
+            core::str::from_utf8(bytes)
+                .map_err(|_| decoding_error_raw("encountered a string with invalid utf-8 data"))
+        })
+    }
+
+    /// If the reader is currently positioned on a blob, returns a slice of the input containing
+    /// its bytes.
+    pub fn read_blob_ref(&mut self) -> IonResult<&'a [u8]> {
+        self.source_bytes(IonType::Blob)
+    }
+
+    /// If the reader is currently positioned on a clob, returns a slice of the input containing
+    /// its bytes.
+    pub fn read_clob_ref(&mut self) -> IonResult<&'a [u8]> {
+        self.source_bytes(IonType::Clob)
+    }
+}
+
 impl<A: AsRef<[u8]>> RawBinaryReader<A> {
     /// Constructs a RawBinaryReader from a value that can be viewed as a byte slice.
     pub fn new(source: A) -> RawBinaryReader<A> {
@@ -641,7 +675,7 @@ impl<A: AsRef<[u8]>> RawBinaryReader<A> {
         )
     }
 
-    pub fn field_id_range(&self) -> Option<std::ops::Range<usize>> {
+    pub fn field_id_range(&self) -> Option<core::ops::Range<usize>> {
         let value = self.encoded_value()?;
         let start = value.field_id_offset()?;
         let end = start + value.field_id_length as usize;
@@ -658,7 +692,7 @@ impl<A: AsRef<[u8]>> RawBinaryReader<A> {
         value.annotations_offset()
     }
 
-    pub fn annotations_range(&self) -> Option<std::ops::Range<usize>> {
+    pub fn annotations_range(&self) -> Option<core::ops::Range<usize>> {
         let value = self.encoded_value()?;
         value.annotations_range()
     }
@@ -671,7 +705,7 @@ impl<A: AsRef<[u8]>> RawBinaryReader<A> {
         }
     }
 
-    pub fn header_range(&self) -> std::ops::Range<usize> {
+    pub fn header_range(&self) -> core::ops::Range<usize> {
         if let Some(value) = self.encoded_value() {
             value.header_range()
         } else {
@@ -695,7 +729,7 @@ impl<A: AsRef<[u8]>> RawBinaryReader<A> {
         }
     }
 
-    pub fn value_range(&self) -> std::ops::Range<usize> {
+    pub fn value_range(&self) -> core::ops::Range<usize> {
         if let Some(value) = self.encoded_value() {
             value.value_range()
         } else {
@@ -874,6 +908,8 @@ impl<A: AsRef<[u8]>> IonReader for RawBinaryReader<A> {
         let (encoded_value, bytes) = self.value_and_bytes(IonType::Int)?;
         let value: Int = if bytes.len() <= mem::size_of::<u64>() {
             DecodedUInt::small_uint_from_slice(bytes).into()
+        } else if bytes.len() <= mem::size_of::<u128>() {
+            DecodedUInt::u128_from_slice(bytes).into()
         } else {
             DecodedUInt::big_uint_from_slice(bytes).into()
         };
@@ -900,13 +936,32 @@ impl<A: AsRef<[u8]>> IonReader for RawBinaryReader<A> {
         let number_of_bytes = encoded_value.value_length();
         let value = match number_of_bytes {
             0 => 0f64,
-            4 => f64::from(BigEndian::read_f32(bytes)),
-            8 => BigEndian::read_f64(bytes),
+            4 => f64::from(f32::from_be_bytes(bytes.try_into().unwrap())),
+            8 => f64::from_be_bytes(bytes.try_into().unwrap()),
             _ => return decoding_error("encountered a float with an illegal length"),
         };
         Ok(value)
     }
 
+    fn encoding(&self) -> Option<Encoding> {
+        let encoded_value = self.encoded_value()?;
+        if encoded_value.ion_type() != IonType::Float || encoded_value.header.is_null() {
+            return None;
+        }
+        FloatWidth::from_length(encoded_value.value_length()).map(Encoding::Float)
+    }
+
+    fn span(&self) -> Option<Span> {
+        let encoded_value = self.encoded_value()?;
+        let start = encoded_value
+            .annotations_offset()
+            .unwrap_or(encoded_value.header_offset);
+        Some(Span::new(
+            Position::with_offset(start),
+            Position::with_offset(encoded_value.value_end_exclusive()),
+        ))
+    }
+
     fn read_decimal(&mut self) -> IonResult<Decimal> {
         let (encoded_value, mut buffer) = self.value_and_buffer(IonType::Decimal)?;
 
@@ -935,7 +990,7 @@ impl<A: AsRef<[u8]>> IonReader for RawBinaryReader<A> {
     /// If the reader is currently positioned on a string, returns a [&str] containing its text.
     fn read_str(&mut self) -> IonResult<&str> {
         self.read_str_bytes().and_then(|bytes| {
-            std::str::from_utf8(bytes)
+            core::str::from_utf8(bytes)
                 .map_err(|_| decoding_error_raw("encountered a string with invalid utf-8 data"))
         })
     }
@@ -1114,13 +1169,13 @@ impl<A: AsRef<[u8]>> IonReader for RawBinaryReader<A> {
 
 /// Iterates over a slice of bytes, lazily reading them as a sequence of VarUInt symbol IDs.
 struct AnnotationsIterator<'a> {
-    data: std::io::Cursor<&'a [u8]>,
+    data: BinaryBuffer<&'a [u8]>,
 }
 
 impl<'a> AnnotationsIterator<'a> {
     pub(crate) fn new(bytes: &[u8]) -> AnnotationsIterator {
         AnnotationsIterator {
-            data: std::io::Cursor::new(bytes),
+            data: BinaryBuffer::new(bytes),
         }
     }
 }
@@ -1137,7 +1192,7 @@ impl<'a> Iterator for AnnotationsIterator<'a> {
         // If the reader is parked on a value, the complete annotations sequence is in the buffer.
         // Therefore, reading symbol IDs from this byte slice cannot fail. This allows us to safely
         // unwrap the result of this `read` call.
-        let var_uint = VarUInt::read(&mut self.data).unwrap();
+        let var_uint = self.data.read_var_uint().unwrap();
         // If this var_uint was longer than the declared annotations wrapper length, return an error.
         if var_uint.size_in_bytes() > remaining {
             Some(decoding_error(
@@ -1441,7 +1496,7 @@ impl<'a, A: AsRef<[u8]>> TxReader<'a, A> {
 #[cfg(test)]
 mod tests {
     use crate::binary::non_blocking::raw_binary_reader::RawBinaryReader;
-    use crate::text::text_value::IntoRawAnnotations;
+    use crate::raw_symbol_token::local_sid_token;
     use crate::{IonError, IonResult};
     use std::fmt::Debug;
 
@@ -1471,11 +1526,9 @@ mod tests {
         }
     }
 
-    fn expect_annotations<A: AsRef<[u8]>, I: IntoRawAnnotations>(
-        reader: &RawBinaryReader<A>,
-        annotations: I,
-    ) {
-        let expected = annotations.into_annotations();
+    fn expect_annotations<A: AsRef<[u8]>>(reader: &RawBinaryReader<A>, annotations: &[SymbolId]) {
+        let expected: Vec<RawSymbolToken> =
+            annotations.iter().copied().map(local_sid_token).collect();
         let actual = reader
             .annotations_iter()
             .collect::<IonResult<Vec<RawSymbolToken>>>()
@@ -1764,13 +1817,13 @@ mod tests {
         let mut reader = RawBinaryReader::new(data);
 
         expect_value(reader.next(), IonType::Int);
-        expect_annotations(&reader, [4]);
+        expect_annotations(&reader, &[4]);
 
         expect_value(reader.next(), IonType::Int);
-        expect_annotations(&reader, [5]);
+        expect_annotations(&reader, &[5]);
 
         expect_value(reader.next(), IonType::Int);
-        expect_annotations(&reader, [6, 7, 8]);
+        expect_annotations(&reader, &[6, 7, 8]);
         // Nothing else in the buffer
         expect_eof(reader.next());
         Ok(())
@@ -1,51 +1,132 @@
-use std::io::Write;
-use std::ops::Range;
-use std::{io, mem};
+use crate::io::Write;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::ops::Range;
 
-use bytes::BufMut;
 use num_bigint::Sign;
 use num_traits::Zero;
 
 use crate::binary::constants::v1_0::IVM;
-use crate::binary::uint::DecodedUInt;
 use crate::binary::var_uint::VarUInt;
 use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
 use crate::result::{illegal_operation, IonResult};
-use crate::types::{ContainerType, Decimal, SymbolId, Timestamp};
+use crate::types::{reject_symbol_handle, ContainerType, Decimal, FloatWidth, SymbolId, Timestamp};
 use crate::writer::IonWriter;
 use crate::{Int, IonType};
 
+#[cfg(feature = "std")]
+use super::buffer_pool::BufferPool;
 use super::decimal::DecimalBinaryEncoder;
+use super::spill::SpillStorage;
 use super::timestamp::TimestampBinaryEncoder;
 use super::uint;
 
 pub struct RawBinaryWriterBuilder {
-    // Nothing yet
+    spill: Option<Spill>,
+    #[cfg(feature = "std")]
+    buffer_pool: Option<BufferPool>,
+    #[cfg(feature = "std")]
+    vectored_writes: bool,
 }
 
 impl RawBinaryWriterBuilder {
     pub fn new() -> Self {
-        RawBinaryWriterBuilder {}
+        RawBinaryWriterBuilder {
+            spill: None,
+            #[cfg(feature = "std")]
+            buffer_pool: None,
+            #[cfg(feature = "std")]
+            vectored_writes: false,
+        }
+    }
+
+    /// Causes the writer to take its encoding buffers from `pool` and to return them to `pool`
+    /// when it is dropped.
+    #[cfg(feature = "std")]
+    pub fn with_buffer_pool(mut self, pool: BufferPool) -> Self {
+        self.buffer_pool = Some(pool);
+        self
+    }
+
+    /// If `enabled` is `true`, `flush()` passes the buffered data to the sink's
+    /// [`write_vectored`](std::io::Write::write_vectored) method as a list of slices instead of
+    /// first copying it into a contiguous buffer. This is only faster for sinks that override
+    /// `write_vectored`, such as files and sockets; the default implementation writes one slice
+    /// per call. (Rust does not yet offer a stable way to ask a sink whether it does.)
+    #[cfg(feature = "std")]
+    pub fn with_vectored_writes(mut self, enabled: bool) -> Self {
+        self.vectored_writes = enabled;
+        self
+    }
+
+    /// Limits the writer's encoding buffer to approximately `max_buffer_size` bytes. Binary Ion
+    /// prefixes each container with its length, so the writer normally holds an entire top-level
+    /// value in memory until it is complete. With this setting, the writer instead moves the
+    /// encoded contents of the value to `storage` whenever the buffer grows past
+    /// `max_buffer_size`, and copies them from `storage` to the output when `flush()` is called.
+    /// The output is identical either way.
+    ///
+    /// ```
+    /// use ion_rs::binary::raw_binary_writer::RawBinaryWriterBuilder;
+    /// use ion_rs::{IonResult, IonType, IonWriter};
+    /// # fn main() -> IonResult<()> {
+    /// let mut writer = RawBinaryWriterBuilder::new()
+    ///     .with_spill_storage(64 * 1024, tempfile::tempfile()?)
+    ///     .build(Vec::new())?;
+    /// writer.write_ion_version_marker(1, 0)?;
+    /// writer.step_in(IonType::List)?;
+    /// for value in 0..100_000 {
+    ///     writer.write_i64(value)?;
+    /// }
+    /// writer.step_out()?;
+    /// writer.flush()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_spill_storage<S>(mut self, max_buffer_size: usize, storage: S) -> Self
+    where
+        S: SpillStorage + Send + Sync + 'static,
+    {
+        self.spill = Some(Spill {
+            storage: Box::new(storage),
+            max_buffer_size,
+            length: 0,
+        });
+        self
     }
 
     /// Creates a new RawBinaryWriter that will write its encoded output to the provided
     /// io::Write sink.
     pub fn build<W: Write>(self, out: W) -> IonResult<RawBinaryWriter<W>> {
+        #[cfg(feature = "std")]
+        let mut buffers = match self.buffer_pool {
+            Some(pool) => {
+                let mut buffers = pool.take().unwrap_or_else(EncodingBuffers::new);
+                buffers.pool = Some(pool);
+                buffers
+            }
+            None => EncodingBuffers::new(),
+        };
+        #[cfg(not(feature = "std"))]
+        let mut buffers = EncodingBuffers::new();
         let mut levels = Vec::with_capacity(INITIAL_ENCODING_LEVELS_CAPACITY);
         // Create an EncodingLevel to represent the top level. It has no annotations.
         levels.push(EncodingLevel::new(ContainerType::TopLevel, None, 0, 0));
         // Create an empty IoRange for top-level leading scalar values.
-        let mut io_ranges = Vec::with_capacity(INITIAL_IO_RANGE_CAPACITY);
-        io_ranges.push(0usize..0);
+        buffers.io_ranges.push(IoRange::Buffered(0..0));
         let raw_binary_writer = RawBinaryWriter {
-            buffer: Vec::with_capacity(INITIAL_ENCODING_BUFFER_CAPACITY),
-            io_ranges,
+            buffers,
             levels,
             out,
             annotations_all_levels: Vec::with_capacity(INITIAL_ANNOTATIONS_CAPACITY),
             num_annotations_current_value: 0,
             field_id: None,
-            contiguous_encoding: Vec::with_capacity(INITIAL_ENCODING_BUFFER_CAPACITY),
+            spill: self.spill,
+            #[cfg(feature = "std")]
+            vectored_writes: self.vectored_writes,
         };
 
         // Currently, this method cannot fail. However, the other builder APIs return an
@@ -73,7 +154,24 @@ impl Default for RawBinaryWriterBuilder {
 // which slices of the buffer should be written out first by maintaining a queue of Range<usize>
 // entries. These entries are referred to as `IoRange`s, as they are the buffer ranges that
 // are actually sent to `io::write` whenever `flush()` is called.
-type IoRange = Range<usize>;
+//
+// If the writer has been configured with `SpillStorage`, the buffered ranges may be moved to that
+// storage before they are flushed; see `RawBinaryWriter::spill`.
+#[derive(Debug, Clone)]
+enum IoRange {
+    // A range of the writer's `buffer`.
+    Buffered(Range<usize>),
+    // A range of the writer's `SpillStorage`.
+    Spilled(Range<usize>),
+}
+
+impl IoRange {
+    fn len(&self) -> usize {
+        match self {
+            IoRange::Buffered(range) | IoRange::Spilled(range) => range.len(),
+        }
+    }
+}
 
 // When encoding the above example data, the buffer with out-of-order byte sequences might look
 // like this:
@@ -100,6 +198,65 @@ type IoRange = Range<usize>;
 //
 //     e0 01 00 ea eb 81 81 b8 83 66 6f 6f 83 62 61 72
 
+// The buffers in which a RawBinaryWriter encodes its data, which can be reused by another writer.
+#[derive(Debug)]
+pub(crate) struct EncodingBuffers {
+    // A byte buffer to encode individual components of the stream.
+    buffer: Vec<u8>,
+    // Slices of the buffer to write out in order when flush() is called.
+    io_ranges: Vec<IoRange>,
+    // Scratch space for the flush() method to rearrange the contents of `buffer` before writing
+    // the data to `out`.
+    contiguous_encoding: Vec<u8>,
+    // The pool to which the buffers are returned when they are dropped.
+    #[cfg(feature = "std")]
+    pool: Option<BufferPool>,
+}
+
+impl EncodingBuffers {
+    fn new() -> EncodingBuffers {
+        EncodingBuffers {
+            buffer: Vec::with_capacity(INITIAL_ENCODING_BUFFER_CAPACITY),
+            io_ranges: Vec::with_capacity(INITIAL_IO_RANGE_CAPACITY),
+            contiguous_encoding: Vec::with_capacity(INITIAL_ENCODING_BUFFER_CAPACITY),
+            #[cfg(feature = "std")]
+            pool: None,
+        }
+    }
+
+    // Empties each of the buffers without releasing their memory.
+    pub(crate) fn clear(&mut self) {
+        self.buffer.clear();
+        self.io_ranges.clear();
+        self.contiguous_encoding.clear();
+    }
+
+    // Releases memory so that each of the buffers occupies at most about `max_bytes` bytes.
+    #[cfg(feature = "std")]
+    pub(crate) fn shrink_to(&mut self, max_bytes: usize) {
+        self.buffer.shrink_to(max_bytes);
+        self.io_ranges
+            .shrink_to(max_bytes / mem::size_of::<IoRange>().max(1));
+        self.contiguous_encoding.shrink_to(max_bytes);
+    }
+}
+
+// Returning the buffers from this Drop impl rather than one on RawBinaryWriter means that a writer
+// does not need to be dropped before its borrowed output can be used.
+#[cfg(feature = "std")]
+impl Drop for EncodingBuffers {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.take() {
+            pool.put(EncodingBuffers {
+                buffer: mem::take(&mut self.buffer),
+                io_ranges: mem::take(&mut self.io_ranges),
+                contiguous_encoding: mem::take(&mut self.contiguous_encoding),
+                pool: None,
+            });
+        }
+    }
+}
+
 // Stores information about each level into which the writer has stepped, including its
 // annotations, field_id, and container type.
 #[derive(Debug)]
@@ -113,7 +270,24 @@ struct EncodingLevel {
     num_annotations: u8,
     // Index of the IoRange for this container's type descriptor and length. When the writer
     // steps out of this level, the type descriptor IoRange will be retrieved and populated.
+    // Once the level has been spilled, this is instead the index of the first IoRange holding
+    // contents that are still buffered.
     td_io_range_index: usize,
+    // Where this level's data is in the SpillStorage, if any of it has been spilled.
+    spilled: Option<SpilledLevel>,
+}
+
+// The offsets in the SpillStorage that belong to a level whose contents have been spilled. Each
+// container reserves space before its contents that is large enough for any header it could need.
+// When the writer steps out of the container, the header is written to that space and the data on
+// one side of it is moved over to close the gap; see `RawBinaryWriter::write_spilled_header`.
+#[derive(Debug, Clone, Copy)]
+struct SpilledLevel {
+    // The start of the space reserved for the container's annotations wrapper and header. For the
+    // top level, which has no header, this is equal to `content_start`.
+    header_start: usize,
+    // The start of the level's spilled contents.
+    content_start: usize,
 }
 
 impl EncodingLevel {
@@ -128,16 +302,37 @@ impl EncodingLevel {
             field_id,
             num_annotations,
             td_io_range_index,
+            spilled: None,
         }
     }
 
-    // Visits all of the IoRanges belonging to this EncodingLevel and notes their total length.
+    // Visits all of the IoRanges belonging to this EncodingLevel and notes their total length,
+    // adding the length of any contents that have been spilled. Everything in the SpillStorage
+    // from the start of those contents to `spill_length` belongs to the innermost level.
     // This length will be written out as a length prefix for the container.
-    fn calculate_final_size(&self, io_ranges: &mut [Range<usize>]) -> usize {
-        io_ranges[self.td_io_range_index..]
+    fn calculate_final_size(&self, io_ranges: &[IoRange], spill_length: usize) -> usize {
+        let spilled_size = self
+            .spilled
+            .map_or(0, |spilled| spill_length - spilled.content_start);
+        let buffered_size: usize = io_ranges[self.td_io_range_index..]
             .iter()
             .map(|r| r.len())
-            .sum()
+            .sum();
+        spilled_size + buffered_size
+    }
+
+    // The largest number of bytes that this container's annotations wrapper and header could
+    // occupy.
+    fn max_header_length(&self) -> usize {
+        // The type descriptor and length
+        let header_length = 1 + MAX_VAR_UINT_LENGTH;
+        if self.num_annotations == 0 {
+            return header_length;
+        }
+        // The wrapper's type descriptor and length, the length of the annotations sequence and
+        // the sequence itself
+        let wrapper_length = 1 + MAX_VAR_UINT_LENGTH * (2 + self.num_annotations as usize);
+        header_length + wrapper_length
     }
 }
 
@@ -149,10 +344,8 @@ impl EncodingLevel {
 /// [RawBinaryWriter::write_ion_version_marker] before writing any data.
 #[derive(Debug)]
 pub struct RawBinaryWriter<W: Write> {
-    // A byte buffer to encode individual components of the stream.
-    buffer: Vec<u8>,
-    // Slices of the buffer to write out in order when flush() is called.
-    io_ranges: Vec<IoRange>,
+    // The buffer in which the stream is encoded and the ranges of it to be written out.
+    buffers: EncodingBuffers,
     // Stack for tracking step_in/step_out
     levels: Vec<EncodingLevel>,
     // An io::Write implementation to be used as a sink for encoded data.
@@ -165,15 +358,40 @@ pub struct RawBinaryWriter<W: Write> {
     // The number of annotations at the tail of `annotations_all_levels` belonging to the current
     // value.
     num_annotations_current_value: u8,
-    // Scratch space for the flush() method to rearrange the contents of `buffer` before writing
-    // the data to `out`.
-    contiguous_encoding: Vec<u8>,
+    // Storage for encoded data that has been moved out of `buffer` to limit its size.
+    spill: Option<Spill>,
+    // Whether flush() should use io::Write::write_vectored.
+    #[cfg(feature = "std")]
+    vectored_writes: bool,
+}
+
+// The SpillStorage configured by `RawBinaryWriterBuilder::with_spill_storage`.
+struct Spill {
+    // The storage is boxed so that spilling does not add a type parameter to every writer. It must
+    // be Send and Sync so that the writer is too.
+    storage: Box<dyn SpillStorage + Send + Sync>,
+    // The size of `buffer` past which its contents are moved to `storage`.
+    max_buffer_size: usize,
+    // The offset just past the last byte in `storage` that has not been flushed.
+    length: usize,
+}
+
+impl fmt::Debug for Spill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spill")
+            .field("max_buffer_size", &self.max_buffer_size)
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
 }
 
 // The largest possible 'L' (length) value that can be written directly in a type descriptor byte.
 // Larger length values will need to be written as a VarUInt following the type descriptor.
 pub(crate) const MAX_INLINE_LENGTH: usize = 13;
 
+// A VarUInt holds 7 bits per byte, so encoding a u64 takes at most 10 bytes.
+const MAX_VAR_UINT_LENGTH: usize = 10;
+
 // The number of IoRanges needed to write out an annotations wrapper, not including the IoRange
 // belonging to the wrapped value. (One IoRange for each of: the annotations sequence, the length
 // of the annotations sequence, and the annotations wrapper header.)
@@ -184,6 +402,11 @@ const INITIAL_ENCODING_BUFFER_CAPACITY: usize = 8 * 1024;
 const INITIAL_ENCODING_LEVELS_CAPACITY: usize = 16;
 const INITIAL_IO_RANGE_CAPACITY: usize = 128;
 const INITIAL_ANNOTATIONS_CAPACITY: usize = 4;
+// The smallest number of bytes that flush() will copy from SpillStorage at a time.
+const MIN_SPILL_CHUNK_SIZE: usize = 4 * 1024;
+// The largest number of slices that flush() will pass to a single io::Write::write_vectored call.
+#[cfg(feature = "std")]
+const MAX_IO_SLICES: usize = 64;
 
 impl<W: Write> RawBinaryWriter<W> {
     // Uses the provided closure to encode data to the buffer. Returns the range of the buffer
@@ -192,10 +415,10 @@ impl<W: Write> RawBinaryWriter<W> {
     fn encode_to_buffer(
         &mut self,
         mut encode_fn: impl FnMut(&mut Self) -> IonResult<()>,
-    ) -> IonResult<IoRange> {
-        let start = self.buffer.len();
+    ) -> IonResult<Range<usize>> {
+        let start = self.buffers.buffer.len();
         encode_fn(self)?;
-        let end = self.buffer.len();
+        let end = self.buffers.buffer.len();
         Ok(start..end)
     }
 
@@ -213,11 +436,12 @@ impl<W: Write> RawBinaryWriter<W> {
     // a new one.
     #[inline]
     fn extend_last_range(&mut self, number_of_bytes: usize) {
-        let last_range = self
-            .io_ranges
-            .last_mut()
-            .expect("io_ranges unexpectedly empty.");
-        last_range.end += number_of_bytes;
+        match self.buffers.io_ranges.last_mut() {
+            Some(IoRange::Buffered(last_range)) => last_range.end += number_of_bytes,
+            // Spilling always leaves an empty buffered range at the end.
+            Some(IoRange::Spilled(_)) => unreachable!("the last IoRange was spilled"),
+            None => panic!("io_ranges unexpectedly empty."),
+        }
     }
 
     // Handles before-and-after tasks common to writing all non-container values, like encoding
@@ -229,19 +453,43 @@ impl<W: Write> RawBinaryWriter<W> {
         // If we're in a struct, encode the field ID first.
         if self.is_in_struct() {
             let field_id = self.expect_field_id()? as u64;
-            let bytes_written = VarUInt::write_u64(&mut self.buffer, field_id)?;
+            let bytes_written = VarUInt::write_u64(&mut self.buffers.buffer, field_id)?;
             self.extend_last_range(bytes_written);
             self.field_id = None;
         }
 
         if self.has_annotations() {
-            return self.encode_annotated_scalar(write_fn);
+            self.encode_annotated_scalar(write_fn)?;
+        } else {
+            let encoded_range =
+                self.encode_to_buffer(|writer| write_fn(&mut writer.buffers.buffer))?;
+            self.extend_last_range(encoded_range.len());
         }
 
-        let encoded_range = self.encode_to_buffer(|writer| write_fn(&mut writer.buffer))?;
-        self.extend_last_range(encoded_range.len());
+        self.spill_if_needed()
+    }
 
-        Ok(())
+    // Writes an Ion integer with the specified sign and magnitude without allocating.
+    fn write_int_with_magnitude(&mut self, is_negative: bool, magnitude: u128) -> IonResult<()> {
+        self.write_scalar(|enc_buffer| {
+            let encoded = uint::encode_u128(magnitude);
+            let bytes_to_write = encoded.as_bytes();
+
+            let mut type_descriptor: u8 = if is_negative { 0x30 } else { 0x20 };
+            // A u128 can take up to 16 bytes, which is too long to fit in the type descriptor.
+            let encoded_length = bytes_to_write.len();
+            if encoded_length <= 13 {
+                type_descriptor |= encoded_length as u8;
+                enc_buffer.push(type_descriptor);
+            } else {
+                type_descriptor |= 0xEu8;
+                enc_buffer.push(type_descriptor);
+                VarUInt::write_u64(enc_buffer, encoded_length as u64)?;
+            }
+            enc_buffer.extend_from_slice(bytes_to_write);
+
+            Ok(())
+        })
     }
 
     // Uses the provided closure to encode a scalar value, then encodes the annotation wrapper
@@ -251,8 +499,8 @@ impl<W: Write> RawBinaryWriter<W> {
         mut scalar_write_fn: impl FnMut(&mut Vec<u8>) -> IonResult<()>,
     ) -> IonResult<()> {
         // Encode the scalar into the buffer, but do not push the IoRange yet.
-        let value_io_range: IoRange =
-            self.encode_to_buffer(|writer| scalar_write_fn(&mut writer.buffer))?;
+        let value_io_range: Range<usize> =
+            self.encode_to_buffer(|writer| scalar_write_fn(&mut writer.buffers.buffer))?;
 
         // Create ranges that will ultimately point to the encoded components of the annotations
         // wrapper for the value.
@@ -271,11 +519,11 @@ impl<W: Write> RawBinaryWriter<W> {
 
         // Push the IO ranges in the correct order so the encoded bytes will be written in the
         // correct order when the user calls `flush()`.
-        self.io_ranges.extend_from_slice(&[
-            header_io_range,
-            annotations_seq_length_io_range,
-            annotations_seq_io_range,
-            value_io_range,
+        self.buffers.io_ranges.extend_from_slice(&[
+            IoRange::Buffered(header_io_range),
+            IoRange::Buffered(annotations_seq_length_io_range),
+            IoRange::Buffered(annotations_seq_io_range),
+            IoRange::Buffered(value_io_range),
         ]);
 
         self.push_empty_io_range();
@@ -288,9 +536,9 @@ impl<W: Write> RawBinaryWriter<W> {
     // the populated IoRanges in the necessary order.
     fn encode_annotation_wrapper(
         &mut self,
-        header_io_range: &mut IoRange,
-        annotations_seq_length_io_range: &mut IoRange,
-        annotations_seq_io_range: &mut IoRange,
+        header_io_range: &mut Range<usize>,
+        annotations_seq_length_io_range: &mut Range<usize>,
+        annotations_seq_io_range: &mut Range<usize>,
         wrapped_value_length: usize,
     ) -> IonResult<()> {
         // Encode the sequence of annotations and make a note of the encoded length.
@@ -302,7 +550,7 @@ impl<W: Write> RawBinaryWriter<W> {
                 let range = writer.current_value_annotations_range();
                 let annotations = &writer.annotations_all_levels[range];
                 for annotation_id in annotations {
-                    VarUInt::write_u64(&mut writer.buffer, *annotation_id as u64)?;
+                    VarUInt::write_u64(&mut writer.buffers.buffer, *annotation_id as u64)?;
                 }
                 Ok(())
             })?,
@@ -314,7 +562,7 @@ impl<W: Write> RawBinaryWriter<W> {
             annotations_seq_length_io_range,
             self.encode_to_buffer(|writer| {
                 let _num_bytes = VarUInt::write_u64(
-                    &mut writer.buffer,
+                    &mut writer.buffers.buffer,
                     annotation_sequence_encoded_length as u64,
                 )?;
                 Ok(())
@@ -337,11 +585,11 @@ impl<W: Write> RawBinaryWriter<W> {
                 if wrapper_length <= MAX_INLINE_LENGTH {
                     // Use inline length encoding
                     type_descriptor = 0xE0 | wrapper_length as u8;
-                    writer.buffer.push(type_descriptor);
+                    writer.buffers.buffer.push(type_descriptor);
                 } else {
                     type_descriptor = 0xEE; // VarUInt length encoding
-                    writer.buffer.push(type_descriptor);
-                    VarUInt::write_u64(&mut writer.buffer, wrapper_length as u64)?;
+                    writer.buffers.buffer.push(type_descriptor);
+                    VarUInt::write_u64(&mut writer.buffers.buffer, wrapper_length as u64)?;
                 }
                 Ok(())
             })?,
@@ -379,10 +627,8 @@ impl<W: Write> RawBinaryWriter<W> {
 
     pub fn write_symbol_id(&mut self, symbol_id: SymbolId) -> IonResult<()> {
         self.write_scalar(|enc_buffer| {
-            const SYMBOL_BUFFER_SIZE: usize = mem::size_of::<u64>();
-            let mut buffer = [0u8; SYMBOL_BUFFER_SIZE];
-            let mut writer = io::Cursor::new(&mut buffer).writer();
-            let encoded_length = DecodedUInt::write_u64(&mut writer, symbol_id as u64)?;
+            let encoded_symbol_id = uint::encode_u64(symbol_id as u64);
+            let encoded_length = encoded_symbol_id.as_bytes().len();
 
             let type_descriptor: u8;
             if encoded_length <= MAX_INLINE_LENGTH {
@@ -393,8 +639,7 @@ impl<W: Write> RawBinaryWriter<W> {
                 enc_buffer.push(type_descriptor);
                 VarUInt::write_u64(enc_buffer, encoded_length as u64)?;
             }
-            let raw_buffer = writer.into_inner().into_inner();
-            enc_buffer.extend_from_slice(&raw_buffer[..encoded_length]);
+            enc_buffer.extend_from_slice(encoded_symbol_id.as_bytes());
             Ok(())
         })
     }
@@ -416,8 +661,10 @@ impl<W: Write> RawBinaryWriter<W> {
 
     // Creates an empty IoRange starting from the next unoccupied byte in the buffer.
     fn push_empty_io_range(&mut self) {
-        let next_byte_index = self.buffer.len();
-        self.io_ranges.push(next_byte_index..next_byte_index);
+        let next_byte_index = self.buffers.buffer.len();
+        self.buffers
+            .io_ranges
+            .push(IoRange::Buffered(next_byte_index..next_byte_index));
     }
 
     pub fn set_field_id(&mut self, field_id: SymbolId) {
@@ -457,18 +704,21 @@ impl<W: Write> RawBinaryWriter<W> {
 
         // Populate each of the reserved annotation IO ranges using the results from above.
         let header_io_range_index = td_io_range_index - IO_RANGES_PER_ANNOTATION_WRAPPER;
-        let _ = mem::replace(&mut self.io_ranges[header_io_range_index], header_io_range);
+        let _ = mem::replace(
+            &mut self.buffers.io_ranges[header_io_range_index],
+            IoRange::Buffered(header_io_range),
+        );
 
         let annotations_seq_length_io_range_index = header_io_range_index + 1;
         let _ = mem::replace(
-            &mut self.io_ranges[annotations_seq_length_io_range_index],
-            annotations_seq_length_io_range,
+            &mut self.buffers.io_ranges[annotations_seq_length_io_range_index],
+            IoRange::Buffered(annotations_seq_length_io_range),
         );
 
         let annotations_seq_io_range_index = header_io_range_index + 2;
         let _ = mem::replace(
-            &mut self.io_ranges[annotations_seq_io_range_index],
-            annotations_seq_io_range,
+            &mut self.buffers.io_ranges[annotations_seq_io_range_index],
+            IoRange::Buffered(annotations_seq_io_range),
         );
 
         Ok(())
@@ -495,15 +745,320 @@ impl<W: Write> RawBinaryWriter<W> {
         self.push_empty_io_range();
     }
 
-    pub fn add_annotation<A: AsRawSymbolTokenRef>(&mut self, annotation: A) {
+    // For each io_range in order, copies the specified bytes into a contiguous buffer, then writes
+    // the buffer to output.
+    fn write_io_ranges_contiguous(&mut self) -> IonResult<()> {
+        for io_range in self.buffers.io_ranges.drain(..) {
+            match io_range {
+                IoRange::Buffered(range) => {
+                    self.buffers
+                        .contiguous_encoding
+                        .extend_from_slice(&self.buffers.buffer[range]);
+                }
+                IoRange::Spilled(range) => {
+                    // Write out the bytes preceding the spilled range, then copy the spilled range.
+                    self.out
+                        .write_all(self.buffers.contiguous_encoding.as_slice())?;
+                    let spill = self.spill.as_mut().expect("spilled without SpillStorage");
+                    Self::copy_spilled(
+                        spill,
+                        range,
+                        &mut self.buffers.contiguous_encoding,
+                        &mut self.out,
+                    )?;
+                }
+            }
+        }
+        self.out
+            .write_all(self.buffers.contiguous_encoding.as_slice())?;
+        self.buffers.contiguous_encoding.clear();
+        Ok(())
+    }
+
+    // Writes each io_range in order by passing batches of slices of the buffer to
+    // io::Write::write_vectored.
+    #[cfg(feature = "std")]
+    fn write_io_ranges_vectored(&mut self) -> IonResult<()> {
+        let mut slices = arrayvec::ArrayVec::<std::io::IoSlice, MAX_IO_SLICES>::new();
+        for io_range in self.buffers.io_ranges.drain(..) {
+            match io_range {
+                IoRange::Buffered(range) if range.is_empty() => {}
+                IoRange::Buffered(range) => {
+                    if slices.is_full() {
+                        Self::write_all_vectored(&mut self.out, &mut slices)?;
+                    }
+                    slices.push(std::io::IoSlice::new(&self.buffers.buffer[range]));
+                }
+                IoRange::Spilled(range) => {
+                    Self::write_all_vectored(&mut self.out, &mut slices)?;
+                    let spill = self.spill.as_mut().expect("spilled without SpillStorage");
+                    Self::copy_spilled(
+                        spill,
+                        range,
+                        &mut self.buffers.contiguous_encoding,
+                        &mut self.out,
+                    )?;
+                }
+            }
+        }
+        Self::write_all_vectored(&mut self.out, &mut slices)
+    }
+
+    // Writes all of the (non-empty) `slices` to `out` and then clears `slices`. This is
+    // equivalent to io::Write::write_all_vectored, which is not yet stable.
+    #[cfg(feature = "std")]
+    fn write_all_vectored(
+        out: &mut W,
+        slices: &mut arrayvec::ArrayVec<std::io::IoSlice, MAX_IO_SLICES>,
+    ) -> IonResult<()> {
+        let mut index = 0;
+        while index < slices.len() {
+            let mut bytes_written = match out.write_vectored(&slices[index..]) {
+                Ok(bytes_written) => bytes_written,
+                // Like io::Write::write_all, retry writes that were interrupted.
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error.into()),
+            };
+            if bytes_written == 0 {
+                return Err(std::io::Error::from(std::io::ErrorKind::WriteZero).into());
+            }
+            // Skip past the slices that were written in full...
+            while index < slices.len() && bytes_written >= slices[index].len() {
+                bytes_written -= slices[index].len();
+                index += 1;
+            }
+            // ...and finish writing the one that was written in part, if any.
+            if bytes_written > 0 {
+                out.write_all(&slices[index][bytes_written..])?;
+                index += 1;
+            }
+        }
+        slices.clear();
+        Ok(())
+    }
+
+    // Copies `range` from the SpillStorage to `out` in chunks no larger than the buffer, using
+    // `chunk` as scratch space.
+    fn copy_spilled(
+        spill: &mut Spill,
+        range: Range<usize>,
+        chunk: &mut Vec<u8>,
+        out: &mut W,
+    ) -> IonResult<()> {
+        let chunk_size = spill.max_buffer_size.max(MIN_SPILL_CHUNK_SIZE);
+        let mut offset = range.start;
+        while offset < range.end {
+            let chunk_length = chunk_size.min(range.end - offset);
+            chunk.resize(chunk_length, 0);
+            spill.storage.read_at(offset, chunk.as_mut_slice())?;
+            out.write_all(chunk.as_slice())?;
+            offset += chunk_length;
+        }
+        chunk.clear();
+        Ok(())
+    }
+
+    // Spills the buffer if it has grown larger than the configured limit.
+    #[inline]
+    fn spill_if_needed(&mut self) -> IonResult<()> {
+        match &self.spill {
+            Some(spill) if self.buffers.buffer.len() > spill.max_buffer_size => self.spill(),
+            _ => Ok(()),
+        }
+    }
+
+    // Moves the contents of the buffer to the SpillStorage.
+    //
+    // Every IoRange that holds data is final; the only IoRanges that will still change are the
+    // empty placeholders that each open container reserved for its header and annotations
+    // wrapper. The data between those placeholders is appended to the storage in order. In place
+    // of each placeholder, the storage gets a gap large enough for any header the container could
+    // need, which is filled in when the writer steps out of the container. Apart from the gaps of
+    // containers that are still open, the storage holds the spilled data in the order in which it
+    // will be flushed, so a single Spilled range at the start of `io_ranges` covers all of it.
+    fn spill(&mut self) -> IonResult<()> {
+        let spill = self.spill.as_mut().expect("spill() requires SpillStorage");
+        let buffer = &self.buffers.buffer;
+        let io_ranges = &self.buffers.io_ranges;
+        let pending = &mut self.buffers.contiguous_encoding;
+        let mut levels = self.levels.iter_mut();
+        // `levels` always has at least one value: the top level.
+        let top_level = levels.next().unwrap();
+        let top_level_start = top_level
+            .spilled
+            .get_or_insert(SpilledLevel {
+                header_start: spill.length,
+                content_start: spill.length,
+            })
+            .content_start;
+        let mut run_start = 0;
+        for level in levels {
+            // Levels that have already been spilled do not have placeholders in `io_ranges`.
+            if level.spilled.is_some() {
+                continue;
+            }
+            let placeholders_start = if level.num_annotations > 0 {
+                level.td_io_range_index - IO_RANGES_PER_ANNOTATION_WRAPPER
+            } else {
+                level.td_io_range_index
+            };
+            Self::spill_run(
+                spill,
+                buffer,
+                pending,
+                &io_ranges[run_start..placeholders_start],
+            )?;
+            let header_start = spill.length;
+            spill.length += level.max_header_length();
+            level.spilled = Some(SpilledLevel {
+                header_start,
+                content_start: spill.length,
+            });
+            run_start = level.td_io_range_index + 1;
+        }
+        Self::spill_run(spill, buffer, pending, &io_ranges[run_start..])?;
+
+        let spilled_range = top_level_start..spill.length;
+        self.buffers.io_ranges.clear();
+        self.buffers.io_ranges.push(IoRange::Spilled(spilled_range));
+        self.buffers.buffer.clear();
+        self.push_empty_io_range();
+        // Each open container's remaining contents start in the empty range after the spilled one.
+        for level in self.levels.iter_mut().skip(1) {
+            level.td_io_range_index = 1;
+        }
+        Ok(())
+    }
+
+    // Appends the buffered bytes of `run` to the SpillStorage.
+    fn spill_run(
+        spill: &mut Spill,
+        buffer: &[u8],
+        pending: &mut Vec<u8>,
+        run: &[IoRange],
+    ) -> IonResult<()> {
+        for io_range in run {
+            // A Spilled range's data is already in the storage, just before the end.
+            if let IoRange::Buffered(range) = io_range {
+                pending.extend_from_slice(&buffer[range.clone()]);
+            }
+        }
+        if pending.is_empty() {
+            return Ok(());
+        }
+        spill.storage.write_at(spill.length, pending)?;
+        spill.length += pending.len();
+        pending.clear();
+        Ok(())
+    }
+
+    // Writes the annotations wrapper and header of a container whose contents were spilled to the
+    // gap that was reserved for them. `header_io_range` is the range of the buffer holding the
+    // encoded type descriptor and length. The header rarely fills the gap, so either the parent's
+    // preceding data or the container's contents are moved to meet it, whichever is smaller. Each
+    // time a byte is moved, the run it belongs to at least doubles in size, so no byte is moved
+    // more than a logarithmic number of times.
+    fn write_spilled_header(
+        &mut self,
+        spilled: SpilledLevel,
+        header_io_range: Range<usize>,
+        container_size: usize,
+        is_annotated: bool,
+    ) -> IonResult<()> {
+        let header_start = header_io_range.start;
+        let mut io_ranges = [0..0, 0..0, 0..0, header_io_range];
+        if is_annotated {
+            let [wrapper_header, annotations_seq_length, annotations_seq, _] = &mut io_ranges;
+            self.encode_annotation_wrapper(
+                wrapper_header,
+                annotations_seq_length,
+                annotations_seq,
+                container_size,
+            )?;
+        }
+        let header = &mut self.buffers.contiguous_encoding;
+        for range in io_ranges {
+            header.extend_from_slice(&self.buffers.buffer[range]);
+        }
+        self.buffers.buffer.truncate(header_start);
+
+        let spill = self.spill.as_mut().expect("spilled without SpillStorage");
+        // The parent level was spilled along with this one, and everything in the storage from
+        // the start of its contents to this container's gap is the data that precedes it.
+        let parent = self
+            .levels
+            .last_mut()
+            .and_then(|level| level.spilled.as_mut())
+            .expect("the parent of a spilled container was not spilled");
+        let preceding = parent.content_start..spilled.header_start;
+        let contents = spilled.content_start..spill.length;
+        if preceding.len() <= contents.len() {
+            // Write the header just before the contents, then move the preceding data up to it.
+            let new_header_start = spilled.content_start - header.len();
+            spill.storage.write_at(new_header_start, header)?;
+            let distance = new_header_start - spilled.header_start;
+            Self::move_spilled(spill, preceding.clone(), preceding.start + distance, header)?;
+            parent.content_start += distance;
+        } else {
+            // Write the header just after the preceding data, then move the contents down to it.
+            spill.storage.write_at(spilled.header_start, header)?;
+            let new_contents_start = spilled.header_start + header.len();
+            Self::move_spilled(spill, contents, new_contents_start, header)?;
+            spill.length -= spilled.content_start - new_contents_start;
+        }
+        header.clear();
+        Ok(())
+    }
+
+    // Moves `range` of the SpillStorage to start at `destination`, using `chunk` as scratch
+    // space. The ranges may overlap.
+    fn move_spilled(
+        spill: &mut Spill,
+        range: Range<usize>,
+        destination: usize,
+        chunk: &mut Vec<u8>,
+    ) -> IonResult<()> {
+        let chunk_size = spill.max_buffer_size.max(MIN_SPILL_CHUNK_SIZE);
+        let mut remaining = range.clone();
+        while !remaining.is_empty() {
+            let chunk_length = chunk_size.min(remaining.len());
+            // Copy the chunk that will not be overwritten by the copy itself: the last chunk when
+            // moving data up, and the first when moving it down.
+            let chunk_start = if destination > range.start {
+                remaining.end - chunk_length
+            } else {
+                remaining.start
+            };
+            chunk.resize(chunk_length, 0);
+            spill.storage.read_at(chunk_start, chunk.as_mut_slice())?;
+            spill
+                .storage
+                .write_at(destination + (chunk_start - range.start), chunk.as_slice())?;
+            if destination > range.start {
+                remaining.end -= chunk_length;
+            } else {
+                remaining.start += chunk_length;
+            }
+        }
+        chunk.clear();
+        Ok(())
+    }
+
+    /// Adds an annotation to the next value that is written. Returns an `Err` if `annotation` is
+    /// text rather than a symbol ID, or if it is a [`SymbolHandle`](crate::SymbolHandle).
+    pub fn add_annotation<A: AsRawSymbolTokenRef>(&mut self, annotation: A) -> IonResult<()> {
+        reject_symbol_handle(&annotation, "RawBinaryWriter")?;
         let symbol_id = match annotation.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(symbol_id) => symbol_id,
-            RawSymbolTokenRef::Text(text) => panic!(
+            RawSymbolTokenRef::Text(text) => {
+                return illegal_operation(format!(
                 "The RawBinaryWriter can only accept symbol ID annotations, not text ('{text}')."
-            ),
+            ))
+            }
         };
         self.annotations_all_levels.push(symbol_id);
         self.num_annotations_current_value += 1;
+        Ok(())
     }
 }
 
@@ -531,15 +1086,19 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         false
     }
 
-    fn set_annotations<I, A>(&mut self, annotations: I)
+    fn set_annotations<I, A>(&mut self, annotations: I) -> IonResult<()>
     where
         A: AsRawSymbolTokenRef,
         I: IntoIterator<Item = A>,
     {
         self.clear_annotations();
         for annotation in annotations {
-            self.add_annotation(annotation);
+            if let Err(error) = self.add_annotation(annotation) {
+                self.clear_annotations();
+                return Err(error);
+            }
         }
+        Ok(())
     }
 
     /// Writes an Ion null of the specified type.
@@ -597,11 +1156,22 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         })
     }
 
+    /// Writes an Ion integer with the specified value.
+    fn write_u64(&mut self, value: u64) -> IonResult<()> {
+        self.write_int_with_magnitude(false, u128::from(value))
+    }
+
+    /// Writes an Ion integer with the specified value.
+    fn write_i128(&mut self, value: i128) -> IonResult<()> {
+        self.write_int_with_magnitude(value < 0, value.unsigned_abs())
+    }
+
     /// Writes an Ion integer with the specified value.
     fn write_int(&mut self, value: &Int) -> IonResult<()> {
-        // If the `value` is an `i64`, use `write_i64` and return.
+        // If the `value` fits in a primitive, use the corresponding method and return.
         let value = match value {
             Int::I64(i) => return self.write_i64(*i),
+            Int::I128(i) => return self.write_i128(i.get()),
             Int::BigInt(i) => i,
         };
 
@@ -663,6 +1233,28 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         })
     }
 
+    /// Writes an Ion float with the specified value using an encoding of exactly the specified
+    /// width. If `width` is [`FloatWidth::Empty`] but `value` is not positive zero, the value is
+    /// written as an 8-byte float.
+    fn write_float_with_width(&mut self, value: f64, width: FloatWidth) -> IonResult<()> {
+        self.write_scalar(|enc_buffer| {
+            match width {
+                FloatWidth::Empty if value == 0f64 && !value.is_sign_negative() => {
+                    enc_buffer.push(0x40);
+                }
+                FloatWidth::F32 => {
+                    enc_buffer.push(0x44);
+                    enc_buffer.extend_from_slice(&(value as f32).to_be_bytes());
+                }
+                FloatWidth::Empty | FloatWidth::F64 => {
+                    enc_buffer.push(0x48);
+                    enc_buffer.extend_from_slice(&value.to_be_bytes());
+                }
+            }
+            Ok(())
+        })
+    }
+
     /// Writes an Ion decimal with the specified value.
     fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
         self.write_scalar(|enc_buffer| {
@@ -680,6 +1272,7 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
     }
 
     fn write_symbol<A: AsRawSymbolTokenRef>(&mut self, value: A) -> IonResult<()> {
+        reject_symbol_handle(&value, "RawBinaryWriter")?;
         match value.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(sid) => self.write_symbol_id(sid),
             RawSymbolTokenRef::Text(_text) => {
@@ -738,7 +1331,7 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         if self.is_in_struct() {
             let field_id_io_range = self.encode_to_buffer(|writer| {
                 let field_id = writer.expect_field_id()? as u64;
-                VarUInt::write_u64(&mut writer.buffer, field_id)?;
+                VarUInt::write_u64(&mut writer.buffers.buffer, field_id)?;
                 Ok(())
             })?;
             self.extend_last_range(field_id_io_range.len());
@@ -752,7 +1345,7 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
 
         // An empty placeholder range that we'll fill in during step_out(). It will point to the
         // type descriptor byte and any length bytes.
-        let header_io_range_index = self.io_ranges.len();
+        let header_io_range_index = self.buffers.io_ranges.len();
         self.push_empty_io_range();
 
         let new_encoding_level = EncodingLevel::new(
@@ -768,16 +1361,22 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         Ok(())
     }
 
-    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) {
+    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) -> IonResult<()> {
         if self.parent_type() != Some(IonType::Struct) {
-            panic!("Attempted to set field name when the writer was not in a struct.");
+            return illegal_operation(
+                "Attempted to set field name when the writer was not in a struct.",
+            );
         }
+        reject_symbol_handle(&name, "RawBinaryWriter")?;
         match name.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(sid) => self.set_field_id(sid),
-            RawSymbolTokenRef::Text(text) => panic!(
+            RawSymbolTokenRef::Text(text) => {
+                return illegal_operation(format!(
                 "The RawBinaryWriter can only accept Symbol ID field names, not text ('{text}')."
-            ),
+            ))
+            }
         }
+        Ok(())
     }
 
     fn parent_type(&self) -> Option<IonType> {
@@ -807,7 +1406,8 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         let container = self.levels.pop().unwrap();
         self.num_annotations_current_value = container.num_annotations;
         self.field_id = container.field_id;
-        let container_size = container.calculate_final_size(&mut self.io_ranges);
+        let spill_length = self.spill.as_ref().map_or(0, |spill| spill.length);
+        let container_size = container.calculate_final_size(&self.buffers.io_ranges, spill_length);
 
         use crate::types::ContainerType::*;
         let mut type_descriptor: u8 = match container.container_type {
@@ -821,11 +1421,11 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         let header_io_range = self.encode_to_buffer(|writer| {
             if container_size <= MAX_INLINE_LENGTH {
                 type_descriptor |= container_size as u8;
-                writer.buffer.push(type_descriptor);
+                writer.buffers.buffer.push(type_descriptor);
             } else {
                 type_descriptor |= 0x0E; // VarUInt encoding
-                writer.buffer.push(type_descriptor);
-                VarUInt::write_u64(&mut writer.buffer, container_size as u64)?;
+                writer.buffers.buffer.push(type_descriptor);
+                VarUInt::write_u64(&mut writer.buffers.buffer, container_size as u64)?;
             }
             Ok(())
         })?;
@@ -834,14 +1434,27 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         // calculated container size.
         let container_size = container_size + header_io_range.len();
 
+        // If the container's contents were spilled, its header belongs in the SpillStorage.
+        if let Some(spilled) = container.spilled {
+            self.write_spilled_header(
+                spilled,
+                header_io_range,
+                container_size,
+                container.num_annotations > 0,
+            )?;
+            self.push_empty_io_range();
+            return self.spill_if_needed();
+        }
+
         // Retrieve this container's header byte range from io_ranges
         let td_io_range = self
+            .buffers
             .io_ranges
             .get_mut(container.td_io_range_index)
             .expect("Missing type descriptor IO range for {}");
 
         // Update the IO range to point to the bytes we just encoded
-        let _ = mem::replace(td_io_range, header_io_range);
+        let _ = mem::replace(td_io_range, IoRange::Buffered(header_io_range));
 
         // If this container had annotations, retrieve the IO ranges that were reserved to store
         // them and use them to encode the annotations wrapper.
@@ -853,7 +1466,7 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         // now that we've stepped out.
         self.push_empty_io_range();
 
-        Ok(())
+        self.spill_if_needed()
     }
 
     /// Writes any buffered data to the sink. This method can only be called when the writer is at
@@ -868,23 +1481,26 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
         // We don't call finalize() on the top level because it has no length prefix.
         // Instead, its io_range represents the bytes of any leading scalar values.
 
-        // For each io_range in order, copy the specified bytes into a contiguous buffer that
-        // we'll write to output.
-
-        for io_range in self.io_ranges.drain(..) {
-            self.contiguous_encoding
-                .extend_from_slice(&self.buffer[io_range]);
+        // Moving data in the SpillStorage may have changed where the spilled data starts and ends
+        // since the last spill. That data is always described by the first IoRange.
+        if let Some(spilled) = self.levels[0].spilled.take() {
+            let spill = self.spill.as_ref().expect("spilled without SpillStorage");
+            self.buffers.io_ranges[0] = IoRange::Spilled(spilled.content_start..spill.length);
         }
 
-        // TODO: When io::Write#is_write_vectored[1] or trait specialization[2] stabilize,
-        //      we can use vectored writes instead of making a contiguous encoding
-        //      buffer.
-        //      [1] https://github.com/rust-lang/rust/issues/69941
-        //      [2] https://github.com/rust-lang/rust/issues/31844
-
-        self.out.write_all(self.contiguous_encoding.as_slice())?;
+        #[cfg(feature = "std")]
+        if self.vectored_writes {
+            self.write_io_ranges_vectored()?;
+        } else {
+            self.write_io_ranges_contiguous()?;
+        }
+        #[cfg(not(feature = "std"))]
+        self.write_io_ranges_contiguous()?;
 
-        self.contiguous_encoding.clear();
+        self.buffers.buffer.clear();
+        if let Some(spill) = self.spill.as_mut() {
+            spill.length = 0;
+        }
         self.push_empty_io_range();
 
         Ok(())
@@ -899,7 +1515,7 @@ impl<W: Write> IonWriter for RawBinaryWriter<W> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod writer_tests {
     use std::fmt::Debug;
 
@@ -1028,6 +1644,99 @@ mod writer_tests {
         )
     }
 
+    #[test]
+    fn binary_writer_u64s() -> IonResult<()> {
+        binary_writer_scalar_test(
+            &[0, 1, i64::MAX as u64, i64::MAX as u64 + 1, u64::MAX],
+            IonType::Int,
+            |writer, v| writer.write_u64(*v),
+            |reader| reader.read_u64(),
+        )
+    }
+
+    #[test]
+    fn binary_writer_i128s() -> IonResult<()> {
+        binary_writer_scalar_test(
+            &[
+                i128::MIN,
+                -(u64::MAX as i128) - 1,
+                -1,
+                0,
+                u64::MAX as i128,
+                u64::MAX as i128 + 1,
+                i128::MAX,
+            ],
+            IonType::Int,
+            |writer, v| writer.write_i128(*v),
+            |reader| reader.read_i128(),
+        )
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn binary_writer_uuids() -> IonResult<()> {
+        let uuid = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        binary_writer_test(
+            |writer| {
+                writer.write_uuid_blob(&uuid)?;
+                writer.write_uuid_string(&uuid)?;
+                writer.write_blob([1, 2, 3])?;
+                writer.write_string("not a uuid")
+            },
+            |reader| {
+                assert_eq!(reader.next()?, StreamItem::Value(IonType::Blob));
+                assert_eq!(reader.read_uuid()?, uuid);
+                assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+                assert_eq!(reader.read_str()?, "67e55044-10b1-426f-9247-bb680e5fe0c8");
+                assert_eq!(reader.read_uuid()?, uuid);
+                assert_eq!(reader.next()?, StreamItem::Value(IonType::Blob));
+                assert!(reader.read_uuid().is_err());
+                assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+                assert!(reader.read_uuid().is_err());
+                Ok(())
+            },
+        )
+    }
+
+    #[rstest]
+    #[case::u64_max(|w: &mut TestWriter| w.write_u64(u64::MAX), &[0x28, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])]
+    #[case::negative_u64_max(|w: &mut TestWriter| w.write_i128(-(u64::MAX as i128)), &[0x38, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])]
+    #[case::thirteen_bytes(|w: &mut TestWriter| w.write_i128(1 << 96), &[0x2D, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])]
+    #[case::i128_max(|w: &mut TestWriter| w.write_i128(i128::MAX), &[0x2E, 0x90, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])]
+    #[case::int_i128(|w: &mut TestWriter| w.write_int(&Int::from(u64::MAX)), &[0x28, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])]
+    fn binary_writer_128_bit_int_encoding(
+        #[case] write_fn: fn(&mut TestWriter) -> IonResult<()>,
+        #[case] expected: &[u8],
+    ) -> IonResult<()> {
+        let mut buffer = vec![];
+        let mut writer = RawBinaryWriterBuilder::new().build(&mut buffer)?;
+        write_fn(&mut writer)?;
+        writer.flush()?;
+        assert_eq!(buffer.as_slice(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_128_bit_ints_are_read_without_big_ints() -> IonResult<()> {
+        binary_writer_test(
+            |writer| {
+                writer.write_u64(u64::MAX)?;
+                writer.write_i128(i128::MIN + 1)
+            },
+            |reader| {
+                reader.next()?;
+                assert!(
+                    matches!(reader.read_int()?, Int::I128(value) if value.get() == u64::MAX as i128)
+                );
+                reader.next()?;
+                assert!(
+                    matches!(reader.read_int()?, Int::I128(value) if value.get() == i128::MIN + 1)
+                );
+                Ok(())
+            },
+        )
+    }
+
     #[test]
     fn binary_writer_floats() -> IonResult<()> {
         binary_writer_scalar_test(
@@ -1155,7 +1864,7 @@ mod writer_tests {
             reader,
             IonType::Int,
             |r| r.read_int(),
-            Int::BigInt(value.clone()),
+            Int::from(value.clone()),
         );
     }
 
@@ -1220,7 +1929,7 @@ mod writer_tests {
 
     fn write_lst<W: Write>(writer: &mut RawBinaryWriter<W>, symbols: &[&str]) -> IonResult<()> {
         // $ion_symbol_table::{symbols: ["your", "strings", "here"]}
-        writer.set_annotations([3]); // $ion_symbol_table
+        writer.set_annotations([3])?; // $ion_symbol_table
         writer.step_in(IonType::Struct)?;
         writer.set_field_id(7); // symbols
         writer.step_in(IonType::List)?;
@@ -1243,11 +1952,11 @@ mod writer_tests {
         let very_big_negative = -very_big_positive.clone();
         binary_writer_test(
             |writer| {
-                writer.write_int(&Int::BigInt(BigInt::zero()))?;
-                writer.write_int(&Int::BigInt(big_positive.clone()))?;
-                writer.write_int(&Int::BigInt(very_big_positive.clone()))?;
-                writer.write_int(&Int::BigInt(big_negative.clone()))?;
-                writer.write_int(&Int::BigInt(very_big_negative.clone()))?;
+                writer.write_int(&Int::from(BigInt::zero()))?;
+                writer.write_int(&Int::from(big_positive.clone()))?;
+                writer.write_int(&Int::from(very_big_positive.clone()))?;
+                writer.write_int(&Int::from(big_negative.clone()))?;
+                writer.write_int(&Int::from(very_big_negative.clone()))?;
                 Ok(())
             },
             |reader| {
@@ -1292,13 +2001,13 @@ mod writer_tests {
             |writer| {
                 write_lst(writer, &["foo", "bar", "baz", "quux", "quuz", "waldo"])?;
 
-                writer.set_annotations([10]);
+                writer.set_annotations([10])?;
                 writer.write_bool(true)?;
 
-                writer.set_annotations([11, 12]);
+                writer.set_annotations([11, 12])?;
                 writer.write_i64(42)?;
 
-                writer.set_annotations([13, 14, 15]);
+                writer.set_annotations([13, 14, 15])?;
                 writer.write_string("Hello")
             },
             |reader| {
@@ -1325,19 +2034,19 @@ mod writer_tests {
                 )?;
 
                 // foo::(true)
-                writer.set_annotations([10]);
+                writer.set_annotations([10])?;
                 writer.step_in(IonType::SExp)?;
                 writer.write_bool(true)?;
                 writer.step_out()?;
 
                 // bar::baz::[11]
-                writer.set_annotations([11, 12]);
+                writer.set_annotations([11, 12])?;
                 writer.step_in(IonType::List)?;
                 writer.write_i64(11)?;
                 writer.step_out()?;
 
                 // quux::quuz::waldo::{gary: "foo"}
-                writer.set_annotations([13, 14, 15]);
+                writer.set_annotations([13, 14, 15])?;
                 writer.step_in(IonType::Struct)?;
                 writer.set_field_id(16);
                 writer.write_string("foo")?;
@@ -1373,12 +2082,12 @@ mod writer_tests {
             |writer| {
                 write_lst(writer, &["foo", "bar", "baz", "quux"])?;
                 // foo::{bar: baz::[quux::"quuz"]]}
-                writer.set_annotations([10]);
+                writer.set_annotations([10])?;
                 writer.step_in(IonType::Struct)?;
                 writer.set_field_id(11);
-                writer.set_annotations([12]);
+                writer.set_annotations([12])?;
                 writer.step_in(IonType::List)?;
-                writer.set_annotations([13]);
+                writer.set_annotations([13])?;
                 writer.write_string("quuz")?;
                 writer.step_out()?; // End of list
                 writer.step_out() // End of struct
@@ -1483,4 +2192,311 @@ mod writer_tests {
             },
         )
     }
+
+    #[test]
+    fn binary_writer_rejects_text_and_misplaced_field_names() -> IonResult<()> {
+        binary_writer_test(
+            |writer| {
+                // The raw writer cannot encode symbol text, only symbol IDs.
+                assert!(writer.set_annotations([4, 5]).is_ok());
+                assert!(writer
+                    .set_annotations([
+                        RawSymbolTokenRef::SymbolId(4),
+                        RawSymbolTokenRef::Text("foo")
+                    ])
+                    .is_err());
+                assert!(writer.set_field_name(4).is_err());
+                writer.step_in(IonType::Struct)?;
+                assert!(writer.set_field_name("foo").is_err());
+                writer.set_field_name(4)?;
+                writer.write_i64(1)?;
+                writer.step_out()
+            },
+            |reader| {
+                // The annotations were discarded when the invalid annotation was rejected.
+                expect_struct(reader);
+                expect_annotations(reader, &[]);
+                reader.step_in()?;
+                assert_eq!(reader.next()?, StreamItem::Value(IonType::Int));
+                expect_field_name(reader, "name");
+                reader.step_out()
+            },
+        )
+    }
+
+    // Records everything that is spilled so that tests can confirm that spilling happened.
+    #[derive(Clone, Default)]
+    struct SharedStorage(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl SpillStorage for SharedStorage {
+        fn write_at(&mut self, offset: usize, bytes: &[u8]) -> IonResult<()> {
+            let mut storage = self.0.lock().unwrap();
+            let length = storage.len().max(offset + bytes.len());
+            storage.resize(length, 0);
+            storage[offset..offset + bytes.len()].copy_from_slice(bytes);
+            Ok(())
+        }
+
+        fn read_at(&mut self, offset: usize, buffer: &mut [u8]) -> IonResult<()> {
+            buffer.copy_from_slice(&self.0.lock().unwrap()[offset..offset + buffer.len()]);
+            Ok(())
+        }
+    }
+
+    // Writes annotated and nested containers, annotated scalars and values of various sizes,
+    // flushing partway through.
+    fn write_nested_values<W: Write>(writer: &mut RawBinaryWriter<W>) -> IonResult<()> {
+        writer.write_ion_version_marker(1, 0)?;
+        writer.write_string("leading scalar")?;
+        writer.set_annotations([4])?;
+        writer.step_in(IonType::List)?;
+        for i in 0..200 {
+            writer.set_annotations([5, 6])?;
+            writer.step_in(IonType::Struct)?;
+            writer.set_field_id(4);
+            writer.write_i64(i)?;
+            writer.set_field_id(5);
+            writer.set_annotations([7])?;
+            writer.write_string("x".repeat(i as usize % 20))?;
+            writer.set_field_id(6);
+            writer.step_in(IonType::SExp)?;
+            for j in 0..(i % 5) {
+                writer.write_symbol_id(j as usize)?;
+            }
+            writer.step_out()?;
+            writer.step_out()?;
+        }
+        writer.write_blob(vec![7u8; 300])?;
+        writer.step_out()?;
+        writer.flush()?;
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_id(4);
+        writer.step_in(IonType::List)?;
+        for i in 0..500 {
+            writer.write_i64(i)?;
+        }
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.write_bool(true)?;
+        writer.flush()
+    }
+
+    #[rstest]
+    #[case::empty_buffer(0)]
+    #[case::tiny_buffer(1)]
+    #[case::small_buffer(100)]
+    #[case::large_buffer(4000)]
+    fn binary_writer_spills_without_changing_output(
+        #[case] max_buffer_size: usize,
+    ) -> IonResult<()> {
+        let mut expected = RawBinaryWriterBuilder::new().build(Vec::new())?;
+        write_nested_values(&mut expected)?;
+
+        let storage = SharedStorage::default();
+        let mut writer = RawBinaryWriterBuilder::new()
+            .with_spill_storage(max_buffer_size, storage.clone())
+            .build(Vec::new())?;
+        write_nested_values(&mut writer)?;
+
+        assert!(!storage.0.lock().unwrap().is_empty());
+        assert_eq!(writer.output(), expected.output());
+        Ok(())
+    }
+
+    #[test]
+    fn spilling_many_large_containers_keeps_io_ranges_bounded() -> IonResult<()> {
+        // Each child list is larger than the buffer, so its contents are spilled before its
+        // header is known. Calls `after_each_child` after stepping out of each one.
+        fn write_large_children<W: Write>(
+            writer: &mut RawBinaryWriter<W>,
+            mut after_each_child: impl FnMut(&RawBinaryWriter<W>),
+        ) -> IonResult<()> {
+            writer.write_ion_version_marker(1, 0)?;
+            writer.step_in(IonType::List)?;
+            for i in 0..500 {
+                if i % 2 == 0 {
+                    writer.set_annotations([4, 5])?;
+                }
+                writer.step_in(IonType::List)?;
+                for j in 0..(50 + i % 50) {
+                    writer.write_i64(j)?;
+                }
+                writer.step_out()?;
+                after_each_child(writer);
+            }
+            writer.step_out()?;
+            writer.flush()
+        }
+        let mut expected = RawBinaryWriterBuilder::new().build(Vec::new())?;
+        write_large_children(&mut expected, |_| {})?;
+
+        let storage = SharedStorage::default();
+        let mut writer = RawBinaryWriterBuilder::new()
+            .with_spill_storage(64, storage.clone())
+            .build(Vec::new())?;
+        let mut max_io_ranges = 0;
+        write_large_children(&mut writer, |writer| {
+            max_io_ranges = max_io_ranges.max(writer.buffers.io_ranges.len())
+        })?;
+
+        assert!(
+            max_io_ranges <= 8,
+            "io_ranges grew to {max_io_ranges} entries"
+        );
+        assert_eq!(writer.output(), expected.output());
+        // Apart from the space reserved for the headers of open containers, the storage never
+        // holds more than the data being written.
+        let storage_size = storage.0.lock().unwrap().len();
+        assert!(storage_size < expected.output().len() + 100);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_spills_to_a_file() -> IonResult<()> {
+        let mut writer = RawBinaryWriterBuilder::new()
+            .with_spill_storage(64, tempfile::tempfile()?)
+            .build(Vec::new())?;
+        write_nested_values(&mut writer)?;
+
+        let mut expected = RawBinaryWriterBuilder::new().build(Vec::new())?;
+        write_nested_values(&mut expected)?;
+        assert_eq!(writer.output(), expected.output());
+        Ok(())
+    }
+
+    // A sink whose vectored writes stop partway through a slice. Every other vectored write is
+    // interrupted before writing anything.
+    #[derive(Default)]
+    struct ShortVectoredWriter(Vec<u8>, bool);
+
+    impl Write for ShortVectoredWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.write(buf)
+        }
+
+        fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+            self.1 = !self.1;
+            if self.1 {
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            let mut bytes_written = 0;
+            for buf in bufs {
+                let length = buf.len().min(7 - bytes_written);
+                self.0.extend_from_slice(&buf[..length]);
+                bytes_written += length;
+                if bytes_written == 7 {
+                    break;
+                }
+            }
+            Ok(bytes_written)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[rstest]
+    #[case::without_spilling(None)]
+    #[case::with_spilling(Some(100))]
+    fn binary_writer_vectored_writes(#[case] max_buffer_size: Option<usize>) -> IonResult<()> {
+        let mut expected = RawBinaryWriterBuilder::new().build(Vec::new())?;
+        write_nested_values(&mut expected)?;
+
+        let mut builder = RawBinaryWriterBuilder::new().with_vectored_writes(true);
+        if let Some(max_buffer_size) = max_buffer_size {
+            builder = builder.with_spill_storage(max_buffer_size, SharedStorage::default());
+        }
+        let mut writer = builder.build(ShortVectoredWriter::default())?;
+        write_nested_values(&mut writer)?;
+
+        assert_eq!(&writer.output().0, expected.output());
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_reuses_pooled_buffers() -> IonResult<()> {
+        use crate::binary::buffer_pool::BufferPool;
+        // Writes a list that is larger than the initial size of the buffer.
+        fn write_large_list<W: Write>(writer: &mut RawBinaryWriter<W>) -> IonResult<()> {
+            writer.step_in(IonType::List)?;
+            for i in 0..5000 {
+                writer.write_i64(i)?;
+            }
+            writer.step_out()?;
+            writer.flush()
+        }
+        let pool = BufferPool::new(1);
+        let mut expected = RawBinaryWriterBuilder::new().build(Vec::new())?;
+        write_large_list(&mut expected)?;
+
+        let mut writer = RawBinaryWriterBuilder::new()
+            .with_buffer_pool(pool.clone())
+            .build(Vec::new())?;
+        write_large_list(&mut writer)?;
+        let buffer_capacity = writer.buffers.buffer.capacity();
+        assert!(buffer_capacity > INITIAL_ENCODING_BUFFER_CAPACITY);
+        assert!(pool.is_empty());
+        drop(writer);
+        assert_eq!(pool.len(), 1);
+
+        // The next writer takes the grown buffers from the pool...
+        let mut writer = RawBinaryWriterBuilder::new()
+            .with_buffer_pool(pool.clone())
+            .build(Vec::new())?;
+        assert!(pool.is_empty());
+        assert_eq!(writer.buffers.buffer.capacity(), buffer_capacity);
+        write_large_list(&mut writer)?;
+        assert_eq!(writer.output(), expected.output());
+
+        // ...and a writer built while the pool is empty allocates its own.
+        let other_writer = RawBinaryWriterBuilder::new()
+            .with_buffer_pool(pool.clone())
+            .build(Vec::new())?;
+        assert_eq!(
+            other_writer.buffers.buffer.capacity(),
+            INITIAL_ENCODING_BUFFER_CAPACITY
+        );
+
+        // The pool holds at most one set of buffers.
+        drop(writer);
+        drop(other_writer);
+        assert_eq!(pool.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn buffer_pool_shrinks_large_buffers() -> IonResult<()> {
+        use crate::binary::buffer_pool::BufferPool;
+        let pool = BufferPool::new(1);
+        // The setting applies to every handle to the pool, including ones cloned beforehand.
+        let _ = pool
+            .clone()
+            .with_max_retained_capacity(INITIAL_ENCODING_BUFFER_CAPACITY);
+        let mut writer = RawBinaryWriterBuilder::new()
+            .with_buffer_pool(pool.clone())
+            .build(Vec::new())?;
+        writer.step_in(IonType::List)?;
+        for i in 0..50_000 {
+            writer.write_i64(i)?;
+        }
+        writer.step_out()?;
+        writer.flush()?;
+        assert!(writer.buffers.buffer.capacity() > INITIAL_ENCODING_BUFFER_CAPACITY);
+        assert!(writer.buffers.contiguous_encoding.capacity() > INITIAL_ENCODING_BUFFER_CAPACITY);
+        drop(writer);
+        assert_eq!(pool.len(), 1);
+
+        // The buffers were kept, but shrunk to the maximum retained capacity.
+        let writer = RawBinaryWriterBuilder::new()
+            .with_buffer_pool(pool.clone())
+            .build(Vec::new())?;
+        assert!(pool.is_empty());
+        let buffers = &writer.buffers;
+        assert!(buffers.buffer.capacity() <= INITIAL_ENCODING_BUFFER_CAPACITY);
+        assert!(buffers.contiguous_encoding.capacity() <= INITIAL_ENCODING_BUFFER_CAPACITY);
+        let io_ranges_size = buffers.io_ranges.capacity() * mem::size_of::<IoRange>();
+        assert!(io_ranges_size <= INITIAL_ENCODING_BUFFER_CAPACITY);
+        Ok(())
+    }
 }
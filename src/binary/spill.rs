@@ -0,0 +1,41 @@
+//! Scratch storage that lets a [`RawBinaryWriter`](super::raw_binary_writer::RawBinaryWriter)
+//! write top-level values that are too large to buffer in memory.
+
+use crate::result::IonResult;
+
+/// Storage to which a [`RawBinaryWriter`](super::raw_binary_writer::RawBinaryWriter) can move
+/// the encoded bytes of completed values while it is still writing the container that holds them.
+/// Binary Ion prefixes each container with its length, so the writer cannot send a container to
+/// its output until the container is complete. Spilling its contents allows the writer to hold
+/// only a bounded amount of data in memory while it waits.
+///
+/// The writer treats the storage as a scratch file. It always writes a byte before reading it
+/// back, but it may write to the same offset more than once as it moves data to make room for
+/// container headers. It reuses offsets from the beginning of the storage after each `flush()`.
+///
+/// With the `std` feature, this is implemented for every type that implements
+/// [`Read`](std::io::Read), [`Write`](std::io::Write) and [`Seek`](std::io::Seek), including
+/// [`File`](std::fs::File) and [`Cursor<Vec<u8>>`](std::io::Cursor).
+pub trait SpillStorage {
+    /// Writes all of `bytes` to the storage, starting at `offset`.
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) -> IonResult<()>;
+
+    /// Fills `buffer` with the bytes that were previously written starting at `offset`.
+    fn read_at(&mut self, offset: usize, buffer: &mut [u8]) -> IonResult<()>;
+}
+
+#[cfg(feature = "std")]
+impl<T> SpillStorage for T
+where
+    T: std::io::Read + std::io::Write + std::io::Seek,
+{
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) -> IonResult<()> {
+        self.seek(std::io::SeekFrom::Start(offset as u64))?;
+        Ok(self.write_all(bytes)?)
+    }
+
+    fn read_at(&mut self, offset: usize, buffer: &mut [u8]) -> IonResult<()> {
+        self.seek(std::io::SeekFrom::Start(offset as u64))?;
+        Ok(self.read_exact(buffer)?)
+    }
+}
@@ -1,17 +1,18 @@
 // Copyright Amazon.com, Inc. or its affiliates.
 
-use std::io::Write;
-use std::ops::Neg;
+use crate::io::Write;
 
 use arrayvec::ArrayVec;
 use chrono::{Datelike, Timelike};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::float::FloatCore;
 
 use crate::binary::decimal::DecimalBinaryEncoder;
 use crate::binary::raw_binary_writer::MAX_INLINE_LENGTH;
 use crate::binary::var_int::VarInt;
 use crate::binary::var_uint::VarUInt;
 use crate::result::IonResult;
-use crate::types::{Decimal, Mantissa, Precision, Timestamp};
+use crate::types::{Precision, Timestamp};
 
 const MAX_TIMESTAMP_LENGTH: usize = 32;
 
@@ -71,24 +72,10 @@ where
                     bytes_written += VarUInt::write_u64(self, utc.minute() as u64)?;
                     if timestamp.precision > Precision::HourAndMinute {
                         bytes_written += VarUInt::write_u64(self, utc.second() as u64)?;
-                        if let Some(ref mantissa) = timestamp.fractional_seconds {
-                            // TODO: Both branches encode directly due to one
-                            // branch owning vs borrowing the decimal
-                            // representation. #286 should provide a fix.
-                            match mantissa {
-                                Mantissa::Digits(precision) => {
-                                    // Consider the following case: `2000-01-01T00:00:00.123Z`.
-                                    // That's 123 millis, or 123,000,000 nanos.
-                                    // Our mantissa is 0.123, or 123d-3.
-                                    let scaled = utc.nanosecond() / 10u32.pow(9 - *precision); // 123,000,000 -> 123
-                                    let exponent = (*precision as i64).neg(); // -3
-                                    let fractional = Decimal::new(scaled, exponent); // 123d-3
-                                    bytes_written += self.encode_decimal(&fractional)?;
-                                }
-                                Mantissa::Arbitrary(decimal) => {
-                                    bytes_written += self.encode_decimal(decimal)?;
-                                }
-                            };
+                        if let Some(ref fractional_seconds) = timestamp.fractional_seconds {
+                            // Consider the following case: `2000-01-01T00:00:00.123Z`.
+                            // Our fractional seconds are 0.123, or 123d-3.
+                            bytes_written += self.encode_decimal(fractional_seconds)?;
                         }
                     }
                 }
@@ -127,12 +114,25 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod binary_timestamp_tests {
     use super::*;
+    use crate::data_source::ToIonDataSource;
+    use crate::ion_data::IonEq;
     use crate::{reader, IonReader, IonType, ReaderBuilder};
     use rstest::*;
 
+    const ION_1_0_IVM: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+
+    fn read_timestamp<I: ToIonDataSource>(input: I) -> IonResult<Timestamp> {
+        let mut reader = ReaderBuilder::new().build(input)?;
+        assert_eq!(
+            reader.next()?,
+            reader::StreamItem::Value(IonType::Timestamp)
+        );
+        reader.read_timestamp()
+    }
+
     // These tests show how varying levels of precision affects number of bytes
     // written (for binary encoding of timestamps).
     #[rstest]
@@ -161,4 +161,37 @@ mod binary_timestamp_tests {
         }
         Ok(())
     }
+
+    // Near the ends of the timestamp range, the UTC fields that the binary encoding uses can have
+    // a year of 0 or 10000 even though the local year is valid.
+    #[rstest]
+    #[case::utc_year_zero("0001-01-01T00:00+00:01")]
+    #[case::utc_year_zero_with_fraction("0001-01-01T00:00:00.000+23:59")]
+    #[case::utc_year_ten_thousand("9999-12-31T23:59:59.999-23:59")]
+    #[case::arbitrary_precision("2000-01-01T00:00:00.123456789012345678901234567890Z")]
+    #[case::leading_zeros("2000-01-01T00:00:00.000000000001-00:00")]
+    #[case::zero_fraction("2000-01-01T00:00:00.00000000000+01:00")]
+    fn timestamp_binary_round_trip(#[case] input: &str) -> IonResult<()> {
+        let timestamp = read_timestamp(input)?;
+        let mut buf = ION_1_0_IVM.to_vec();
+        buf.encode_timestamp_value(&timestamp)?;
+        let round_tripped = read_timestamp(buf)?;
+        assert!(round_tripped.ion_eq(&timestamp));
+        assert_eq!(round_tripped.to_string(), input.replace('Z', "+00:00"));
+        Ok(())
+    }
+
+    // Offsets on timestamps with less than minute precision are superfluous and ignored.
+    #[rstest]
+    #[case::year(&[0x63, 0x81, 0x0F, 0xD0], "2000T")]
+    #[case::month(&[0x64, 0x81, 0x0F, 0xD0, 0x82], "2000-02T")]
+    #[case::day(&[0x65, 0xFF, 0x0F, 0xD0, 0x82, 0x81], "2000-02-01T")]
+    fn timestamp_superfluous_offset(#[case] bytes: &[u8], #[case] expected: &str) -> IonResult<()> {
+        let mut buf = ION_1_0_IVM.to_vec();
+        buf.extend_from_slice(bytes);
+        let timestamp = read_timestamp(buf)?;
+        assert!(timestamp.ion_eq(&read_timestamp(expected)?));
+        assert_eq!(timestamp.to_string(), expected);
+        Ok(())
+    }
 }
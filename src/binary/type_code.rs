@@ -1,4 +1,5 @@
-use std::convert::TryFrom;
+use alloc::format;
+use core::convert::TryFrom;
 
 use crate::result::{decoding_error, IonError};
 use crate::types::IonType;
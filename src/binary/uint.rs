@@ -1,9 +1,17 @@
+use crate::io::Write;
+#[cfg(feature = "std")]
+use alloc::format;
+#[cfg(feature = "std")]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
 use num_bigint::BigUint;
-use std::io::Write;
-use std::mem;
 
+#[cfg(feature = "std")]
 use crate::data_source::IonDataSource;
-use crate::result::{decoding_error, IonResult};
+#[cfg(feature = "std")]
+use crate::result::decoding_error;
+use crate::result::IonResult;
 use crate::types::{Int, UInt};
 
 // This limit is used for stack-allocating buffer space to encode/decode UInts.
@@ -41,12 +49,26 @@ impl DecodedUInt {
         magnitude
     }
 
+    /// Interprets all of the bytes in the provided slice as big-endian unsigned integer bytes.
+    /// The caller must confirm that `uint_bytes` is no longer than 16 bytes long; otherwise,
+    /// overflow may quietly occur.
+    pub(crate) fn u128_from_slice(uint_bytes: &[u8]) -> u128 {
+        let mut magnitude: u128 = 0;
+        for &byte in uint_bytes {
+            let byte = u128::from(byte);
+            magnitude <<= 8;
+            magnitude |= byte;
+        }
+        magnitude
+    }
+
     /// Interprets all of the bytes in the provided slice as big-endian unsigned integer bytes.
     pub(crate) fn big_uint_from_slice(uint_bytes: &[u8]) -> BigUint {
         BigUint::from_bytes_be(uint_bytes)
     }
 
     /// Reads a UInt with `length` bytes from the provided data source.
+    #[cfg(feature = "std")]
     pub fn read<R: IonDataSource>(data_source: &mut R, length: usize) -> IonResult<DecodedUInt> {
         if length > MAX_UINT_SIZE_IN_BYTES {
             return decoding_error(format!(
@@ -64,6 +86,7 @@ impl DecodedUInt {
         }
     }
 
+    #[cfg(feature = "std")]
     fn read_using_buffer<R: IonDataSource>(
         data_source: &mut R,
         length: usize,
@@ -84,10 +107,13 @@ impl DecodedUInt {
                 magnitude |= byte;
             }
             UInt::U64(magnitude)
+        } else if length <= mem::size_of::<u128>() {
+            // The UInt is small enough to fit in a u128, which does not require an allocation.
+            UInt::from(DecodedUInt::u128_from_slice(buffer))
         } else {
-            // The UInt is too large to fit in a u64; read it as a BigUInt instead
+            // The UInt is too large to fit in a u128; read it as a BigUInt instead
             let magnitude = BigUint::from_bytes_be(buffer);
-            UInt::BigUInt(magnitude)
+            UInt::from(magnitude)
         };
 
         Ok(DecodedUInt {
@@ -129,15 +155,15 @@ impl From<DecodedUInt> for Int {
     }
 }
 
-/// A buffer for storing a UInt's Big Endian bytes. UInts that can fit in a `u64` will use the
+/// A buffer for storing a UInt's Big Endian bytes. UInts that can fit in a `u128` will use the
 /// `Stack` storage variant, meaning that no heap allocations are required in the common case.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum UIntBeBytes {
-    Stack([u8; mem::size_of::<u64>()]),
+    Stack([u8; mem::size_of::<u128>()]),
     Heap(Vec<u8>),
 }
 
-/// The big-endian, compact slice of bytes for a UInt (`u64`). Leading zero
+/// The big-endian, compact slice of bytes for a UInt. Leading zero
 /// octets are not part of the representation. See the [spec] for more
 /// information.
 ///
@@ -177,12 +203,24 @@ impl AsRef<[u8]> for EncodedUInt {
 /// assert_eq!(&[0x01, 0x00], two_bytes.as_bytes());
 /// ```
 pub fn encode_u64(magnitude: u64) -> EncodedUInt {
+    encode_u128(u128::from(magnitude))
+}
+
+/// Returns the magnitude as big-endian bytes. This does not allocate.
+///
+/// ```
+/// use ion_rs::binary::uint;
+///
+/// let repr = uint::encode_u128(u128::from(u64::MAX) + 1);
+/// assert_eq!(&[0x01, 0, 0, 0, 0, 0, 0, 0, 0], repr.as_bytes());
+/// ```
+pub fn encode_u128(magnitude: u128) -> EncodedUInt {
     // We can divide the number of leading zero bits by 8
     // to to get the number of leading zero bytes.
     let empty_leading_bytes: u32 = magnitude.leading_zeros() / 8;
     let first_occupied_byte = empty_leading_bytes as usize;
 
-    let magnitude_bytes: [u8; mem::size_of::<u64>()] = magnitude.to_be_bytes();
+    let magnitude_bytes: [u8; mem::size_of::<u128>()] = magnitude.to_be_bytes();
 
     EncodedUInt {
         be_bytes: UIntBeBytes::Stack(magnitude_bytes),
@@ -194,6 +232,7 @@ pub fn encode_u64(magnitude: u64) -> EncodedUInt {
 pub fn encode_uint(magnitude: &UInt) -> EncodedUInt {
     let magnitude: &BigUint = match magnitude {
         UInt::U64(m) => return encode_u64(*m),
+        UInt::U128(m) => return encode_u128(*m),
         UInt::BigUInt(m) => m,
     };
 
@@ -206,7 +245,7 @@ pub fn encode_uint(magnitude: &UInt) -> EncodedUInt {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use num_traits::Num;
@@ -249,6 +288,8 @@ mod tests {
             uint.value(),
             &UInt::BigUInt(BigUint::from_str_radix("ffffffffffffffffffff", 16).unwrap())
         );
+        // UInts of up to 16 bytes are read without allocating.
+        assert!(matches!(uint.value(), UInt::U128(_)));
     }
 
     #[test]
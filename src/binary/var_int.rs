@@ -1,7 +1,12 @@
+#[cfg(feature = "std")]
 use crate::data_source::IonDataSource;
-use crate::result::{decoding_error, IonResult};
-use std::io::Write;
-use std::mem;
+use crate::io::Write;
+#[cfg(feature = "std")]
+use crate::result::decoding_error;
+use crate::result::IonResult;
+#[cfg(feature = "std")]
+use alloc::format;
+use core::mem;
 
 // ion_rust does not currently support reading variable length integers of truly arbitrary size.
 // These type aliases will simplify the process of changing the data types used to represent each
@@ -47,6 +52,7 @@ impl VarInt {
     }
 
     /// Reads a VarInt from the provided data source.
+    #[cfg(feature = "std")]
     pub fn read<R: IonDataSource>(data_source: &mut R) -> IonResult<VarInt> {
         // Unlike VarUInt's encoding, the first byte in a VarInt is a special case because
         // bit #6 (0-indexed, from the right) indicates whether the value is positive (0) or
@@ -116,7 +122,7 @@ impl VarInt {
         // Saturating subtraction will return 0 instead of underflowing.
         let remaining_bits = occupied_bits.saturating_sub(MAGNITUDE_BITS_IN_FINAL_BYTE);
         // We can encode 7 bits of magnitude in every other byte.
-        bytes_required += f64::ceil(remaining_bits as f64 / 7.0) as usize;
+        bytes_required += (remaining_bits + 6) / 7;
 
         // TODO: The above calculation could be cached for each number of occupied_bits from 0 to 64
 
@@ -185,7 +191,7 @@ impl VarInt {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::VarInt;
     use crate::result::IonResult;
@@ -1,7 +1,12 @@
+#[cfg(feature = "std")]
 use crate::data_source::IonDataSource;
-use crate::result::{decoding_error, IonResult};
-use std::io::Write;
-use std::mem;
+use crate::io::Write;
+#[cfg(feature = "std")]
+use crate::result::decoding_error;
+use crate::result::IonResult;
+#[cfg(feature = "std")]
+use alloc::format;
+use core::mem;
 
 // ion_rust does not currently support reading variable length integers of truly arbitrary size.
 // These type aliases will simplify the process of changing the data types used to represent each
@@ -33,6 +38,7 @@ impl VarUInt {
     }
 
     /// Reads a VarUInt from the provided data source.
+    #[cfg(feature = "std")]
     pub fn read<R: IonDataSource>(data_source: &mut R) -> IonResult<VarUInt> {
         let mut magnitude: usize = 0;
 
@@ -120,7 +126,7 @@ impl VarUInt {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::VarUInt;
     use crate::result::IonResult;
@@ -4,11 +4,12 @@ use std::ops::Range;
 use crate::binary::non_blocking::raw_binary_reader::RawBinaryReader;
 use crate::data_source::ToIonDataSource;
 use crate::element::{Blob, Clob};
-use crate::raw_reader::BufferedRawReader;
+use crate::raw_reader::{BufferedRawReader, RawStreamItem};
+use crate::raw_symbol_token::RawSymbolToken;
 use crate::result::IonResult;
 use crate::stream_reader::IonReader;
 use crate::text::non_blocking::raw_text_reader::RawTextReader;
-use crate::types::Timestamp;
+use crate::types::{Encoding, Span, Timestamp};
 use crate::{Decimal, Int, IonError, IonType, Str};
 
 pub type BlockingRawTextReader<T> = BlockingRawReader<RawTextReader<Vec<u8>>, T>;
@@ -53,6 +54,14 @@ impl<R: BufferedRawReader, T: ToIonDataSource> BlockingRawReader<R, T> {
     }
 }
 
+impl<T: ToIonDataSource> BlockingRawTextReader<T> {
+    /// Configures whether the reader reports the span of each value. This should be set before
+    /// the reader begins reading.
+    pub(crate) fn set_track_spans(&mut self, track_spans: bool) {
+        self.reader.set_track_spans(track_spans);
+    }
+}
+
 impl<R: BufferedRawReader, T: ToIonDataSource> IonReader for BlockingRawReader<R, T> {
     type Item = R::Item;
     type Symbol = R::Symbol;
@@ -141,6 +150,14 @@ impl<R: BufferedRawReader, T: ToIonDataSource> IonReader for BlockingRawReader<R
         self.reader.read_f64()
     }
 
+    fn encoding(&self) -> Option<Encoding> {
+        self.reader.encoding()
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.reader.span()
+    }
+
     fn read_decimal(&mut self) -> IonResult<Decimal> {
         self.reader.read_decimal()
     }
@@ -225,6 +242,159 @@ impl<T: ToIonDataSource> BlockingRawReader<RawBinaryReader<Vec<u8>>, T> {
     }
 }
 
+/// A blocking raw reader for either text or binary Ion. Unlike a `Box<dyn RawReader>`, it
+/// dispatches each call to the underlying reader with a `match` that the compiler can inline.
+// Boxing the text reader would reintroduce the indirection that this type exists to avoid.
+#[allow(clippy::large_enum_variant)]
+pub enum BlockingRawAnyReader<T: ToIonDataSource> {
+    Text(BlockingRawTextReader<T>),
+    Binary(BlockingRawBinaryReader<T>),
+}
+
+/// Calls the same method on whichever reader `$self` holds.
+macro_rules! dispatch {
+    ($self:expr, $reader:ident => $call:expr) => {
+        match $self {
+            BlockingRawAnyReader::Text($reader) => $call,
+            BlockingRawAnyReader::Binary($reader) => $call,
+        }
+    };
+}
+
+impl<T: ToIonDataSource> IonReader for BlockingRawAnyReader<T> {
+    type Item = RawStreamItem;
+    type Symbol = RawSymbolToken;
+
+    fn ion_version(&self) -> (u8, u8) {
+        dispatch!(self, reader => reader.ion_version())
+    }
+
+    #[inline]
+    fn next(&mut self) -> IonResult<Self::Item> {
+        dispatch!(self, reader => reader.next())
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Item {
+        dispatch!(self, reader => reader.current())
+    }
+
+    #[inline]
+    fn ion_type(&self) -> Option<IonType> {
+        dispatch!(self, reader => reader.ion_type())
+    }
+
+    fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = IonResult<Self::Symbol>> + 'a> {
+        dispatch!(self, reader => reader.annotations())
+    }
+
+    fn has_annotations(&self) -> bool {
+        dispatch!(self, reader => reader.has_annotations())
+    }
+
+    fn number_of_annotations(&self) -> usize {
+        dispatch!(self, reader => reader.number_of_annotations())
+    }
+
+    fn field_name(&self) -> IonResult<Self::Symbol> {
+        dispatch!(self, reader => reader.field_name())
+    }
+
+    #[inline]
+    fn is_null(&self) -> bool {
+        dispatch!(self, reader => reader.is_null())
+    }
+
+    fn read_null(&mut self) -> IonResult<IonType> {
+        dispatch!(self, reader => reader.read_null())
+    }
+
+    #[inline]
+    fn read_bool(&mut self) -> IonResult<bool> {
+        dispatch!(self, reader => reader.read_bool())
+    }
+
+    #[inline]
+    fn read_i64(&mut self) -> IonResult<i64> {
+        dispatch!(self, reader => reader.read_i64())
+    }
+
+    fn read_u64(&mut self) -> IonResult<u64> {
+        dispatch!(self, reader => reader.read_u64())
+    }
+
+    fn read_i128(&mut self) -> IonResult<i128> {
+        dispatch!(self, reader => reader.read_i128())
+    }
+
+    fn read_int(&mut self) -> IonResult<Int> {
+        dispatch!(self, reader => reader.read_int())
+    }
+
+    #[inline]
+    fn read_f32(&mut self) -> IonResult<f32> {
+        dispatch!(self, reader => reader.read_f32())
+    }
+
+    #[inline]
+    fn read_f64(&mut self) -> IonResult<f64> {
+        dispatch!(self, reader => reader.read_f64())
+    }
+
+    fn encoding(&self) -> Option<Encoding> {
+        dispatch!(self, reader => reader.encoding())
+    }
+
+    fn span(&self) -> Option<Span> {
+        dispatch!(self, reader => reader.span())
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Decimal> {
+        dispatch!(self, reader => reader.read_decimal())
+    }
+
+    fn read_string(&mut self) -> IonResult<Str> {
+        dispatch!(self, reader => reader.read_string())
+    }
+
+    #[inline]
+    fn read_str(&mut self) -> IonResult<&str> {
+        dispatch!(self, reader => reader.read_str())
+    }
+
+    fn read_symbol(&mut self) -> IonResult<Self::Symbol> {
+        dispatch!(self, reader => reader.read_symbol())
+    }
+
+    fn read_blob(&mut self) -> IonResult<Blob> {
+        dispatch!(self, reader => reader.read_blob())
+    }
+
+    fn read_clob(&mut self) -> IonResult<Clob> {
+        dispatch!(self, reader => reader.read_clob())
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Timestamp> {
+        dispatch!(self, reader => reader.read_timestamp())
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        dispatch!(self, reader => reader.step_in())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        dispatch!(self, reader => reader.step_out())
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        dispatch!(self, reader => reader.parent_type())
+    }
+
+    fn depth(&self) -> usize {
+        dispatch!(self, reader => reader.depth())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
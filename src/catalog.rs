@@ -1,6 +1,9 @@
 use crate::result::{illegal_operation, illegal_operation_raw, IonResult};
 use crate::shared_symbol_table::SharedSymbolTable;
-use std::collections::{BTreeMap, HashMap};
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
 
 /// A Catalog is a collection of Shared Symbol Tables.
 /// For more information about the concept of a catalog,
@@ -17,13 +20,13 @@ pub trait Catalog {
 }
 
 struct MapCatalog {
-    tables_by_name: HashMap<String, BTreeMap<usize, SharedSymbolTable>>,
+    tables_by_name: BTreeMap<String, BTreeMap<usize, SharedSymbolTable>>,
 }
 
 impl MapCatalog {
     pub fn new() -> Self {
         Self {
-            tables_by_name: HashMap::new(),
+            tables_by_name: BTreeMap::new(),
         }
     }
 }
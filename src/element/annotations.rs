@@ -1,7 +1,9 @@
 use crate::element::iterators::{AnnotationsIntoIter, SymbolsIterator};
 use crate::ion_data::IonOrd;
 use crate::Symbol;
-use std::cmp::Ordering;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 /// An ordered sequence of symbols that convey additional, application-specific information about
 /// their associated Ion value.
@@ -18,7 +20,9 @@ use std::cmp::Ordering;
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Annotations {
-    symbols: Vec<Symbol>,
+    // Most values have no annotations, so this is a boxed slice rather than a `Vec` to keep
+    // `Element` as small as possible. An empty boxed slice does not allocate.
+    symbols: Box<[Symbol]>,
 }
 
 impl Annotations {
@@ -26,20 +30,22 @@ impl Annotations {
     // else (`thinvec`?) in the future. Users are unlikely to need to construct an `Annotations`
     // themselves, but can use a `From` impl if necessary.
     pub(crate) fn new(symbols: Vec<Symbol>) -> Self {
-        Annotations { symbols }
+        Annotations {
+            symbols: symbols.into_boxed_slice(),
+        }
     }
 
     /// Constructs an Annotations object representing an empty symbol sequence
     pub fn empty() -> Self {
         Annotations {
-            symbols: Vec::new(),
+            symbols: Box::new([]),
         }
     }
 
     /// Returns an [`Iterator`] that yields each of the [`Symbol`]s in this annotations
     /// sequence in order.
     pub fn iter(&self) -> SymbolsIterator {
-        SymbolsIterator::new(self.symbols.as_slice())
+        SymbolsIterator::new(&self.symbols)
     }
 
     /// Returns the number of annotations in this sequence.
@@ -122,7 +128,7 @@ impl<'a> IntoIterator for &'a Annotations {
     type IntoIter = SymbolsIterator<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        SymbolsIterator::new(self.symbols.as_slice())
+        SymbolsIterator::new(&self.symbols)
     }
 }
 
@@ -131,7 +137,7 @@ impl IntoIterator for Annotations {
     type IntoIter = AnnotationsIntoIter;
 
     fn into_iter(self) -> Self::IntoIter {
-        AnnotationsIntoIter::new(self.symbols.into_iter())
+        AnnotationsIntoIter::new(self.symbols.into_vec().into_iter())
     }
 }
 
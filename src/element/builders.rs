@@ -1,5 +1,6 @@
 use crate::element::{Element, Sequence, Struct};
 use crate::Symbol;
+use alloc::vec::Vec;
 
 /// Constructs [Sequence], [List], and [SExp] values incrementally.
 ///
@@ -348,7 +349,7 @@ macro_rules! ion_struct {
 use crate::types::{List, SExp};
 pub use {ion_list, ion_sexp, ion_struct};
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::element::builders::{SequenceBuilder, StructBuilder};
     use crate::element::Element;
@@ -0,0 +1,567 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides [`ElementRef`], a borrowed view of an Ion value whose text and byte payloads point
+//! directly into the input buffer whenever possible.
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+
+use num_bigint::BigInt;
+
+use crate::element::{Annotations, Element, Struct, Value};
+use crate::result::{decoding_error, IonResult};
+use crate::slice_reader::SliceReader;
+use crate::symbol_ref::AsSymbolRef;
+use crate::types::IntAccess;
+use crate::{Decimal, Int, IonReader, IonType, ReaderBuilder, StreamItem, Symbol, Timestamp};
+
+/// A borrowed counterpart to [`Element`].
+///
+/// When an `ElementRef` is read from binary Ion, the text of its strings and the contents of
+/// its blobs and clobs are slices of the input buffer rather than copies. Symbol text is shared
+/// with the reader's symbol table. Text Ion must be unescaped as it is parsed, so an `ElementRef`
+/// read from text owns its data.
+///
+/// [`to_owned`](Self::to_owned) converts an `ElementRef` into an equivalent `Element`.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::element::{Element, ElementRef};
+/// let ion_data = Element::read_one("{name: \"Fido\", tricks: [sit, stay]}")?;
+/// let mut buffer = Vec::new();
+/// # use ion_rs::element::writer::ElementWriter;
+/// # use ion_rs::{BinaryWriterBuilder, IonWriter};
+/// # let mut writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+/// # writer.write_element(&ion_data)?;
+/// # writer.flush()?;
+/// # drop(writer);
+/// // `buffer` holds the binary encoding of `ion_data`
+/// let element = ElementRef::read_one(&buffer)?;
+/// let name = element.as_struct().and_then(|s| s.get("name")).and_then(|n| n.as_text());
+/// assert_eq!(name, Some("Fido"));
+/// assert_eq!(element.to_owned(), ion_data);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementRef<'a> {
+    annotations: Annotations,
+    value: ValueRef<'a>,
+}
+
+/// Variants for all borrowed _values_ within an [`ElementRef`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueRef<'a> {
+    Null(IonType),
+    Int(Int),
+    Float(f64),
+    Decimal(Box<Decimal>),
+    Timestamp(Box<Timestamp>),
+    String(Cow<'a, str>),
+    Symbol(Symbol),
+    Bool(bool),
+    Blob(Cow<'a, [u8]>),
+    Clob(Cow<'a, [u8]>),
+    SExp(Vec<ElementRef<'a>>),
+    List(Vec<ElementRef<'a>>),
+    Struct(StructRef<'a>),
+}
+
+impl<'a> ValueRef<'a> {
+    pub fn ion_type(&self) -> IonType {
+        use ValueRef::*;
+        match self {
+            Null(t) => *t,
+            Int(_) => IonType::Int,
+            Float(_) => IonType::Float,
+            Decimal(_) => IonType::Decimal,
+            Timestamp(_) => IonType::Timestamp,
+            String(_) => IonType::String,
+            Symbol(_) => IonType::Symbol,
+            Bool(_) => IonType::Bool,
+            Blob(_) => IonType::Blob,
+            Clob(_) => IonType::Clob,
+            SExp(_) => IonType::SExp,
+            List(_) => IonType::List,
+            Struct(_) => IonType::Struct,
+        }
+    }
+
+    /// Copies any borrowed data and returns the equivalent owned [`Value`].
+    pub fn to_owned(&self) -> Value {
+        use ValueRef::*;
+        match self {
+            Null(t) => Value::Null(*t),
+            Int(i) => Value::Int(i.clone()),
+            Float(f) => Value::Float(*f),
+            Decimal(d) => Value::Decimal(d.clone()),
+            Timestamp(t) => Value::Timestamp(t.clone()),
+            String(s) => Value::String(s.as_ref().into()),
+            Symbol(s) => Value::Symbol(s.clone()),
+            Bool(b) => Value::Bool(*b),
+            Blob(b) => Value::Blob(b.as_ref().into()),
+            Clob(c) => Value::Clob(c.as_ref().into()),
+            SExp(s) => Value::SExp(
+                s.iter()
+                    .map(ElementRef::to_owned)
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            List(l) => Value::List(
+                l.iter()
+                    .map(ElementRef::to_owned)
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            Struct(s) => Value::Struct(s.to_owned()),
+        }
+    }
+}
+
+/// A borrowed counterpart to [`Struct`]. Fields are stored in the order in which they were read;
+/// lookups by name are linear scans.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StructRef<'a> {
+    fields: Vec<(Symbol, ElementRef<'a>)>,
+}
+
+impl<'a> StructRef<'a> {
+    /// Returns an iterator over the field name/value pairs in this struct.
+    pub fn iter(&self) -> impl Iterator<Item = (&Symbol, &ElementRef<'a>)> {
+        self.fields.iter().map(|(name, value)| (name, value))
+    }
+
+    /// Returns an iterator over the field name/value pairs in this struct.
+    pub fn fields(&self) -> impl Iterator<Item = (&Symbol, &ElementRef<'a>)> {
+        self.iter()
+    }
+
+    /// Returns the last value in the struct with the specified field name, if any.
+    pub fn get<A: AsSymbolRef>(&self, field_name: A) -> Option<&ElementRef<'a>> {
+        let field_name = field_name.as_symbol_ref();
+        self.fields
+            .iter()
+            .rev()
+            .find(|(name, _value)| name.as_symbol_ref() == field_name)
+            .map(|(_name, value)| value)
+    }
+
+    /// Returns an iterator over all of the values in the struct with the specified field name.
+    pub fn get_all<'b, A: AsSymbolRef + 'b>(
+        &'b self,
+        field_name: A,
+    ) -> impl Iterator<Item = &'b ElementRef<'a>> + 'b {
+        self.fields
+            .iter()
+            .filter(move |(name, _value)| name.as_symbol_ref() == field_name.as_symbol_ref())
+            .map(|(_name, value)| value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Copies any borrowed data and returns the equivalent owned [`Struct`].
+    pub fn to_owned(&self) -> Struct {
+        Struct::from_iter(
+            self.fields
+                .iter()
+                .map(|(name, value)| (name.clone(), value.to_owned())),
+        )
+    }
+}
+
+impl<'a> ElementRef<'a> {
+    pub(crate) fn new(annotations: Annotations, value: ValueRef<'a>) -> Self {
+        Self { annotations, value }
+    }
+
+    /// Returns a reference to this [ElementRef]'s [ValueRef].
+    pub fn value(&self) -> &ValueRef<'a> {
+        &self.value
+    }
+
+    pub fn ion_type(&self) -> IonType {
+        self.value.ion_type()
+    }
+
+    pub fn annotations(&self) -> &Annotations {
+        &self.annotations
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(&self.value, ValueRef::Null(_))
+    }
+
+    pub fn as_int(&self) -> Option<&Int> {
+        match &self.value {
+            ValueRef::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match &self.value {
+            ValueRef::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    pub fn as_decimal(&self) -> Option<&Decimal> {
+        match &self.value {
+            ValueRef::Decimal(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn as_timestamp(&self) -> Option<&Timestamp> {
+        match &self.value {
+            ValueRef::Timestamp(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match &self.value {
+            ValueRef::String(text) => Some(text.as_ref()),
+            ValueRef::Symbol(sym) => sym.text(),
+            _ => None,
+        }
+    }
+
+    pub fn as_string(&self) -> Option<&str> {
+        match &self.value {
+            ValueRef::String(text) => Some(text.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn as_symbol(&self) -> Option<&Symbol> {
+        match &self.value {
+            ValueRef::Symbol(sym) => Some(sym),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match &self.value {
+            ValueRef::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_lob(&self) -> Option<&[u8]> {
+        match &self.value {
+            ValueRef::Blob(bytes) | ValueRef::Clob(bytes) => Some(bytes.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn as_blob(&self) -> Option<&[u8]> {
+        match &self.value {
+            ValueRef::Blob(bytes) => Some(bytes.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn as_clob(&self) -> Option<&[u8]> {
+        match &self.value {
+            ValueRef::Clob(bytes) => Some(bytes.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn as_sequence(&self) -> Option<&[ElementRef<'a>]> {
+        match &self.value {
+            ValueRef::SExp(s) | ValueRef::List(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&StructRef<'a>> {
+        match &self.value {
+            ValueRef::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
+
+    /// Copies any borrowed data and returns the equivalent owned [`Element`].
+    pub fn to_owned(&self) -> Element {
+        Element::new(self.annotations.clone(), self.value.to_owned())
+    }
+
+    /// Reads a single Ion [`ElementRef`] from the provided slice.
+    ///
+    /// If the slice is empty, returns `Ok(None)`.
+    /// If the slice has at least one value, returns `Ok(Some(ElementRef))`.
+    /// If the slice has invalid data, returns `Err`.
+    pub fn read_first(data: &'a [u8]) -> IonResult<Option<ElementRef<'a>>> {
+        let mut reader = ReaderBuilder::default().build_slice(data)?;
+        ElementRefLoader::for_reader(&mut reader).materialize_next()
+    }
+
+    /// Reads a single Ion [`ElementRef`] from the provided slice. If the input has invalid
+    /// data or does not contain at exactly one Ion value, returns `Err(IonError)`.
+    pub fn read_one(data: &'a [u8]) -> IonResult<ElementRef<'a>> {
+        let mut elements = Self::read_all(data)?;
+        match elements.len() {
+            1 => Ok(elements.pop().unwrap()),
+            0 => decoding_error("expected 1 value, found 0"),
+            _ => decoding_error(format!(
+                "found more than one value; second value: {}",
+                elements[1].to_owned()
+            )),
+        }
+    }
+
+    /// Reads all available [`ElementRef`]s from the provided slice.
+    ///
+    /// If the input has valid data, returns `Ok(Vec<ElementRef>)`.
+    /// If the input has invalid data, returns `Err(IonError)`.
+    pub fn read_all(data: &'a [u8]) -> IonResult<Vec<ElementRef<'a>>> {
+        let mut reader = ReaderBuilder::default().build_slice(data)?;
+        ElementRefLoader::for_reader(&mut reader).materialize_all()
+    }
+}
+
+impl<'a> IntAccess for ElementRef<'a> {
+    fn as_i64(&self) -> Option<i64> {
+        match self.as_int() {
+            Some(any) => any.as_i64(),
+            _ => None,
+        }
+    }
+
+    fn as_big_int(&self) -> Option<&BigInt> {
+        match self.as_int() {
+            Some(any) => any.as_big_int(),
+            _ => None,
+        }
+    }
+}
+
+/// A reader that can hand out text and lob payloads with the lifetime of its input. Readers
+/// that cannot borrow from their input return owned data.
+trait BorrowingReader<'a>: IonReader<Item = StreamItem, Symbol = Symbol> {
+    fn read_str_cow(&mut self) -> IonResult<Cow<'a, str>>;
+    fn read_blob_cow(&mut self) -> IonResult<Cow<'a, [u8]>>;
+    fn read_clob_cow(&mut self) -> IonResult<Cow<'a, [u8]>>;
+}
+
+impl<'a> BorrowingReader<'a> for SliceReader<'a> {
+    fn read_str_cow(&mut self) -> IonResult<Cow<'a, str>> {
+        SliceReader::read_str_cow(self)
+    }
+
+    fn read_blob_cow(&mut self) -> IonResult<Cow<'a, [u8]>> {
+        SliceReader::read_blob_cow(self)
+    }
+
+    fn read_clob_cow(&mut self) -> IonResult<Cow<'a, [u8]>> {
+        SliceReader::read_clob_cow(self)
+    }
+}
+
+/// Helper type; the [`ElementRef`] counterpart to the `ElementLoader` used by
+/// [`ElementReader`](crate::element::reader::ElementReader).
+struct ElementRefLoader<'r, R> {
+    reader: &'r mut R,
+}
+
+impl<'a, 'r, R: BorrowingReader<'a>> ElementRefLoader<'r, R> {
+    fn for_reader(reader: &'r mut R) -> Self {
+        ElementRefLoader { reader }
+    }
+
+    fn materialize_all(&mut self) -> IonResult<Vec<ElementRef<'a>>> {
+        let mut elements = Vec::new();
+        while let Some(element) = self.materialize_next()? {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+
+    fn materialize_next(&mut self) -> IonResult<Option<ElementRef<'a>>> {
+        let _ = self.reader.next()?;
+        self.materialize_current()
+    }
+
+    fn materialize_current(&mut self) -> IonResult<Option<ElementRef<'a>>> {
+        let mut annotations = Vec::new();
+        if self.reader.has_annotations() {
+            for annotation in self.reader.annotations() {
+                annotations.push(annotation?);
+            }
+        }
+
+        let value = match self.reader.current() {
+            StreamItem::Nothing => return Ok(None),
+            StreamItem::Null(ion_type) => ValueRef::Null(ion_type),
+            StreamItem::Value(ion_type) => {
+                use crate::IonType::*;
+                match ion_type {
+                    Null => unreachable!("non-null value had IonType::Null"),
+                    Bool => ValueRef::Bool(self.reader.read_bool()?),
+                    Int => ValueRef::Int(self.reader.read_int()?),
+                    Float => ValueRef::Float(self.reader.read_f64()?),
+                    Decimal => ValueRef::Decimal(Box::new(self.reader.read_decimal()?)),
+                    Timestamp => ValueRef::Timestamp(Box::new(self.reader.read_timestamp()?)),
+                    Symbol => ValueRef::Symbol(self.reader.read_symbol()?),
+                    String => ValueRef::String(self.reader.read_str_cow()?),
+                    Clob => ValueRef::Clob(self.reader.read_clob_cow()?),
+                    Blob => ValueRef::Blob(self.reader.read_blob_cow()?),
+                    List => ValueRef::List(self.materialize_sequence()?),
+                    SExp => ValueRef::SExp(self.materialize_sequence()?),
+                    Struct => ValueRef::Struct(self.materialize_struct()?),
+                }
+            }
+        };
+        Ok(Some(ElementRef::new(Annotations::new(annotations), value)))
+    }
+
+    fn materialize_sequence(&mut self) -> IonResult<Vec<ElementRef<'a>>> {
+        let mut child_elements = Vec::new();
+        self.reader.step_in()?;
+        while let Some(element) = self.materialize_next()? {
+            child_elements.push(element);
+        }
+        self.reader.step_out()?;
+        Ok(child_elements)
+    }
+
+    fn materialize_struct(&mut self) -> IonResult<StructRef<'a>> {
+        let mut fields = Vec::new();
+        self.reader.step_in()?;
+        while let StreamItem::Value(_) | StreamItem::Null(_) = self.reader.next()? {
+            let field_name = self.reader.field_name()?;
+            let value = self
+                .materialize_current()?
+                .expect("materialize_current() returned None for user data");
+            fields.push((field_name, value));
+        }
+        self.reader.step_out()?;
+        Ok(StructRef { fields })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::element::writer::ElementWriter;
+    use crate::{BinaryWriterBuilder, IonWriter};
+    use rstest::*;
+
+    fn to_binary(ion_text: &str) -> Vec<u8> {
+        let elements = Element::read_all(ion_text).unwrap();
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriterBuilder::new().build(&mut buffer).unwrap();
+        writer.write_elements(&elements).unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+        buffer
+    }
+
+    fn points_into(input: &[u8], bytes: &[u8]) -> bool {
+        let range = input.as_ptr_range();
+        range.contains(&bytes.as_ptr())
+    }
+
+    #[rstest]
+    #[case("1 2.5e0 3.5 2023-01-01T foo::\"bar\" null.int")]
+    #[case("{a: 1, b: [\"two\", {{Mw==}}], c: (d e::{f: {{\"g\"}}})}")]
+    #[case("[] () {} \"\" {{}}")]
+    fn to_owned_matches_element(#[case] ion_text: &str) {
+        let expected = Element::read_all(ion_text).unwrap();
+        let from_text: Vec<Element> = ElementRef::read_all(ion_text.as_bytes())
+            .unwrap()
+            .iter()
+            .map(ElementRef::to_owned)
+            .collect();
+        assert_eq!(from_text, expected);
+
+        let binary = to_binary(ion_text);
+        let from_binary: Vec<Element> = ElementRef::read_all(&binary)
+            .unwrap()
+            .iter()
+            .map(ElementRef::to_owned)
+            .collect();
+        assert_eq!(from_binary, expected);
+    }
+
+    #[test]
+    fn binary_payloads_borrow_from_input() {
+        let binary = to_binary("{name: \"Fido\", photo: {{aGVsbG8=}}, notes: {{\"good dog\"}}}");
+        let element = ElementRef::read_one(&binary).unwrap();
+        let fields = element.as_struct().unwrap();
+        match fields.get("name").unwrap().value() {
+            ValueRef::String(Cow::Borrowed(text)) => {
+                assert_eq!(*text, "Fido");
+                assert!(points_into(&binary, text.as_bytes()));
+            }
+            other => panic!("expected a borrowed string, found {other:?}"),
+        }
+        match fields.get("photo").unwrap().value() {
+            ValueRef::Blob(Cow::Borrowed(bytes)) => {
+                assert_eq!(*bytes, b"hello");
+                assert!(points_into(&binary, bytes));
+            }
+            other => panic!("expected a borrowed blob, found {other:?}"),
+        }
+        match fields.get("notes").unwrap().value() {
+            ValueRef::Clob(Cow::Borrowed(bytes)) => {
+                assert_eq!(*bytes, b"good dog");
+                assert!(points_into(&binary, bytes));
+            }
+            other => panic!("expected a borrowed clob, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn accessors() {
+        let element = ElementRef::read_one(b"foo::{a: 1, a: 2, b: bar, c: (1 2 3)}").unwrap();
+        assert_eq!(element.ion_type(), IonType::Struct);
+        assert!(element.annotations().contains("foo"));
+        let fields = element.as_struct().unwrap();
+        assert_eq!(fields.len(), 4);
+        assert_eq!(fields.get("a").unwrap().as_i64(), Some(2));
+        let all_a: Vec<i64> = fields.get_all("a").filter_map(|e| e.as_i64()).collect();
+        assert_eq!(all_a, vec![1, 2]);
+        assert_eq!(fields.get("b").unwrap().as_text(), Some("bar"));
+        assert_eq!(fields.get("c").unwrap().as_sequence().unwrap().len(), 3);
+        assert!(fields.get("d").is_none());
+        let names: Vec<&str> = fields
+            .fields()
+            .filter_map(|(name, _)| name.text())
+            .collect();
+        assert_eq!(names, vec!["a", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn fields_with_unknown_text() {
+        let element = ElementRef::read_one(b"{$0: 1, a: 2}").unwrap();
+        let fields = element.as_struct().unwrap();
+        assert_eq!(
+            fields.get(Symbol::unknown_text()).unwrap().as_i64(),
+            Some(1)
+        );
+        assert_eq!(fields.get_all(&Symbol::owned("a")).count(), 1);
+        assert_eq!(
+            element.to_owned(),
+            Element::read_one("{$0: 1, a: 2}").unwrap()
+        );
+    }
+
+    #[test]
+    fn read_one_requires_exactly_one_value() {
+        assert!(ElementRef::read_one(b"").is_err());
+        assert!(ElementRef::read_one(b"1 2").is_err());
+        assert_eq!(ElementRef::read_first(b"").unwrap(), None);
+    }
+}
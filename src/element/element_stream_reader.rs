@@ -1,16 +1,25 @@
 use crate::result::{decoding_error, illegal_operation, illegal_operation_raw};
 use crate::text::parent_container::ParentContainer;
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
 use crate::element::iterators::SymbolsIterator;
 use crate::element::{Blob, Clob, Element};
+use crate::types::{Encoding, Span};
 use crate::{
     Decimal, Int, IonError, IonReader, IonResult, IonType, Str, StreamItem, Symbol, Timestamp,
 };
-use std::fmt::Display;
-use std::mem;
+use core::fmt::Display;
+use core::mem;
 
 const INITIAL_PARENTS_CAPACITY: usize = 16;
 
+// An iterator over the (field name, value) pairs of a container.
+type ChildValues = Box<dyn Iterator<Item = (Option<Symbol>, Element)> + Send>;
+
 // TODO: Add an IonElementReader trait implementation
 // TODO: once ElementReader trait is removed this can  use the name `ElementReader`
 pub struct ElementStreamReader {
@@ -18,8 +27,8 @@ pub struct ElementStreamReader {
     element: Option<Element>,
     // If the reader is not positioned on a struct the iterator item will store (None, _element_)
     // Otherwise it will store (Some(_field_name_), _element_)
-    current_iter: Box<dyn Iterator<Item = (Option<Symbol>, Element)>>,
-    iter_stack: Vec<Box<dyn Iterator<Item = (Option<Symbol>, Element)>>>,
+    current_iter: ChildValues,
+    iter_stack: Vec<ChildValues>,
     // If the reader is not positioned over a value inside a struct, this is None.
     current_field_name: Option<Symbol>,
     // If the reader has not yet begun reading at the current level or is positioned over an IVM,
@@ -33,7 +42,7 @@ impl ElementStreamReader {
     pub fn new(input: Element) -> ElementStreamReader {
         ElementStreamReader {
             element: Some(input),
-            current_iter: Box::new(std::iter::empty()),
+            current_iter: Box::new(core::iter::empty()),
             iter_stack: vec![],
             current_field_name: None,
             current_value: None,
@@ -106,7 +115,7 @@ impl ElementStreamReader {
         ))
     }
 
-    fn container_values(value: Element) -> Box<dyn Iterator<Item = (Option<Symbol>, Element)>> {
+    fn container_values(value: Element) -> ChildValues {
         match value.ion_type() {
             IonType::List | IonType::SExp => Box::new(
                 value
@@ -218,9 +227,7 @@ impl IonReader for ElementStreamReader {
         match self.current_value.as_ref() {
             Some(element) if element.as_int().is_some() => match element.as_int().unwrap() {
                 Int::I64(value) => Ok(*value),
-                Int::BigInt(value) => {
-                    decoding_error(format!("Integer {value} is too large to fit in an i64."))
-                }
+                value => decoding_error(format!("Integer {value} is too large to fit in an i64.")),
             },
             _ => Err(self.expected("int value")),
         }
@@ -234,6 +241,14 @@ impl IonReader for ElementStreamReader {
         self.current_value_as("float value", |v| v.as_float())
     }
 
+    fn encoding(&self) -> Option<Encoding> {
+        self.current_value.as_ref().and_then(Element::encoding)
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.current_value.as_ref().and_then(Element::span).cloned()
+    }
+
     fn read_decimal(&mut self) -> IonResult<Decimal> {
         self.current_value_as("decimal value", |v| v.as_decimal().map(|i| i.to_owned()))
     }
@@ -354,7 +369,7 @@ impl IonReader for ElementStreamReader {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod reader_tests {
     use rstest::*;
 
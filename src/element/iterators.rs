@@ -10,7 +10,7 @@ macro_rules! create_new_slice_iterator_type {
         // iterator without requiring that an empty Vec or slice be provided. This sidesteps some
         // hairy lifetime issues.
         pub struct $iterator_name<'a> {
-            values: Option<std::slice::Iter<'a, $item_name>>
+            values: Option<core::slice::Iter<'a, $item_name>>
         }
 
         impl<'a> $iterator_name<'a> {
@@ -47,11 +47,11 @@ create_new_slice_iterator_type!(
 /// Consuming iterator for [`Annotations`](crate::element::Annotations).
 #[derive(Debug, Clone)]
 pub struct AnnotationsIntoIter {
-    into_iter: std::vec::IntoIter<Symbol>,
+    into_iter: alloc::vec::IntoIter<Symbol>,
 }
 
 impl AnnotationsIntoIter {
-    pub(crate) fn new(into_iter: std::vec::IntoIter<Symbol>) -> Self {
+    pub(crate) fn new(into_iter: alloc::vec::IntoIter<Symbol>) -> Self {
         Self { into_iter }
     }
 }
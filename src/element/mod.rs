@@ -16,13 +16,18 @@ use crate::element::builders::{SequenceBuilder, StructBuilder};
 use crate::element::reader::ElementReader;
 use crate::ion_data::{IonEq, IonOrd};
 use crate::text::text_formatter::IonValueFormatter;
+use crate::types::{Encoding, Span};
 use crate::{ion_data, Decimal, Int, IonResult, IonType, ReaderBuilder, Str, Symbol, Timestamp};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
 use num_bigint::BigInt;
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
 
 mod annotations;
 pub mod builders;
+mod element_ref;
 mod element_stream_reader;
 pub(crate) mod iterators;
 pub mod reader;
@@ -31,6 +36,7 @@ pub mod writer;
 // Re-export the Value variant types and traits so they can be accessed directly from this module.
 pub use crate::types::{Blob, Bytes, Clob};
 pub use annotations::{Annotations, IntoAnnotations};
+pub use element_ref::{ElementRef, StructRef, ValueRef};
 
 pub use crate::types::{List, SExp, Sequence, Struct};
 
@@ -101,14 +107,17 @@ impl IonOrd for Value {
 }
 
 /// Variants for all _values_ within an [`Element`].
+///
+/// Payloads that are both comparatively large and comparatively rare (`Decimal` and `Timestamp`)
+/// are boxed so that they do not inflate the size of every `Value`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Null(IonType),
     Bool(bool),
     Int(Int),
     Float(f64),
-    Decimal(Decimal),
-    Timestamp(Timestamp),
+    Decimal(Box<Decimal>),
+    Timestamp(Box<Timestamp>),
     Symbol(Symbol),
     String(Str),
     Clob(Bytes),
@@ -141,7 +150,7 @@ impl Value {
 }
 
 impl Display for Value {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let mut ivf = IonValueFormatter { output: f };
         match &self {
             Value::Null(ion_type) => ivf.format_null(*ion_type),
@@ -158,7 +167,7 @@ impl Display for Value {
             Value::SExp(sequence) => ivf.format_sexp(sequence),
             Value::Struct(struct_) => ivf.format_struct(struct_),
         }
-        .map_err(|_| std::fmt::Error)?;
+        .map_err(|_| core::fmt::Error)?;
 
         Ok(())
     }
@@ -178,7 +187,7 @@ impl From<i64> for Value {
 
 impl From<BigInt> for Value {
     fn from(big_int_val: BigInt) -> Self {
-        Value::Int(Int::BigInt(big_int_val))
+        Value::Int(Int::from(big_int_val))
     }
 }
 
@@ -196,13 +205,13 @@ impl From<f64> for Value {
 
 impl From<Decimal> for Value {
     fn from(decimal_val: Decimal) -> Self {
-        Value::Decimal(decimal_val)
+        Value::Decimal(Box::new(decimal_val))
     }
 }
 
 impl From<Timestamp> for Value {
     fn from(timestamp_val: Timestamp) -> Self {
-        Value::Timestamp(timestamp_val)
+        Value::Timestamp(Box::new(timestamp_val))
     }
 }
 
@@ -343,6 +352,17 @@ impl IonOrd for Element {
 pub struct Element {
     annotations: Annotations,
     value: Value,
+    // Only populated by readers that were asked to preserve encodings or track spans. This is
+    // not part of the Ion data model and is ignored by equality and ordering. It is boxed so
+    // that elements without metadata (the vast majority) stay small.
+    metadata: Option<Box<Metadata>>,
+}
+
+/// Details about how and where an [Element] was encoded in its source.
+#[derive(Debug, Clone, Default)]
+struct Metadata {
+    encoding: Option<Encoding>,
+    span: Option<Span>,
 }
 
 impl Element {
@@ -350,9 +370,14 @@ impl Element {
         Self {
             annotations,
             value: value.into(),
+            metadata: None,
         }
     }
 
+    fn metadata_mut(&mut self) -> &mut Metadata {
+        self.metadata.get_or_insert_with(Default::default)
+    }
+
     /// Returns a reference to this [Element]'s [Value].
     ///
     /// ```
@@ -430,7 +455,73 @@ impl Element {
     }
 
     pub fn with_annotations<I: IntoAnnotations>(self, annotations: I) -> Self {
-        Element::new(annotations.into_annotations(), self.value)
+        Element {
+            annotations: annotations.into_annotations(),
+            ..self
+        }
+    }
+
+    /// Returns the [`Encoding`] this element's value had in its source, if it was read by a
+    /// reader configured to [preserve encodings](crate::ReaderBuilder::preserve_encoding).
+    /// The binary writer uses the encoding to write floats with their original width.
+    pub fn encoding(&self) -> Option<Encoding> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.encoding)
+    }
+
+    /// Returns a copy of this element that will be written using the specified [`Encoding`]
+    /// where the output format allows. An encoding that does not apply to the element's value
+    /// (for example, a float width on a string) is ignored.
+    ///
+    /// ```
+    /// use ion_rs::element::Element;
+    /// use ion_rs::types::{Encoding, FloatWidth};
+    /// let element = Element::float(0.5).with_encoding(Encoding::Float(FloatWidth::F64));
+    /// assert_eq!(element.encoding(), Some(Encoding::Float(FloatWidth::F64)));
+    /// // Encodings are not part of the data model
+    /// assert_eq!(element, Element::float(0.5));
+    /// ```
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.set_encoding(encoding);
+        self
+    }
+
+    pub(crate) fn set_encoding(&mut self, encoding: Encoding) {
+        self.metadata_mut().encoding = Some(encoding);
+    }
+
+    /// Returns the [`Span`] this element occupied in its source, if it was read by a reader
+    /// configured to [track spans](crate::ReaderBuilder::track_spans).
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::element::Element;
+    /// use ion_rs::element::reader::ElementReader;
+    /// use ion_rs::{IonData, ReaderBuilder};
+    /// let element = ReaderBuilder::new()
+    ///     .track_spans(true)
+    ///     .build("// a comment\nfoo::bar")?
+    ///     .read_one_element()?;
+    /// let span = element.span().unwrap();
+    /// assert_eq!(span.range(), 13..21);
+    /// assert_eq!(span.line(), Some(2));
+    /// // Spans are not part of the data model
+    /// let unspanned = Element::read_one("foo::bar")?;
+    /// assert_eq!(IonData::from(&element), IonData::from(&unspanned));
+    /// assert_eq!(element, unspanned);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn span(&self) -> Option<&Span> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.span.as_ref())
+    }
+
+    pub(crate) fn set_span(&mut self, span: Span) {
+        self.metadata_mut().span = Some(span);
     }
 
     pub fn is_null(&self) -> bool {
@@ -529,6 +620,28 @@ impl Element {
         }
     }
 
+    /// Returns a mutable reference to this element's [Sequence] if it is a list or s-expression.
+    ///
+    /// Containers share their storage with any clones, so modifying the returned value will
+    /// only copy this container (and not its siblings or children) if it is currently shared.
+    pub fn as_sequence_mut(&mut self) -> Option<&mut Sequence> {
+        match &mut self.value {
+            Value::SExp(s) | Value::List(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to this element's [Struct] if it is a struct.
+    ///
+    /// Containers share their storage with any clones, so modifying the returned value will
+    /// only copy this container (and not its siblings or children) if it is currently shared.
+    pub fn as_struct_mut(&mut self) -> Option<&mut Struct> {
+        match &mut self.value {
+            Value::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
+
     /// Reads a single Ion [`Element`] from the provided data source.
     ///
     /// If the data source is empty, returns `Ok(None)`.
@@ -537,7 +650,7 @@ impl Element {
     pub fn read_first<A: AsRef<[u8]>>(data: A) -> IonResult<Option<Element>> {
         let bytes: &[u8] = data.as_ref();
         // Create an iterator over the Elements in the data
-        let mut reader = ReaderBuilder::default().build(bytes)?;
+        let mut reader = ReaderBuilder::default().build_slice(bytes)?;
         reader.read_next_element()
     }
 
@@ -546,7 +659,7 @@ impl Element {
     pub fn read_one<A: AsRef<[u8]>>(data: A) -> IonResult<Element> {
         let bytes: &[u8] = data.as_ref();
         // Create an iterator over the Elements in the data
-        let mut reader = ReaderBuilder::default().build(bytes)?;
+        let mut reader = ReaderBuilder::default().build_slice(bytes)?;
         reader.read_one_element()
     }
 
@@ -556,17 +669,20 @@ impl Element {
     /// If the input has invalid data, returns `Err(IonError)`.
     pub fn read_all<A: AsRef<[u8]>>(data: A) -> IonResult<Vec<Element>> {
         let bytes: &[u8] = data.as_ref();
-        ReaderBuilder::default().build(bytes)?.elements().collect()
+        ReaderBuilder::default()
+            .build_slice(bytes)?
+            .elements()
+            .collect()
     }
 }
 
 impl Display for Element {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         let mut ivf = IonValueFormatter { output: f };
 
         // display for annotations of this element
         ivf.format_annotations(&self.annotations)
-            .map_err(|_| std::fmt::Error)?;
+            .map_err(|_| core::fmt::Error)?;
 
         self.value.fmt(f)
     }
@@ -602,6 +718,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::element::annotations::IntoAnnotations;
+    use crate::element::{Sequence, Value};
     use crate::types::Timestamp;
     use crate::{ion_list, ion_sexp, ion_struct, Decimal, Int, IonType, Symbol};
     use chrono::*;
@@ -613,6 +730,17 @@ mod tests {
         DateTime::parse_from_rfc3339(text.as_ref()).unwrap().into()
     }
 
+    #[test]
+    fn elements_are_send_and_sync() {
+        fn assert_send_and_sync<T: Send + Sync>() {}
+        assert_send_and_sync::<Element>();
+        assert_send_and_sync::<Value>();
+        assert_send_and_sync::<Symbol>();
+        assert_send_and_sync::<Sequence>();
+        assert_send_and_sync::<Struct>();
+        assert_send_and_sync::<Annotations>();
+    }
+
     struct CaseAnnotations {
         elem: Element,
         annotations: Annotations,
@@ -911,7 +1039,7 @@ mod tests {
             ops: vec![AsAnyInt],
             op_assert: Box::new(|e: &Element| {
                 let expected: Element = BigInt::from(100).into();
-                assert_eq!(Some(&Int::BigInt(BigInt::from(100))), e.as_int());
+                assert_eq!(Some(&Int::from(BigInt::from(100))), e.as_int());
                 assert_eq!(BigInt::from_str("100").unwrap(), *e.as_big_int().unwrap());
                 assert_eq!(&expected, e);
             }),
@@ -1124,6 +1252,7 @@ mod tests {
 #[cfg(test)]
 mod value_tests {
     use crate::element::*;
+    #[cfg(feature = "std")]
     use crate::ion_data::IonEq;
     use crate::{ion_list, ion_sexp, ion_struct, IonType};
     use rstest::*;
@@ -1145,6 +1274,19 @@ mod value_tests {
         });
     }
 
+    // Every value in a document is an `Element`, so growing it (including through padding added by
+    // a more strictly aligned field) has a cost for every reader. See `benches/element_layout.rs`.
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn element_layout() {
+        use std::mem::{align_of, size_of};
+        assert_eq!(align_of::<Element>(), 8);
+        assert_eq!(size_of::<Int>(), 24);
+        assert_eq!(size_of::<Value>(), 24);
+        assert_eq!(size_of::<Element>(), 48);
+    }
+
+    #[cfg(feature = "std")]
     #[rstest]
     #[case::strings(
         Element::from("hello"), // An explicitly constructed String Element
@@ -1226,6 +1368,7 @@ mod value_tests {
         }
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn list_display_roundtrip() {
         let list = ion_list![1, 2, 3, true, false];
@@ -1238,6 +1381,7 @@ mod value_tests {
         assert!(expected_element.ion_eq(&actual_element));
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn sexp_display_roundtrip() {
         let sexp = ion_sexp! (1 2 3 true false);
@@ -1250,6 +1394,7 @@ mod value_tests {
         assert!(expected_element.ion_eq(&actual_element));
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn struct_display_roundtrip() {
         let struct_ = ion_struct! {"foo": 1, "bar": 2, "baz": ion_list! [true, false]};
@@ -1261,4 +1406,84 @@ mod value_tests {
         let actual_element = Element::read_one(text_struct).unwrap();
         assert!(expected_element.ion_eq(&actual_element));
     }
+
+    #[test]
+    fn clones_share_container_storage() {
+        let original: Element = ion_struct! {
+            "name": "service",
+            "limits": ion_list![1, 2, 3],
+            "config": ion_struct! { "retries": 3 }
+        }
+        .into();
+        let clone = original.clone();
+        assert!(original
+            .as_struct()
+            .unwrap()
+            .ptr_eq(clone.as_struct().unwrap()));
+    }
+
+    #[test]
+    fn modifying_clone_copies_only_the_path() {
+        let original: Element = ion_struct! {
+            "limits": ion_list![1, 2, 3],
+            "config": ion_struct! { "retries": 3 }
+        }
+        .into();
+        let mut modified = original.clone();
+        *modified
+            .as_struct_mut()
+            .and_then(|s| s.get_mut("config"))
+            .and_then(Element::as_struct_mut)
+            .and_then(|s| s.get_mut("retries"))
+            .unwrap() = Element::integer(5);
+
+        let original_struct = original.as_struct().unwrap();
+        let modified_struct = modified.as_struct().unwrap();
+        // The top level and the modified child were copied...
+        assert!(!original_struct.ptr_eq(modified_struct));
+        let original_config = original_struct.get("config").unwrap().as_struct().unwrap();
+        let modified_config = modified_struct.get("config").unwrap().as_struct().unwrap();
+        assert!(!original_config.ptr_eq(modified_config));
+        assert_eq!(original_config.get("retries"), Some(&Element::integer(3)));
+        assert_eq!(modified_config.get("retries"), Some(&Element::integer(5)));
+        // ...but the untouched sibling is still shared.
+        let original_limits = original_struct
+            .get("limits")
+            .unwrap()
+            .as_sequence()
+            .unwrap();
+        let modified_limits = modified_struct
+            .get("limits")
+            .unwrap()
+            .as_sequence()
+            .unwrap();
+        assert!(original_limits.ptr_eq(modified_limits));
+    }
+
+    #[test]
+    fn sequence_mutation_does_not_affect_clones() {
+        use crate::types::IntAccess;
+        let original: Element = ion_list![1, 2, 3].into();
+        let mut modified = original.clone();
+        let sequence = modified.as_sequence_mut().unwrap();
+        sequence.push(4);
+        assert_eq!(sequence.remove(0), Some(Element::integer(1)));
+        for element in sequence.elements_mut() {
+            *element = Element::integer(element.as_i64().unwrap() * 10);
+        }
+        assert_eq!(original, ion_list![1, 2, 3].into());
+        assert_eq!(modified, ion_list![20, 30, 40].into());
+    }
+
+    #[test]
+    fn struct_add_field_does_not_affect_clones() {
+        let original = ion_struct! { "foo": 1 };
+        let mut modified = original.clone();
+        modified.add_field("foo", 2);
+        modified.add_field("bar", 3);
+        assert_eq!(original, ion_struct! { "foo": 1 });
+        assert_eq!(modified.get("foo"), Some(&Element::integer(2)));
+        assert_eq!(modified.get_all("foo").count(), 2);
+        assert_eq!(modified.len(), 3);
+    }
 }
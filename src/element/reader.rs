@@ -6,6 +6,8 @@
 use crate::element::{Annotations, Element, Sequence, Struct, Value};
 use crate::result::{decoding_error, IonResult};
 use crate::{IonReader, StreamItem, Symbol};
+use alloc::format;
+use alloc::vec::Vec;
 
 /// Reads Ion data into [`Element`] instances.
 ///
@@ -130,6 +132,11 @@ impl<'a, R: IonReader<Item = StreamItem, Symbol = Symbol>> ElementLoader<'a, R>
             }
         }
 
+        // Any encoding details describe the current value, so they must also be captured before
+        // the reader advances.
+        let encoding = self.reader.encoding();
+        let mut span = self.reader.span();
+
         let value = match self.reader.current() {
             // No more values at this level of the stream
             StreamItem::Nothing => return Ok(None),
@@ -143,8 +150,8 @@ impl<'a, R: IonReader<Item = StreamItem, Symbol = Symbol>> ElementLoader<'a, R>
                     Bool => Value::Bool(self.reader.read_bool()?),
                     Int => Value::Int(self.reader.read_int()?),
                     Float => Value::Float(self.reader.read_f64()?),
-                    Decimal => Value::Decimal(self.reader.read_decimal()?),
-                    Timestamp => Value::Timestamp(self.reader.read_timestamp()?),
+                    Decimal => self.reader.read_decimal()?.into(),
+                    Timestamp => self.reader.read_timestamp()?.into(),
                     Symbol => Value::Symbol(self.reader.read_symbol()?),
                     String => Value::String(self.reader.read_string()?),
                     Clob => Value::Clob(self.reader.read_clob()?.into()),
@@ -156,7 +163,19 @@ impl<'a, R: IonReader<Item = StreamItem, Symbol = Symbol>> ElementLoader<'a, R>
                 }
             }
         };
-        Ok(Some(Element::new(Annotations::new(annotations), value)))
+        if self.reader.ion_type().is_none() {
+            // A reader that has just stepped out of a container may only now know where the
+            // container ended.
+            span = self.reader.span().or(span);
+        }
+        let mut element = Element::new(Annotations::new(annotations), value);
+        if let Some(encoding) = encoding {
+            element.set_encoding(encoding);
+        }
+        if let Some(span) = span {
+            element.set_span(span);
+        }
+        Ok(Some(element))
     }
 
     /// Steps into the current sequence and materializes each of its children to construct
@@ -190,7 +209,7 @@ impl<'a, R: IonReader<Item = StreamItem, Symbol = Symbol>> ElementLoader<'a, R>
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod reader_tests {
     use super::*;
     use crate::element::builders::{ion_list, ion_sexp, ion_struct};
@@ -198,10 +217,11 @@ mod reader_tests {
     use crate::element::{Element, IntoAnnotatedElement};
     use crate::ion_data::IonEq;
     use crate::types::{Int, Timestamp as TS};
-    use crate::{IonType, Symbol};
+    use crate::{IonType, ReaderBuilder, Symbol};
     use bigdecimal::BigDecimal;
     use num_bigint::BigInt;
     use rstest::*;
+    use std::ops::Range;
     use std::str::FromStr;
 
     #[rstest]
@@ -256,7 +276,7 @@ mod reader_tests {
                 "-18446744073709551616", "18446744073709551615",
                 "-79228162514264337593543950336", "79228162514264337593543950335",
             ].into_iter()
-            .map(|v| Int::BigInt(BigInt::parse_bytes(v.as_bytes(), 10).unwrap()))
+            .map(|v| Int::from(BigInt::parse_bytes(v.as_bytes(), 10).unwrap()))
         ).map(|ai| Int(ai).into()).collect(),
     )]
     #[case::int64_threshold_as_big_int(
@@ -264,14 +284,14 @@ mod reader_tests {
         vec![
             "18446744073709551615",
         ].into_iter()
-        .map(|v| Int::BigInt(BigInt::parse_bytes(v.as_bytes(), 10).unwrap())).map(|ai| Int(ai).into()).collect(),
+        .map(|v| Int::from(BigInt::parse_bytes(v.as_bytes(), 10).unwrap())).map(|ai| Int(ai).into()).collect(),
     )]
     #[case::int64_threshold_as_int64(
         &[0xE0, 0x01, 0x00, 0xEA, 0x38, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
         vec![
             "-9223372036854775808",
         ].into_iter()
-        .map(|v| Int::BigInt(BigInt::parse_bytes(v.as_bytes(), 10).unwrap())).map(|ai| Int(ai).into()).collect(),
+        .map(|v| Int::from(BigInt::parse_bytes(v.as_bytes(), 10).unwrap())).map(|ai| Int(ai).into()).collect(),
     )]
     #[case::floats(
         br#"
@@ -287,7 +307,7 @@ mod reader_tests {
         "#,
         vec![
             "1e0", "100e10", "-2.1234567e-100",
-        ].into_iter().map(|s| Decimal(BigDecimal::from_str(s).unwrap().into()).into()).collect(),
+        ].into_iter().map(|s| Decimal(Box::new(BigDecimal::from_str(s).unwrap().into())).into()).collect(),
     )]
     #[case::timestamps(
         br#"
@@ -306,7 +326,7 @@ mod reader_tests {
                 .with_hms(14, 16, 33)
                 .with_milliseconds(123)
                 .build_at_offset(0),
-        ].into_iter().map(|ts_res| Timestamp(ts_res.unwrap()).into()).collect(),
+        ].into_iter().map(|ts_res| Timestamp(Box::new(ts_res.unwrap())).into()).collect(),
     )]
     #[case::text_symbols(
         br#"
@@ -388,4 +408,94 @@ mod reader_tests {
         assert!(expected.ion_eq(&actual));
         Ok(())
     }
+
+    fn read_with_spans(input: &[u8]) -> IonResult<Vec<Element>> {
+        ReaderBuilder::new()
+            .track_spans(true)
+            .build(input)?
+            .read_all_elements()
+    }
+
+    // Returns the byte range, line, and column of an element's span.
+    fn span_of(element: &Element) -> (Range<usize>, Option<usize>, Option<usize>) {
+        let span = element.span().expect("element has no span");
+        (span.range(), span.line(), span.column())
+    }
+
+    #[test]
+    fn text_spans() -> IonResult<()> {
+        let text =
+            "1 /* one */ foo::'bar'\n\n  // a list\n  [2, (x ++ y) ,{a: null.int}]\n\"é\" 3.5";
+        let elements = read_with_spans(text.as_bytes())?;
+        assert_eq!(span_of(&elements[0]), (0..1, Some(1), Some(1)));
+        assert_eq!(span_of(&elements[1]), (12..22, Some(1), Some(13)));
+        assert_eq!(span_of(&elements[2]), (38..66, Some(4), Some(3)));
+        // Columns count characters rather than bytes
+        assert_eq!(span_of(&elements[3]), (67..71, Some(5), Some(1)));
+        assert_eq!(span_of(&elements[4]), (72..75, Some(5), Some(5)));
+
+        let list = elements[2].as_sequence().unwrap();
+        let children: Vec<_> = list.elements().map(span_of).collect();
+        assert_eq!(
+            children,
+            vec![
+                (39..40, Some(4), Some(4)),
+                (42..50, Some(4), Some(7)),
+                (52..65, Some(4), Some(17)),
+            ]
+        );
+        let sexp = list.get(1).unwrap().as_sequence().unwrap();
+        let children: Vec<_> = sexp.elements().map(span_of).collect();
+        assert_eq!(
+            children,
+            vec![
+                (43..44, Some(4), Some(8)),
+                (45..47, Some(4), Some(10)),
+                (48..49, Some(4), Some(13)),
+            ]
+        );
+        let field = list.get(2).unwrap().as_struct().unwrap().get("a").unwrap();
+        assert_eq!(span_of(field), (56..64, Some(4), Some(21)));
+        Ok(())
+    }
+
+    #[test]
+    fn binary_spans() -> IonResult<()> {
+        let ion_data = &[
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0x21, 0x05, // 5
+            0xE4, 0x81, 0x84, 0x21, 0x07, // name::7
+            0xB3, 0x21, 0x01, 0x0F, // [1, null]
+        ];
+        let elements = read_with_spans(ion_data)?;
+        let spans: Vec<_> = elements.iter().map(span_of).collect();
+        assert_eq!(
+            spans,
+            vec![
+                (4..6, None, None),
+                (6..11, None, None),
+                (11..15, None, None)
+            ]
+        );
+        let children: Vec<_> = elements[2]
+            .as_sequence()
+            .unwrap()
+            .elements()
+            .map(span_of)
+            .collect();
+        assert_eq!(children, vec![(12..14, None, None), (14..15, None, None)]);
+        Ok(())
+    }
+
+    #[test]
+    fn spans_are_opt_in_and_ignored_by_equality() -> IonResult<()> {
+        let text = "foo::{a: [1, 2]}";
+        let with_spans = read_with_spans(text.as_bytes())?;
+        let without_spans = Element::read_all(text)?;
+        assert!(with_spans[0].span().is_some());
+        assert!(without_spans[0].span().is_none());
+        assert!(with_spans.ion_eq(&without_spans));
+        assert_eq!(with_spans, without_spans);
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@
 use crate::result::IonResult;
 
 use crate::element::{Element, Value};
+use crate::types::Encoding;
 use crate::{IonType, IonWriter};
 pub use Format::*;
 pub use TextKind::*;
@@ -34,12 +35,15 @@ where
     W: IonWriter,
 {
     fn write_element(&mut self, element: &Element) -> IonResult<()> {
-        self.set_annotations(element.annotations());
+        self.set_annotations(element.annotations())?;
 
         match element.value() {
             Value::Null(ion_type) => self.write_null(*ion_type),
             Value::Int(i) => self.write_int(i),
             Value::Float(f) => {
+                if let Some(Encoding::Float(width)) = element.encoding() {
+                    return self.write_float_with_width(*f, width);
+                }
                 let f = *f;
                 let small_float = f as f32;
                 if (small_float as f64) == f {
@@ -68,7 +72,7 @@ where
             Value::Struct(s) => {
                 self.step_in(IonType::Struct)?;
                 for (name, value) in s.fields() {
-                    self.set_field_name(name);
+                    self.set_field_name(name)?;
                     self.write_element(value)?;
                 }
                 self.step_out()
@@ -85,7 +89,8 @@ pub enum TextKind {
     Pretty,
 }
 
-/// Basic configuration options for [`ElementWriter`] instances.
+/// Basic configuration options for [`ElementWriter`] instances. A
+/// [`WriterBuilder`](crate::WriterBuilder) constructs a [`Writer`](crate::Writer) for any `Format`.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Format {
     Text(TextKind),
@@ -93,15 +98,18 @@ pub enum Format {
     // TODO a mode for Json(TextKind)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::element::writer::ElementWriter;
     use crate::element::Element;
     use crate::ion_data::IonEq;
     use crate::text::text_writer::TextWriterBuilder;
 
-    use crate::{IonResult, IonWriter};
+    use crate::element::reader::ElementReader;
+    use crate::types::{Encoding, FloatWidth};
+    use crate::{BinaryWriterBuilder, IonResult, IonWriter, ReaderBuilder};
     use nom::AsBytes;
+    use rstest::*;
 
     #[test]
     fn element_roundtrip() -> IonResult<()> {
@@ -118,4 +126,59 @@ mod tests {
         assert!(expected_elements.ion_eq(&actual_elements));
         Ok(())
     }
+
+    fn binary_roundtrip(ion_data: &[u8], preserve_encoding: bool) -> IonResult<Vec<u8>> {
+        let elements = ReaderBuilder::new()
+            .preserve_encoding(preserve_encoding)
+            .build(ion_data)?
+            .read_all_elements()?;
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        writer.write_elements(&elements)?;
+        writer.flush()?;
+        drop(writer);
+        Ok(buffer)
+    }
+
+    #[rstest]
+    // 0.5 as a 64-bit float
+    #[case::f64(&[0x48, 0x3F, 0xE0, 0, 0, 0, 0, 0, 0])]
+    // 0.5 as a 32-bit float
+    #[case::f32(&[0x44, 0x3F, 0x00, 0, 0])]
+    // Positive zero as an empty, 32-bit and 64-bit float
+    #[case::zero_empty(&[0x40])]
+    #[case::zero_f32(&[0x44, 0, 0, 0, 0])]
+    #[case::zero_f64(&[0x48, 0, 0, 0, 0, 0, 0, 0, 0])]
+    // null.null, null.int, null.float
+    #[case::typed_nulls(&[0x0F, 0x2F, 0x4F])]
+    // 2000-01-01T00:00 at offsets -00:00 and +00:00
+    #[case::timestamp_offsets(&[0x67, 0xC0, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x67, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80])]
+    // -0d0
+    #[case::negative_zero_decimal(&[0x52, 0x80, 0x80])]
+    fn preserved_encoding_roundtrip(#[case] body: &[u8]) -> IonResult<()> {
+        let mut ion_data = vec![0xE0, 0x01, 0x00, 0xEA];
+        ion_data.extend_from_slice(body);
+        assert_eq!(binary_roundtrip(&ion_data, true)?, ion_data);
+        Ok(())
+    }
+
+    #[test]
+    fn float_width_is_not_preserved_by_default() -> IonResult<()> {
+        let ion_data = [0xE0, 0x01, 0x00, 0xEA, 0x48, 0x3F, 0xE0, 0, 0, 0, 0, 0, 0];
+        let output = binary_roundtrip(&ion_data, false)?;
+        assert_eq!(output, [0xE0, 0x01, 0x00, 0xEA, 0x44, 0x3F, 0x00, 0, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn text_writer_ignores_float_width() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+        let element = Element::float(0.5).with_encoding(Encoding::Float(FloatWidth::F32));
+        writer.write_element(&element)?;
+        writer.flush()?;
+        let actual = Element::read_one(writer.output().as_bytes())?;
+        assert_eq!(actual, Element::float(0.5));
+        Ok(())
+    }
 }
@@ -0,0 +1,206 @@
+//! The I/O traits used by the binary reader and writer.
+//!
+//! When the `std` feature is enabled, these are re-exports of the corresponding items in
+//! [`std::io`]. Otherwise, this module provides minimal stand-ins that are implemented for
+//! byte slices and `Vec<u8>`, which allows the binary reader and writer to be used without the
+//! standard library.
+
+#[cfg(feature = "std")]
+pub use std::io::{Error, ErrorKind, Read, Result, Write};
+
+#[cfg(not(feature = "std"))]
+pub use self::no_std_io::*;
+
+#[cfg(not(feature = "std"))]
+mod no_std_io {
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+    use core::fmt;
+
+    /// The result of an I/O operation.
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// The kinds of failure that can be reported by the I/O traits in this module.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum ErrorKind {
+        /// A write could not be completed because the sink is full.
+        WriteZero,
+        /// A read could not be completed because the source ran out of data.
+        UnexpectedEof,
+        /// Any other failure.
+        Other,
+    }
+
+    /// An error reported by a [`Read`] or [`Write`] implementation.
+    #[derive(Debug)]
+    pub struct Error {
+        kind: ErrorKind,
+    }
+
+    impl Error {
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl From<ErrorKind> for Error {
+        fn from(kind: ErrorKind) -> Self {
+            Error { kind }
+        }
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let description = match self.kind {
+                ErrorKind::WriteZero => "failed to write whole buffer",
+                ErrorKind::UnexpectedEof => "failed to fill whole buffer",
+                ErrorKind::Other => "other error",
+            };
+            f.write_str(description)
+        }
+    }
+
+    /// A sink for bytes. This is a subset of `std::io::Write`.
+    pub trait Write {
+        /// Writes some prefix of `buf`, returning the number of bytes that were written.
+        fn write(&mut self, buf: &[u8]) -> Result<usize>;
+
+        /// Writes any buffered data to its destination.
+        fn flush(&mut self) -> Result<()>;
+
+        /// Writes all of `buf`.
+        fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
+            while !buf.is_empty() {
+                match self.write(buf)? {
+                    0 => return Err(ErrorKind::WriteZero.into()),
+                    n => buf = &buf[n..],
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl Write for Vec<u8> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            self.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Writes into the beginning of the slice, which is then advanced past the written bytes.
+    impl Write for &mut [u8] {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            let length = buf.len().min(self.len());
+            let (head, tail) = core::mem::take(self).split_at_mut(length);
+            head.copy_from_slice(&buf[..length]);
+            *self = tail;
+            Ok(length)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Appends to the vector, writing as many bytes as will fit in its remaining capacity.
+    impl<const N: usize> Write for arrayvec::ArrayVec<u8, N> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            let length = buf.len().min(self.remaining_capacity());
+            self.try_extend_from_slice(&buf[..length])
+                .expect("length does not exceed the remaining capacity");
+            Ok(length)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<W: Write + ?Sized> Write for &mut W {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            (**self).write(buf)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            (**self).flush()
+        }
+    }
+
+    impl<W: Write + ?Sized> Write for Box<W> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            (**self).write(buf)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            (**self).flush()
+        }
+    }
+
+    /// A source of bytes. This is a subset of `std::io::Read`.
+    pub trait Read {
+        /// Reads some bytes into `buf`, returning the number of bytes that were read.
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+    }
+
+    /// Reads from the beginning of the slice, which is then advanced past the bytes read.
+    impl Read for &[u8] {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let length = buf.len().min(self.len());
+            let (head, tail) = self.split_at(length);
+            buf[..length].copy_from_slice(head);
+            *self = tail;
+            Ok(length)
+        }
+    }
+
+    impl<R: Read + ?Sized> Read for &mut R {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            (**self).read(buf)
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_io_tests {
+    use super::*;
+    use arrayvec::ArrayVec;
+
+    #[test]
+    fn write_all_to_slice() -> Result<()> {
+        let mut buffer = [0u8; 4];
+        let mut sink = &mut buffer[..];
+        sink.write_all(&[1, 2, 3])?;
+        assert_eq!(sink.len(), 1);
+        let error = sink.write_all(&[4, 5]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::WriteZero);
+        assert_eq!(buffer, [1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_all_to_array_vec() -> Result<()> {
+        let mut sink: ArrayVec<u8, 3> = ArrayVec::new();
+        sink.write_all(&[1, 2])?;
+        let error = sink.write_all(&[3, 4]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::WriteZero);
+        assert_eq!(sink.as_slice(), &[1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn read_from_slice() -> Result<()> {
+        let mut source = &[1u8, 2, 3][..];
+        let mut buffer = [0u8; 2];
+        assert_eq!(source.read(&mut buffer)?, 2);
+        assert_eq!(buffer, [1, 2]);
+        assert_eq!((&mut source).read(&mut buffer)?, 1);
+        assert_eq!(buffer[0], 3);
+        assert_eq!(source.read(&mut buffer)?, 0);
+        Ok(())
+    }
+}
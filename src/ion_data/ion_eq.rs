@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use core::ops::Deref;
 
 /// Determines whether two values are equal according to Ion's definition of equivalence.
 ///
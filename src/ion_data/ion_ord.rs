@@ -1,5 +1,5 @@
-use std::cmp::Ordering;
-use std::ops::Deref;
+use core::cmp::Ordering;
+use core::ops::Deref;
 
 /// Trait used for delegating [Ord] and [PartialOrd] in [IonData](crate::IonData).
 /// Implementations of [IonOrd] must be consistent with [IonEq](crate::ion_data::IonEq).
@@ -58,7 +58,7 @@ pub(crate) fn ion_cmp_bool(this: &bool, that: &bool) -> Ordering {
     this.cmp(that)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod ord_tests {
     use super::*;
     use crate::element::{List, Sequence};
@@ -1,9 +1,9 @@
 mod ion_eq;
 mod ion_ord;
 
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
-use std::ops::Deref;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::ops::Deref;
 
 pub(crate) use ion_eq::{ion_eq_bool, ion_eq_f64, IonEq};
 pub(crate) use ion_ord::{ion_cmp_bool, ion_cmp_f64, IonOrd};
@@ -71,7 +71,7 @@ impl<T: IonEq> AsRef<T> for IonData<T> {
 }
 
 impl<T: Display> Display for IonData<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.0, f)
     }
 }
@@ -88,7 +88,7 @@ impl<T: IonEq + IonOrd> Ord for IonData<T> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::ion_data::{IonEq, IonOrd};
     use crate::{Element, IonData, Symbol};
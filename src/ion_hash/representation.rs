@@ -62,6 +62,10 @@ where
                     self.update_escaping(encoded.as_bytes());
                 }
             },
+            Some(Int::I128(v)) => {
+                let encoded = binary::uint::encode_u128(v.get().unsigned_abs());
+                self.update_escaping(encoded.as_bytes());
+            }
             Some(Int::BigInt(b)) => self.update_escaping(&b.magnitude().to_bytes_be()[..]),
             None => {}
         }
@@ -129,10 +133,16 @@ where
     }
 
     fn write_repr_symbol(&mut self, value: Option<&Symbol>) -> IonResult<()> {
-        // There are no representation bytes for null symbols or unknown-text symbols.
+        // There are no representation bytes for null symbols or `$0`. A symbol with unknown text
+        // that has an import location is represented by the name of its shared symbol table
+        // followed by its symbol ID in that table, encoded as a UInt.
         if let Some(symbol) = value {
             if let Some(text) = symbol.text() {
                 self.write_repr_string(Some(text))?;
+            } else if let Some(location) = symbol.import_location() {
+                self.write_repr_string(Some(location.table_name()))?;
+                let sid = binary::uint::encode_u64(location.sid() as u64);
+                self.update_escaping(sid.as_bytes());
             }
         }
         Ok(())
@@ -8,7 +8,6 @@ use std::slice;
 use crate::binary::IonTypeCode;
 use crate::element::{Element, Sequence, Struct};
 use crate::{Decimal, Int, IonType, Symbol, Timestamp};
-use num_bigint::Sign;
 
 // For many types, the qualifier is either 'null' or 'not null'. That's what
 // these constants are for!
@@ -62,10 +61,7 @@ impl TypeQualifier {
 fn is_integer_positive(value: Option<&Int>) -> bool {
     match value {
         None => true,
-        Some(any) => match any {
-            Int::I64(i) => *i >= 0,
-            Int::BigInt(b) => !std::matches!(b.sign(), Sign::Minus),
-        },
+        Some(any) => !any.is_negative(),
     }
 }
 
@@ -1,5 +1,10 @@
+// Tests always run with `std`, which provides the test harness, even when the `std` feature is
+// disabled.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(dead_code)]
-#![deny(rustdoc::broken_intra_doc_links)]
+// Many doc comments link to items that only exist with the `std` feature, so links are only
+// checked in the default configuration.
+#![cfg_attr(feature = "std", deny(rustdoc::broken_intra_doc_links))]
 #![deny(rustdoc::private_intra_doc_links)]
 #![deny(rustdoc::bare_urls)]
 //! # Reading and writing `Element`s
@@ -161,17 +166,48 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Threads
+//!
+//! [`Element`] and its component types (such as [`Symbol`], [`Sequence`](element::Sequence)
+//! and [`Struct`](element::Struct)) are both `Send` and `Sync`, so values can be shared between
+//! threads by reference or by wrapping them in an [`Arc`](std::sync::Arc).
+//!
+//! The readers and writers are `Send` whenever their input or output is `Send`, and `Sync`
+//! whenever it is `Sync`. This applies to [`TextReader`], [`BinaryReader`], [`AnyReader`],
+//! [`SliceReader`], [`Writer`], [`TextWriter`], [`BinaryWriter`] and the raw readers and
+//! writers. The one exception is [`Reader`], which boxes its raw reader without requiring it to
+//! be `Send`; use [`ReaderBuilder::build_send`] to construct a [`SendReader`] instead.
+//!
+//! [`SymbolInterner`](types::SymbolInterner) and [`IonError`] are also `Send` and `Sync`.
+//!
+//! # `no_std` support
+//!
+//! The `std` feature is enabled by default. Disabling it builds the crate with only `alloc`,
+//! which provides:
+//! * the [`Element`] model and the rest of the [`types`] module,
+//! * [`RawBinaryReader`](binary::non_blocking::raw_binary_reader::RawBinaryReader) and
+//!   [`ReaderBuilder::build_slice`] for reading binary Ion from an in-memory slice, and
+//! * [`RawBinaryWriter`] and [`BinaryWriter`] for writing binary Ion to any [`io::Write`]
+//!   implementation, such as a `Vec<u8>` or a fixed-capacity `arrayvec::ArrayVec<u8, N>`.
+//!
+//! The text format, the blocking readers, [`std::io`] sources and sinks, and the optional
+//! conversions to and from other crates' types all require `std`.
 
 // This import is used in the doc comments and test code above. Clippy incorrectly
 // declares it an unused import.
 #[allow(unused_imports)]
 use element::Element;
 
+extern crate alloc;
+
 pub mod result;
 
 pub mod binary;
+#[cfg(feature = "std")]
 pub mod data_source;
 pub mod element;
+pub mod io;
 pub mod raw_reader;
 pub mod text;
 pub mod types;
@@ -180,6 +216,7 @@ mod ion_data;
 #[cfg(feature = "ion-hash")]
 pub mod ion_hash;
 
+#[cfg(feature = "std")]
 mod blocking_reader;
 mod catalog;
 // Public as a workaround for: https://github.com/amazon-ion/ion-rust/issues/484
@@ -189,6 +226,7 @@ mod raw_symbol_token_ref;
 // Public as a workaround for: https://github.com/amazon-ion/ion-rust/issues/484
 pub mod reader;
 mod shared_symbol_table;
+mod slice_reader;
 mod stream_reader;
 mod symbol_ref;
 mod symbol_table;
@@ -198,6 +236,7 @@ mod writer;
 #[cfg(feature = "experimental-streaming")]
 pub(crate) mod thunk;
 
+#[cfg(feature = "std")]
 #[doc(inline)]
 pub use data_source::IonDataSource;
 #[doc(inline)]
@@ -205,23 +244,35 @@ pub use raw_symbol_token::RawSymbolToken;
 #[doc(inline)]
 pub use raw_symbol_token_ref::RawSymbolTokenRef;
 
+pub use shared_symbol_table::SharedSymbolTable;
+pub use slice_reader::{RawSliceReader, SliceReader};
 pub use symbol_ref::SymbolRef;
 pub use symbol_table::SymbolTable;
 
-pub use types::{Decimal, Int, IonType, Str, Symbol, Timestamp};
+pub use types::{Decimal, Int, IonType, Str, Symbol, SymbolHandle, Timestamp};
 
 pub use ion_data::IonData;
 
-pub use binary::binary_writer::{BinaryWriter, BinaryWriterBuilder};
+pub use binary::binary_writer::{BinaryWriter, BinaryWriterBuilder, SymbolTableReset};
+#[cfg(feature = "std")]
 pub use text::text_writer::{TextWriter, TextWriterBuilder};
 pub use writer::IonWriter;
+#[cfg(feature = "std")]
+pub use writer::{Writer, WriterBuilder};
 
 pub use binary::raw_binary_writer::RawBinaryWriter;
-pub use blocking_reader::{BlockingRawBinaryReader, BlockingRawReader, BlockingRawTextReader};
+pub use binary::spill::SpillStorage;
+#[cfg(feature = "std")]
+pub use blocking_reader::{
+    BlockingRawAnyReader, BlockingRawBinaryReader, BlockingRawReader, BlockingRawTextReader,
+};
 pub use raw_reader::{RawReader, RawStreamItem};
-pub use reader::{Reader, ReaderBuilder, StreamItem, UserReader};
+#[cfg(feature = "std")]
+pub use reader::{AnyReader, BinaryReader, Reader, SendReader, TextReader};
+pub use reader::{ReaderBuilder, StreamItem, UserReader};
 pub use stream_reader::IonReader;
 pub use system_reader::{SystemReader, SystemStreamItem};
+#[cfg(feature = "std")]
 pub use text::raw_text_writer::{RawTextWriter, RawTextWriterBuilder};
 
 pub use result::{IonError, IonResult};
@@ -230,5 +281,8 @@ pub use result::{IonError, IonResult};
 ///
 /// See also: <https://github.com/amazon-ion/ion-rust/issues/302>
 pub mod external {
+    #[cfg(feature = "std")]
     pub use bigdecimal;
+    #[cfg(feature = "chrono-tz")]
+    pub use chrono_tz;
 }
@@ -1,10 +1,13 @@
 use crate::element::{Blob, Clob};
+use crate::io::Read;
 use crate::raw_symbol_token::RawSymbolToken;
 use crate::stream_reader::IonReader;
+use crate::types::{Encoding, Span};
 use crate::types::{IonType, Str};
 use crate::{Decimal, Int, IonResult, Timestamp};
-use std::fmt::{Display, Formatter};
-use std::io::Read;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
 
 /// `RawReader` is a shorthand for a [Reader](crate::Reader) implementation that returns [RawStreamItem]s and
 /// uses [RawSymbolToken] to represent its field names, annotations, and symbol values.
@@ -74,6 +77,14 @@ impl<R: RawReader + ?Sized> IonReader for Box<R> {
         (**self).read_f64()
     }
 
+    fn encoding(&self) -> Option<Encoding> {
+        (**self).encoding()
+    }
+
+    fn span(&self) -> Option<Span> {
+        (**self).span()
+    }
+
     fn read_decimal(&mut self) -> IonResult<Decimal> {
         (**self).read_decimal()
     }
@@ -152,7 +163,7 @@ impl RawStreamItem {
 }
 
 impl Display for RawStreamItem {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         use RawStreamItem::*;
         match self {
             VersionMarker(major, minor) => write!(f, "ion version marker (v{major}.{minor})"),
@@ -1,4 +1,6 @@
 use crate::types::SymbolId;
+use alloc::string::String;
+use alloc::string::ToString;
 
 /// A symbol token encountered in a text or binary Ion stream.
 /// [RawSymbolToken]s do not store import source information for the token encountered. Similarly,
@@ -1,6 +1,7 @@
 use crate::raw_symbol_token::RawSymbolToken;
-use crate::types::SymbolId;
+use crate::types::{ImportLocation, SymbolHandle, SymbolId};
 use crate::Symbol;
+use alloc::string::String;
 
 /// Like RawSymbolToken, but the Text variant holds a borrowed reference instead of a String.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,6 +13,20 @@ pub enum RawSymbolTokenRef<'a> {
 /// Implemented by types that can be viewed as a [RawSymbolTokenRef] without allocations.
 pub trait AsRawSymbolTokenRef {
     fn as_raw_symbol_token_ref(&self) -> RawSymbolTokenRef;
+
+    /// If this token has unknown text and was imported from a shared symbol table, returns the
+    /// location of its definition. Writers that import the same table use this to encode the
+    /// token with the corresponding symbol ID.
+    fn import_location(&self) -> Option<&ImportLocation> {
+        None
+    }
+
+    /// If this token is a [SymbolHandle] that was registered with a
+    /// [BinaryWriter](crate::BinaryWriter), returns it. That writer uses the handle's symbol ID
+    /// without looking up its text.
+    fn symbol_handle(&self) -> Option<&SymbolHandle> {
+        None
+    }
 }
 
 impl<'a> AsRawSymbolTokenRef for RawSymbolTokenRef<'a> {
@@ -48,6 +63,10 @@ impl AsRawSymbolTokenRef for Symbol {
             None => RawSymbolTokenRef::SymbolId(0),
         }
     }
+
+    fn import_location(&self) -> Option<&ImportLocation> {
+        Symbol::import_location(self)
+    }
 }
 
 impl<T> AsRawSymbolTokenRef for &T
@@ -57,6 +76,24 @@ where
     fn as_raw_symbol_token_ref(&self) -> RawSymbolTokenRef {
         (*self).as_raw_symbol_token_ref()
     }
+
+    fn import_location(&self) -> Option<&ImportLocation> {
+        (*self).import_location()
+    }
+
+    fn symbol_handle(&self) -> Option<&SymbolHandle> {
+        (*self).symbol_handle()
+    }
+}
+
+impl AsRawSymbolTokenRef for SymbolHandle {
+    fn as_raw_symbol_token_ref(&self) -> RawSymbolTokenRef<'_> {
+        RawSymbolTokenRef::SymbolId(self.symbol_id())
+    }
+
+    fn symbol_handle(&self) -> Option<&SymbolHandle> {
+        Some(self)
+    }
 }
 
 impl AsRawSymbolTokenRef for RawSymbolToken {
@@ -1,39 +1,221 @@
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::ops::Range;
+#[cfg(feature = "std")]
 use std::io;
+#[cfg(feature = "std")]
 use std::io::Read;
-use std::ops::Range;
 
 use delegate::delegate;
 
+#[cfg(feature = "std")]
 use crate::binary::constants::v1_0::IVM;
+#[cfg(feature = "std")]
+use crate::blocking_reader::BlockingRawAnyReader;
 use crate::constants::v1_0::system_symbol_ids;
+#[cfg(feature = "std")]
 use crate::data_source::ToIonDataSource;
 use crate::element::{Blob, Clob};
 use crate::raw_reader::{RawReader, RawStreamItem};
 use crate::raw_symbol_token::RawSymbolToken;
 use crate::result::{decoding_error, decoding_error_raw, IonResult};
+use crate::slice_reader::{RawSliceReader, SliceReader};
 use crate::stream_reader::IonReader;
-use crate::symbol_table::SymbolTable;
-use crate::types::{Decimal, Int, Symbol, Timestamp};
-use crate::{BlockingRawBinaryReader, BlockingRawTextReader, IonType};
-use std::fmt::{Display, Formatter};
+use crate::symbol_table::{ImportDescriptor, SymbolTable};
+use crate::types::{Decimal, Encoding, Int, Span, Symbol, Timestamp};
+use crate::IonType;
+#[cfg(feature = "std")]
+use crate::{BlockingRawBinaryReader, BlockingRawTextReader};
+use core::fmt::{Display, Formatter};
 
 use crate::types::Str;
 /// Configures and constructs new instances of [Reader].
-pub struct ReaderBuilder {}
+pub struct ReaderBuilder {
+    preserve_encoding: bool,
+    track_spans: bool,
+}
 
 impl ReaderBuilder {
     /// Constructs a [ReaderBuilder] pre-populated with common default settings.
     pub fn new() -> ReaderBuilder {
         ReaderBuilder {
             // Eventually, this will contain settings like a `Catalog` implementation.
+            preserve_encoding: false,
+            track_spans: false,
         }
     }
 
+    /// If `preserve_encoding` is `true`, the [Reader] will report the [`Encoding`] of each value
+    /// whose encoding cannot be derived from the value itself. Currently, this is only the width
+    /// of a binary float. [`Element`](crate::element::Element)s read from the `Reader` record
+    /// their `Encoding` so that writing them back out uses the same float width. See [`Encoding`]
+    /// for the encoding details that are not preserved.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::element::reader::ElementReader;
+    /// use ion_rs::types::{Encoding, FloatWidth};
+    /// use ion_rs::ReaderBuilder;
+    /// // A binary stream containing the 64-bit float 0.5
+    /// let ion_data = [0xE0, 0x01, 0x00, 0xEA, 0x48, 0x3F, 0xE0, 0, 0, 0, 0, 0, 0];
+    /// let mut reader = ReaderBuilder::new().preserve_encoding(true).build(&ion_data[..])?;
+    /// let element = reader.read_one_element()?;
+    /// assert_eq!(element.encoding(), Some(Encoding::Float(FloatWidth::F64)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn preserve_encoding(mut self, preserve_encoding: bool) -> ReaderBuilder {
+        self.preserve_encoding = preserve_encoding;
+        self
+    }
+
+    /// If `track_spans` is `true`, the [Reader] will report the [`Span`](crate::types::Span)
+    /// that each value occupies in the input. [`Element`](crate::element::Element)s read from the
+    /// `Reader` record their span, which can be used to point users at the source of a value.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::element::reader::ElementReader;
+    /// use ion_rs::ReaderBuilder;
+    /// let ion_data = "foo\n  {bar: [1, 2]}";
+    /// let mut reader = ReaderBuilder::new().track_spans(true).build(ion_data)?;
+    /// let elements = reader.read_all_elements()?;
+    /// let span = elements[1].span().unwrap();
+    /// assert_eq!(span.range(), 6..19);
+    /// assert_eq!((span.line(), span.column()), (Some(2), Some(3)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn track_spans(mut self, track_spans: bool) -> ReaderBuilder {
+        self.track_spans = track_spans;
+        self
+    }
+
     /// Applies the specified settings to a new instance of `Reader`. This process involves
     /// reading some data from the beginning of `input` to detect whether its content is
     /// text or binary Ion. If this read operation fails, `build` will return an `Err`
     /// describing the problem it encountered.
+    #[cfg(feature = "std")]
     pub fn build<'a, I: 'a + ToIonDataSource>(self, input: I) -> IonResult<Reader<'a>> {
+        let (is_binary, input) = Self::detect_format(input)?;
+        let raw_reader: Box<dyn RawReader + 'a> = if is_binary {
+            Box::new(self.make_binary_raw_reader(input)?)
+        } else {
+            Box::new(self.make_text_raw_reader(input)?)
+        };
+        Ok(self.make_reader(raw_reader))
+    }
+
+    /// Like [`build`](Self::build), but constructs a [`SendReader`], which can be moved to
+    /// another thread. This requires the input's data source to be `Send`.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::element::reader::ElementReader;
+    /// use ion_rs::ReaderBuilder;
+    /// let mut reader = ReaderBuilder::new().build_send(b"1 2 3".to_vec())?;
+    /// let count = std::thread::spawn(move || reader.elements().count())
+    ///     .join()
+    ///     .unwrap();
+    /// assert_eq!(count, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn build_send<'a, I>(self, input: I) -> IonResult<SendReader<'a>>
+    where
+        I: 'a + ToIonDataSource,
+        I::DataSource: Send,
+    {
+        let (is_binary, input) = Self::detect_format(input)?;
+        let raw_reader: Box<dyn RawReader + Send + 'a> = if is_binary {
+            Box::new(self.make_binary_raw_reader(input)?)
+        } else {
+            Box::new(self.make_text_raw_reader(input)?)
+        };
+        Ok(self.make_reader(raw_reader))
+    }
+
+    /// Like [`build`](Self::build), but constructs an [`AnyReader`], which dispatches calls to
+    /// its text or binary raw reader statically rather than through a `Box<dyn RawReader>`.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::{IonReader, IonType, ReaderBuilder, StreamItem};
+    /// let mut reader = ReaderBuilder::new().build_any("1 2 3")?;
+    /// let mut sum = 0;
+    /// while let StreamItem::Value(IonType::Int) = reader.next()? {
+    ///     sum += reader.read_i64()?;
+    /// }
+    /// assert_eq!(sum, 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn build_any<I: ToIonDataSource>(self, input: I) -> IonResult<AnyReader<I>> {
+        let (is_binary, input) = Self::detect_format(input)?;
+        let raw_reader = if is_binary {
+            BlockingRawAnyReader::Binary(self.make_binary_raw_reader(input)?)
+        } else {
+            BlockingRawAnyReader::Text(self.make_text_raw_reader(input)?)
+        };
+        Ok(self.make_reader(raw_reader))
+    }
+
+    /// Applies the specified settings to a new [`SliceReader`], which reads `data` in place
+    /// instead of copying it into an internal buffer. Binary strings, blobs and clobs can be
+    /// borrowed directly from `data`; see [`SliceReader::read_str_cow`].
+    ///
+    /// This is the only `build` method that is available without the `std` feature. In that
+    /// configuration, it returns an `Err` if `data` is not binary Ion.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::{IonReader, ReaderBuilder};
+    /// use std::borrow::Cow;
+    /// // A binary stream containing the string "hello"
+    /// let ion_data = [0xE0, 0x01, 0x00, 0xEA, 0x85, b'h', b'e', b'l', b'l', b'o'];
+    /// let mut reader = ReaderBuilder::new().build_slice(&ion_data)?;
+    /// reader.next()?;
+    /// assert_eq!(reader.read_str_cow()?, Cow::Borrowed("hello"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_slice<'a>(self, data: &'a [u8]) -> IonResult<SliceReader<'a>> {
+        let mut raw_reader = RawSliceReader::new(data)?;
+        raw_reader.set_track_spans(self.track_spans);
+        Ok(self.make_reader(raw_reader))
+    }
+
+    /// Applies the specified settings to a new [`TextReader`]. Unlike [`build`](Self::build),
+    /// this does not inspect `input` to detect its format; it is always read as text Ion.
+    #[cfg(feature = "std")]
+    pub fn build_text<I: ToIonDataSource>(self, input: I) -> IonResult<TextReader<I>> {
+        let raw_reader = self.make_text_raw_reader(input)?;
+        Ok(self.make_reader(raw_reader))
+    }
+
+    /// Applies the specified settings to a new [`BinaryReader`]. Unlike [`build`](Self::build),
+    /// this does not inspect `input` to detect its format; it is always read as binary Ion.
+    #[cfg(feature = "std")]
+    pub fn build_binary<I: ToIonDataSource>(self, input: I) -> IonResult<BinaryReader<I>> {
+        let raw_reader = self.make_binary_raw_reader(input)?;
+        Ok(self.make_reader(raw_reader))
+    }
+
+    /// Reads up to four bytes from the beginning of `input` to determine whether it is binary
+    /// Ion. Returns `true` if it is, along with a data source that yields all of `input`.
+    #[cfg(feature = "std")]
+    fn detect_format<I: ToIonDataSource>(input: I) -> IonResult<(bool, DetectedInput<I>)> {
         // Convert the provided input into an implementation of `BufRead`
         let mut input = input.to_ion_data_source();
         // Stack-allocated buffer to hold the first four bytes from input
@@ -50,54 +232,60 @@ impl ReaderBuilder {
             // all four bytes. That means this isn't a (valid) binary stream. We'll assume
             // it's text.
             if bytes_read == 0 {
-                // `header` is a stack-allocated buffer that won't outlive this function call.
-                // If it were full, we could move the whole `[u8; 4]` into the reader. However,
-                // only some of it is populated and we can't use a slice of it because the array
-                // is short-lived. Instead we'll make a statically owned copy of the bytes that
-                // we can move into the reader.
-                let owned_header = Vec::from(&header[..total_bytes_read]);
-                // The file was too short to be binary Ion. Construct a text Reader.
-                return Self::make_text_reader(owned_header);
+                break;
             }
             total_bytes_read += bytes_read;
         }
+        // Put the bytes that we read back in front of the rest of the input. If the input was
+        // too short to be binary Ion, only the bytes that were actually read are replayed.
+        let full_input = io::Cursor::new(header)
+            .take(total_bytes_read as u64)
+            .chain(input);
+        if total_bytes_read < IVM.len() {
+            return Ok((false, full_input));
+        }
 
         // If we've reached this point, we successfully read 4 bytes from the file into `header`.
         // Match against `header` to see if it contains the Ion 1.0 version marker.
         match header {
-            [0xe0, 0x01, 0x00, 0xea] => {
-                // Binary Ion v1.0
-                let full_input = io::Cursor::new(header).chain(input);
-                Ok(Self::make_binary_reader(full_input)?)
-            }
+            // Binary Ion v1.0
+            [0xe0, 0x01, 0x00, 0xea] => Ok((true, full_input)),
             [0xe0, major, minor, 0xea] => {
                 // Binary Ion v{major}.{minor}
                 decoding_error(format!(
                     "cannot read Ion v{major}.{minor}; only v1.0 is supported"
                 ))
             }
-            _ => {
-                // It's not binary, assume it's text
-                let full_input = io::Cursor::new(header).chain(input);
-                Ok(Self::make_text_reader(full_input)?)
-            }
+            // It's not binary, assume it's text
+            _ => Ok((false, full_input)),
         }
     }
 
-    fn make_text_reader<'a, I: 'a + ToIonDataSource>(data: I) -> IonResult<Reader<'a>> {
-        let raw_reader = Box::new(BlockingRawTextReader::new(data)?);
-        Ok(Reader {
-            raw_reader,
-            symbol_table: SymbolTable::new(),
-        })
+    #[cfg(feature = "std")]
+    fn make_text_raw_reader<I: ToIonDataSource>(
+        &self,
+        data: I,
+    ) -> IonResult<BlockingRawTextReader<I>> {
+        let mut raw_reader = BlockingRawTextReader::new(data)?;
+        raw_reader.set_track_spans(self.track_spans);
+        Ok(raw_reader)
     }
 
-    fn make_binary_reader<'a, I: 'a + ToIonDataSource>(data: I) -> IonResult<Reader<'a>> {
-        let raw_reader = Box::new(BlockingRawBinaryReader::new(data)?);
-        Ok(Reader {
+    #[cfg(feature = "std")]
+    fn make_binary_raw_reader<I: ToIonDataSource>(
+        &self,
+        data: I,
+    ) -> IonResult<BlockingRawBinaryReader<I>> {
+        BlockingRawBinaryReader::new(data)
+    }
+
+    fn make_reader<R: RawReader>(&self, raw_reader: R) -> UserReader<R> {
+        UserReader {
             raw_reader,
             symbol_table: SymbolTable::new(),
-        })
+            preserve_encoding: self.preserve_encoding,
+            track_spans: self.track_spans,
+        }
     }
 }
 
@@ -109,8 +297,35 @@ impl Default for ReaderBuilder {
 
 /// A Reader that uses dynamic dispatch to abstract over the format (text or binary) being
 /// read by an underlying [RawReader].
+///
+/// A `Reader` is never `Send`, because it accepts inputs (such as [`std::io::StdinLock`]) that
+/// cannot be moved to another thread. See [`SendReader`].
+#[cfg(feature = "std")]
 pub type Reader<'a> = UserReader<Box<dyn RawReader + 'a>>;
 
+/// A [`Reader`] that can be moved to another thread. See [`ReaderBuilder::build_send`].
+#[cfg(feature = "std")]
+pub type SendReader<'a> = UserReader<Box<dyn RawReader + Send + 'a>>;
+
+/// A Reader for text Ion. See [`ReaderBuilder::build_text`].
+#[cfg(feature = "std")]
+pub type TextReader<I> = UserReader<BlockingRawTextReader<I>>;
+
+/// A Reader for binary Ion. See [`ReaderBuilder::build_binary`].
+#[cfg(feature = "std")]
+pub type BinaryReader<I> = UserReader<BlockingRawBinaryReader<I>>;
+
+/// A Reader that abstracts over the format (text or binary) being read without using dynamic
+/// dispatch. See [`ReaderBuilder::build_any`].
+#[cfg(feature = "std")]
+pub type AnyReader<I> = UserReader<BlockingRawAnyReader<DetectedInput<I>>>;
+
+/// The input of a reader whose format was detected by a [`ReaderBuilder`]: the bytes that were
+/// read from the beginning of the input to detect its format, followed by the rest of the input.
+#[cfg(feature = "std")]
+pub type DetectedInput<I> =
+    io::Chain<io::Take<io::Cursor<[u8; 4]>>, <I as ToIonDataSource>::DataSource>;
+
 /// A streaming Ion reader that resolves symbol IDs into their corresponding text.
 ///
 /// Reader itself is format-agnostic; all format-specific logic is handled by the
@@ -118,6 +333,8 @@ pub type Reader<'a> = UserReader<Box<dyn RawReader + 'a>>;
 pub struct UserReader<R: RawReader> {
     raw_reader: R,
     symbol_table: SymbolTable,
+    preserve_encoding: bool,
+    track_spans: bool,
 }
 
 impl<R: RawReader> UserReader<R> {
@@ -125,8 +342,18 @@ impl<R: RawReader> UserReader<R> {
         UserReader {
             raw_reader,
             symbol_table: SymbolTable::new(),
+            preserve_encoding: false,
+            track_spans: false,
         }
     }
+
+    pub(crate) fn raw_reader(&self) -> &R {
+        &self.raw_reader
+    }
+
+    pub(crate) fn raw_reader_mut(&mut self) -> &mut R {
+        &mut self.raw_reader
+    }
 }
 
 // This module exists to allow our integration tests to directly construct a `UserReader`
@@ -134,6 +361,7 @@ impl<R: RawReader> UserReader<R> {
 // `ReaderBuilder` will also work for the integration tests and we can remove this.
 // See: https://github.com/amazon-ion/ion-rust/issues/484
 #[doc(hidden)]
+#[cfg(feature = "std")]
 pub mod integration_testing {
     use crate::{RawReader, Reader, UserReader};
 
@@ -170,7 +398,7 @@ impl StreamItem {
 }
 
 impl Display for StreamItem {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         use StreamItem::*;
         match self {
             Value(ion_type) => write!(f, "{ion_type}"),
@@ -193,6 +421,7 @@ impl<R: RawReader> UserReader<R> {
         self.raw_reader.step_in()?;
 
         let mut is_append = false;
+        let mut imports = vec![];
         let mut new_symbols = vec![];
 
         // It's illegal for a symbol table to have multiple `symbols` or `imports` fields.
@@ -222,9 +451,11 @@ impl<R: RawReader> UserReader<R> {
                 (symbol, IonType::List)
                     if symbol.matches(system_symbol_ids::IMPORTS, "imports") =>
                 {
-                    // TODO: SST imports. This implementation only supports local symbol
-                    //       table imports and appends.
-                    return decoding_error("importing shared symbol tables is not yet supported");
+                    if has_found_imports_field {
+                        return decoding_error("symbol table had multiple 'imports' fields");
+                    }
+                    has_found_imports_field = true;
+                    imports = self.read_imports()?;
                 }
                 // The field name is either SID 6 or the text 'imports' and the
                 // field value is a non-null symbol
@@ -285,6 +516,7 @@ impl<R: RawReader> UserReader<R> {
             // The symbol table has been set by defining new symbols without importing the current
             // symbol table.
             self.symbol_table.reset();
+            self.symbol_table.add_unavailable_imports(imports)?;
             for maybe_text in new_symbols.drain(..) {
                 let _sid = self.symbol_table.intern_or_add_placeholder(maybe_text);
             }
@@ -294,6 +526,50 @@ impl<R: RawReader> UserReader<R> {
         Ok(())
     }
 
+    /// Reads the import descriptors in the `imports` list of a local symbol table. Descriptors
+    /// without a valid name are ignored, as are imports of the system symbol table.
+    fn read_imports(&mut self) -> IonResult<Vec<ImportDescriptor>> {
+        let mut imports = vec![];
+        self.raw_reader.step_in()?;
+        while let RawStreamItem::Value(ion_type) | RawStreamItem::Null(ion_type) =
+            self.raw_reader.next()?
+        {
+            if ion_type != IonType::Struct || self.raw_reader.is_null() {
+                continue;
+            }
+            if let Some(import) = self.read_import_descriptor()? {
+                imports.push(import);
+            }
+        }
+        self.raw_reader.step_out()?;
+        Ok(imports)
+    }
+
+    fn read_import_descriptor(&mut self) -> IonResult<Option<ImportDescriptor>> {
+        let mut name = None;
+        let mut version = None;
+        let mut max_id = None;
+        self.raw_reader.step_in()?;
+        while let RawStreamItem::Value(ion_type) = self.raw_reader.next()? {
+            let field_name = self.raw_reader.field_name()?;
+            match ion_type {
+                IonType::String if field_name.matches(system_symbol_ids::NAME, "name") => {
+                    name = Some(self.raw_reader.read_str()?.to_owned());
+                }
+                IonType::Int if field_name.matches(system_symbol_ids::VERSION, "version") => {
+                    version = Some(self.raw_reader.read_int()?);
+                }
+                IonType::Int if field_name.matches(system_symbol_ids::MAX_ID, "max_id") => {
+                    max_id = Some(self.raw_reader.read_int()?);
+                }
+                // Other fields and values of other types are ignored.
+                _ => {}
+            }
+        }
+        self.raw_reader.step_out()?;
+        ImportDescriptor::new(name, version, max_id)
+    }
+
     fn raw_annotations(&mut self) -> impl Iterator<Item = RawSymbolToken> + '_ {
         // RawReader implementations do not attempt to resolve each annotation into text.
         // Additionally, they perform all I/O related to annotations in their implementations
@@ -415,6 +691,22 @@ impl<R: RawReader> IonReader for UserReader<R> {
         }
     }
 
+    fn encoding(&self) -> Option<Encoding> {
+        if self.preserve_encoding {
+            self.raw_reader.encoding()
+        } else {
+            None
+        }
+    }
+
+    fn span(&self) -> Option<Span> {
+        if self.track_spans {
+            self.raw_reader.span()
+        } else {
+            None
+        }
+    }
+
     // The Reader needs to expose many of the same functions as the Cursor, but only some of those
     // need to be re-defined to allow for system value processing. Any method listed here will be
     // delegated to self.raw_reader directly.
@@ -445,6 +737,7 @@ impl<R: RawReader> IonReader for UserReader<R> {
 
 /// Functionality that is only available if the data source we're reading from is in-memory, like
 /// a `Vec<u8>` or `&[u8]`.
+#[cfg(feature = "std")]
 impl<T: AsRef<[u8]>> UserReader<BlockingRawBinaryReader<io::Cursor<T>>> {
     delegate! {
         to self.raw_reader {
@@ -473,7 +766,7 @@ impl<T: AsRef<[u8]>> UserReader<BlockingRawBinaryReader<io::Cursor<T>>> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use std::io;
 
@@ -481,12 +774,43 @@ mod tests {
     use crate::binary::constants::v1_0::IVM;
     use crate::BlockingRawBinaryReader;
 
+    use crate::element::reader::ElementReader;
+    use crate::element::writer::ElementWriter;
+    use crate::element::Element;
     use crate::result::IonResult;
-    use crate::types::IonType;
+    use crate::types::{ImportLocation, IonType};
     use crate::StreamItem::Value;
+    use crate::{BinaryWriterBuilder, IonError, IonWriter};
+    use rstest::*;
 
     type TestDataSource = io::Cursor<Vec<u8>>;
 
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn readers_are_send_and_sync_when_their_input_is() {
+        assert_send::<AnyReader<Vec<u8>>>();
+        assert_sync::<AnyReader<Vec<u8>>>();
+        assert_send::<TextReader<TestDataSource>>();
+        assert_sync::<TextReader<TestDataSource>>();
+        assert_send::<BinaryReader<TestDataSource>>();
+        assert_sync::<BinaryReader<TestDataSource>>();
+        assert_send::<SliceReader<'static>>();
+        assert_sync::<SliceReader<'static>>();
+        assert_send::<SendReader<'static>>();
+    }
+
+    #[test]
+    fn send_reader_can_be_moved_to_another_thread() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build_send(b"foo::1 [2, 3]".to_vec())?;
+        let elements = std::thread::spawn(move || reader.read_all_elements())
+            .join()
+            .unwrap()?;
+        assert_eq!(elements, Element::read_all("foo::1 [2, 3]")?);
+        Ok(())
+    }
+
     // Create a growable byte vector that starts with the Ion 1.0 version marker
     fn ion_data(bytes: &[u8]) -> Vec<u8> {
         let mut data = Vec::new();
@@ -558,4 +882,100 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn symbols_from_unavailable_imports_have_import_locations() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_symbol_table::{
+                symbols: ["local"],
+                imports: [
+                    {name: "shared", version: 2, max_id: 2},
+                    {name: "$ion", version: 1, max_id: 9},
+                    {version: 1, max_id: 10},
+                    {name: "other", max_id: 1},
+                ],
+            }
+            $10 $11 $12 $13
+        "#;
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        let location = |name, sid| Symbol::with_import_location(ImportLocation::new(name, sid));
+
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, location("shared", 1));
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, location("shared", 2));
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, location("other", 1));
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, "local");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::huge_max_id(r#"$ion_symbol_table::{imports: [{name: "a", max_id: 10000000000}]} foo"#)]
+    #[case::many_imports(
+        r#"$ion_symbol_table::{imports: [
+            {name: "a", max_id: 1000000}, {name: "b", max_id: 1000000}
+        ]} foo"#
+    )]
+    fn too_many_unavailable_imported_symbols_is_an_error(#[case] ion_data: &str) -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert!(matches!(reader.next(), Err(IonError::DecodingError { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn unavailable_import_without_max_id_is_an_error() -> IonResult<()> {
+        let ion_data = r#"$ion_symbol_table::{imports: [{name: "shared"}]} foo"#;
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    const TEXT_DATA: &[u8] = b"{foo: [1, bar::\"baz\"]} 2e0";
+
+    fn binary_data() -> IonResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        writer.write_elements(&Element::read_all(TEXT_DATA)?)?;
+        writer.flush()?;
+        drop(writer);
+        Ok(buffer)
+    }
+
+    #[rstest]
+    #[case::text(TEXT_DATA.to_vec())]
+    #[case::binary(binary_data().unwrap())]
+    #[case::short_text(b"1".to_vec())]
+    #[case::empty(Vec::new())]
+    fn build_any_matches_build(#[case] data: Vec<u8>) -> IonResult<()> {
+        let expected = ReaderBuilder::new().build(&data[..])?.read_all_elements()?;
+        let actual = ReaderBuilder::new().build_any(data)?.read_all_elements()?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn build_text_and_build_binary() -> IonResult<()> {
+        let text = ReaderBuilder::new()
+            .build_text(TEXT_DATA)?
+            .read_all_elements()?;
+        let binary = ReaderBuilder::new()
+            .build_binary(binary_data()?)?
+            .read_all_elements()?;
+        assert_eq!(text, binary);
+        assert_eq!(text[1], Element::float(2.0));
+        Ok(())
+    }
+
+    #[test]
+    fn build_any_applies_settings() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().track_spans(true).build_any("  foo")?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.span().unwrap().range(), 2..5);
+        assert!(ReaderBuilder::new()
+            .build_any([0xE0, 0x02, 0x00, 0xEA].as_slice())
+            .is_err());
+        Ok(())
+    }
 }
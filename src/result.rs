@@ -1,8 +1,9 @@
-use std::convert::From;
-use std::fmt::{Debug, Display, Error};
-use std::{fmt, io};
+use alloc::string::{String, ToString};
+use core::convert::From;
+use core::fmt;
+use core::fmt::{Debug, Display, Error};
 
-use thiserror::Error;
+use crate::io;
 
 /// Position represents the location within an Ion stream where an error has been
 /// identified. For all formats `byte_offset` will contain the number of bytes into the stream
@@ -60,7 +61,7 @@ impl Position {
 
 impl Display for Position {
     // Formats the position based on whether we have a LineAndColumn or not.
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), Error> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), Error> {
         match &self.line_column {
             None => write!(f, "{}", self.byte_offset),
             Some((line, column)) => {
@@ -74,40 +75,66 @@ impl Display for Position {
 pub type IonResult<T> = Result<T, IonError>;
 
 /// Represents the different types of high-level failures that might occur when reading Ion data.
-#[derive(Debug, Error)]
+#[derive(Debug)]
 pub enum IonError {
     /// Indicates that an IO error was encountered while reading or writing.
-    #[error("{source:?}")]
-    IoError {
-        #[from]
-        source: io::Error,
-    },
+    IoError { source: io::Error },
 
     /// Indicates that the input buffer did not contain enough data to perform the requested read
     /// operation. If the input source contains more data, the reader can append it to the buffer
     /// and try again.
-    #[error("ran out of input while reading {label} at offset {position}")]
     Incomplete {
         label: &'static str,
         position: Position,
     },
 
     /// Indicates that the writer encountered a problem while serializing a given piece of data.
-    #[error("{description}")]
     EncodingError { description: String },
 
     /// Indicates that the data stream being read contained illegal or otherwise unreadable data.
-    #[error("{description}")]
     DecodingError { description: String },
 
     /// Returned when the user has performed an illegal operation (for example: calling stepOut()
     /// on the cursor at the top level.)
-    #[error(
-        "The user has performed an operation that is not legal in the current state: {operation}"
-    )]
     IllegalOperation { operation: String },
 }
 
+impl Display for IonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use IonError::*;
+        match self {
+            IoError { source } => write!(f, "{source:?}"),
+            Incomplete { label, position } => write!(
+                f,
+                "ran out of input while reading {label} at offset {position}"
+            ),
+            EncodingError { description } | DecodingError { description } => {
+                write!(f, "{description}")
+            }
+            IllegalOperation { operation } => write!(
+                f,
+                "The user has performed an operation that is not legal in the current state: {operation}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IonError::IoError { source } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for IonError {
+    fn from(source: io::Error) -> Self {
+        IonError::IoError { source }
+    }
+}
+
 impl From<fmt::Error> for IonError {
     fn from(error: Error) -> Self {
         IonError::EncodingError {
@@ -235,3 +262,39 @@ pub fn illegal_operation_raw<S: AsRef<str>>(operation: S) -> IonError {
         operation: operation.as_ref().to_string(),
     }
 }
+
+#[cfg(test)]
+mod result_tests {
+    use super::*;
+    use rstest::*;
+    #[cfg(feature = "std")]
+    use std::error::Error as _;
+
+    #[rstest]
+    #[case::incomplete(
+        incomplete_data_error_raw("a value", 12),
+        "ran out of input while reading a value at offset 12"
+    )]
+    #[case::encoding(encoding_error_raw("bad symbol"), "bad symbol")]
+    #[case::decoding(decoding_error_raw("bad type code"), "bad type code")]
+    #[case::illegal_operation(
+        illegal_operation_raw("step_out() at the top level"),
+        "The user has performed an operation that is not legal in the current state: step_out() at the top level"
+    )]
+    fn display(#[case] error: IonError, #[case] expected: &str) {
+        assert_eq!(error.to_string(), expected);
+        #[cfg(feature = "std")]
+        assert!(error.source().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_error_is_the_source() {
+        let error: IonError = io::Error::from(io::ErrorKind::UnexpectedEof).into();
+        let source = error.source().expect("an I/O error has a source");
+        assert_eq!(
+            source.downcast_ref::<io::Error>().map(io::Error::kind),
+            Some(io::ErrorKind::UnexpectedEof)
+        );
+    }
+}
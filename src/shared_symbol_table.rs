@@ -1,5 +1,7 @@
 use crate::result::illegal_operation;
 use crate::IonResult;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Stores [`SharedSymbolTable`] with the table name, version and imports
@@ -35,4 +37,10 @@ impl SharedSymbolTable {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Returns the text of each symbol defined by this [`SharedSymbolTable`]. The first entry has
+    /// symbol ID 1 within the table.
+    pub fn symbols(&self) -> &[Option<String>] {
+        &self.symbols
+    }
 }
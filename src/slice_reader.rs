@@ -0,0 +1,346 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+
+use crate::binary::non_blocking::raw_binary_reader::RawBinaryReader;
+use crate::element::{Blob, Clob};
+use crate::raw_reader::RawStreamItem;
+use crate::raw_symbol_token::RawSymbolToken;
+#[cfg(not(feature = "std"))]
+use crate::result::illegal_operation;
+use crate::result::{decoding_error, IonResult};
+use crate::stream_reader::IonReader;
+#[cfg(feature = "std")]
+use crate::text::non_blocking::raw_text_reader::RawTextReader;
+use crate::types::{Decimal, Encoding, Int, IonType, Span, Str, Timestamp};
+use crate::UserReader;
+
+/// A raw reader that reads text or binary Ion directly from an in-memory slice. Unlike the
+/// blocking raw readers, it does not copy its input into an internal buffer. Without the `std`
+/// feature, only binary Ion can be read.
+// Like `BlockingRawAnyReader`, this avoids boxing the text reader so that each call is
+// dispatched statically.
+#[allow(clippy::large_enum_variant)]
+pub enum RawSliceReader<'a> {
+    #[cfg(feature = "std")]
+    Text(RawTextReader<&'a [u8]>),
+    Binary(RawBinaryReader<&'a [u8]>),
+}
+
+impl<'a> RawSliceReader<'a> {
+    /// Constructs a reader for `data`, which is read as binary Ion if it begins with an Ion 1.0
+    /// version marker and as text Ion otherwise.
+    pub fn new(data: &'a [u8]) -> IonResult<RawSliceReader<'a>> {
+        match data {
+            [0xe0, 0x01, 0x00, 0xea, ..] => Ok(RawSliceReader::Binary(RawBinaryReader::new(data))),
+            [0xe0, major, minor, 0xea, ..] => decoding_error(format!(
+                "cannot read Ion v{major}.{minor}; only v1.0 is supported"
+            )),
+            #[cfg(feature = "std")]
+            _ => Ok(RawSliceReader::Text(RawTextReader::new(data))),
+            // An empty stream is valid in either format.
+            #[cfg(not(feature = "std"))]
+            [] => Ok(RawSliceReader::Binary(RawBinaryReader::new(data))),
+            #[cfg(not(feature = "std"))]
+            _ => illegal_operation("reading text Ion requires the `std` feature"),
+        }
+    }
+
+    /// Configures whether the reader reports the span of each value. This should be set before
+    /// the reader begins reading.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    pub(crate) fn set_track_spans(&mut self, track_spans: bool) {
+        match self {
+            #[cfg(feature = "std")]
+            RawSliceReader::Text(reader) => reader.set_track_spans(track_spans),
+            // Spans are only tracked by the text reader.
+            RawSliceReader::Binary(_) => {}
+        }
+    }
+
+    /// Returns `true` if the reader is reading binary Ion.
+    pub fn is_binary(&self) -> bool {
+        matches!(self, RawSliceReader::Binary(_))
+    }
+}
+
+/// Calls the same method on whichever reader `$self` holds.
+macro_rules! dispatch {
+    ($self:expr, $reader:ident => $call:expr) => {
+        match $self {
+            #[cfg(feature = "std")]
+            RawSliceReader::Text($reader) => $call,
+            RawSliceReader::Binary($reader) => $call,
+        }
+    };
+}
+
+impl<'a> IonReader for RawSliceReader<'a> {
+    type Item = RawStreamItem;
+    type Symbol = RawSymbolToken;
+
+    fn ion_version(&self) -> (u8, u8) {
+        dispatch!(self, reader => reader.ion_version())
+    }
+
+    #[inline]
+    fn next(&mut self) -> IonResult<Self::Item> {
+        dispatch!(self, reader => reader.next())
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Item {
+        dispatch!(self, reader => reader.current())
+    }
+
+    #[inline]
+    fn ion_type(&self) -> Option<IonType> {
+        dispatch!(self, reader => reader.ion_type())
+    }
+
+    fn annotations<'b>(&'b self) -> Box<dyn Iterator<Item = IonResult<Self::Symbol>> + 'b> {
+        dispatch!(self, reader => reader.annotations())
+    }
+
+    fn has_annotations(&self) -> bool {
+        dispatch!(self, reader => reader.has_annotations())
+    }
+
+    fn number_of_annotations(&self) -> usize {
+        dispatch!(self, reader => reader.number_of_annotations())
+    }
+
+    fn field_name(&self) -> IonResult<Self::Symbol> {
+        dispatch!(self, reader => reader.field_name())
+    }
+
+    #[inline]
+    fn is_null(&self) -> bool {
+        dispatch!(self, reader => reader.is_null())
+    }
+
+    fn read_null(&mut self) -> IonResult<IonType> {
+        dispatch!(self, reader => reader.read_null())
+    }
+
+    #[inline]
+    fn read_bool(&mut self) -> IonResult<bool> {
+        dispatch!(self, reader => reader.read_bool())
+    }
+
+    #[inline]
+    fn read_i64(&mut self) -> IonResult<i64> {
+        dispatch!(self, reader => reader.read_i64())
+    }
+
+    fn read_u64(&mut self) -> IonResult<u64> {
+        dispatch!(self, reader => reader.read_u64())
+    }
+
+    fn read_i128(&mut self) -> IonResult<i128> {
+        dispatch!(self, reader => reader.read_i128())
+    }
+
+    fn read_int(&mut self) -> IonResult<Int> {
+        dispatch!(self, reader => reader.read_int())
+    }
+
+    #[inline]
+    fn read_f32(&mut self) -> IonResult<f32> {
+        dispatch!(self, reader => reader.read_f32())
+    }
+
+    #[inline]
+    fn read_f64(&mut self) -> IonResult<f64> {
+        dispatch!(self, reader => reader.read_f64())
+    }
+
+    fn encoding(&self) -> Option<Encoding> {
+        dispatch!(self, reader => reader.encoding())
+    }
+
+    fn span(&self) -> Option<Span> {
+        dispatch!(self, reader => reader.span())
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Decimal> {
+        dispatch!(self, reader => reader.read_decimal())
+    }
+
+    fn read_string(&mut self) -> IonResult<Str> {
+        dispatch!(self, reader => reader.read_string())
+    }
+
+    #[inline]
+    fn read_str(&mut self) -> IonResult<&str> {
+        dispatch!(self, reader => reader.read_str())
+    }
+
+    fn read_symbol(&mut self) -> IonResult<Self::Symbol> {
+        dispatch!(self, reader => reader.read_symbol())
+    }
+
+    fn read_blob(&mut self) -> IonResult<Blob> {
+        dispatch!(self, reader => reader.read_blob())
+    }
+
+    fn read_clob(&mut self) -> IonResult<Clob> {
+        dispatch!(self, reader => reader.read_clob())
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Timestamp> {
+        dispatch!(self, reader => reader.read_timestamp())
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        dispatch!(self, reader => reader.step_in())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        dispatch!(self, reader => reader.step_out())
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        dispatch!(self, reader => reader.parent_type())
+    }
+
+    fn depth(&self) -> usize {
+        dispatch!(self, reader => reader.depth())
+    }
+}
+
+/// A Reader that reads text or binary Ion directly from an in-memory slice. See
+/// [`ReaderBuilder::build_slice`](crate::ReaderBuilder::build_slice).
+pub type SliceReader<'a> = UserReader<RawSliceReader<'a>>;
+
+impl<'a> SliceReader<'a> {
+    /// If the reader is currently positioned on a string, returns its text. When reading binary
+    /// Ion, the text is borrowed directly from the input slice. Text Ion strings may contain
+    /// escape sequences, so their text is copied.
+    pub fn read_str_cow(&mut self) -> IonResult<Cow<'a, str>> {
+        match self.raw_reader_mut() {
+            RawSliceReader::Binary(reader) => reader.read_str_ref().map(Cow::Borrowed),
+            #[cfg(feature = "std")]
+            RawSliceReader::Text(reader) => reader.read_str().map(|text| Cow::Owned(text.into())),
+        }
+    }
+
+    /// If the reader is currently positioned on a blob, returns its bytes. When reading binary
+    /// Ion, the bytes are borrowed directly from the input slice. Text Ion blobs are base64
+    /// encoded, so their bytes are decoded into a new buffer.
+    pub fn read_blob_cow(&mut self) -> IonResult<Cow<'a, [u8]>> {
+        match self.raw_reader_mut() {
+            RawSliceReader::Binary(reader) => reader.read_blob_ref().map(Cow::Borrowed),
+            #[cfg(feature = "std")]
+            RawSliceReader::Text(reader) => {
+                reader.read_blob().map(|blob| Cow::Owned(blob.0.into()))
+            }
+        }
+    }
+
+    /// If the reader is currently positioned on a clob, returns its bytes. When reading binary
+    /// Ion, the bytes are borrowed directly from the input slice. Text Ion clobs may contain
+    /// escape sequences, so their bytes are copied.
+    pub fn read_clob_cow(&mut self) -> IonResult<Cow<'a, [u8]>> {
+        match self.raw_reader_mut() {
+            RawSliceReader::Binary(reader) => reader.read_clob_ref().map(Cow::Borrowed),
+            #[cfg(feature = "std")]
+            RawSliceReader::Text(reader) => {
+                reader.read_clob().map(|clob| Cow::Owned(clob.0.into()))
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod slice_reader_tests {
+    use super::*;
+    use crate::element::reader::ElementReader;
+    use crate::element::writer::ElementWriter;
+    use crate::element::Element;
+    use crate::{BinaryWriterBuilder, IonWriter, ReaderBuilder};
+    use rstest::*;
+
+    const TEXT_DATA: &str = r#"
+        $ion_symbol_table::{symbols: ["foo"]}
+        {foo: "hello", bar: {{aGVsbG8=}}, baz: {{"hello"}}}
+    "#;
+
+    fn binary_data() -> IonResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        writer.write_elements(&Element::read_all(TEXT_DATA)?)?;
+        writer.flush()?;
+        drop(writer);
+        Ok(buffer)
+    }
+
+    #[test]
+    fn binary_values_are_borrowed_from_the_input() -> IonResult<()> {
+        let data = binary_data()?;
+        let data_range = data.as_ptr_range();
+        let mut reader = ReaderBuilder::new().build_slice(&data)?;
+        assert!(reader.raw_reader().is_binary());
+        reader.next()?;
+        reader.step_in()?;
+
+        reader.next()?;
+        let text = reader.read_str_cow()?;
+        assert!(matches!(text, Cow::Borrowed("hello")));
+        assert!(data_range.contains(&text.as_ptr()));
+        // `read_str` also returns a slice of the input.
+        assert!(data_range.contains(&reader.read_str()?.as_ptr()));
+
+        reader.next()?;
+        let blob = reader.read_blob_cow()?;
+        assert!(matches!(blob, Cow::Borrowed(b"hello")));
+        assert!(data_range.contains(&blob.as_ptr()));
+
+        reader.next()?;
+        assert!(matches!(reader.read_clob_cow()?, Cow::Borrowed(b"hello")));
+        Ok(())
+    }
+
+    #[test]
+    fn text_values_are_copied() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build_slice(TEXT_DATA.as_bytes())?;
+        assert!(!reader.raw_reader().is_binary());
+        reader.next()?;
+        reader.step_in()?;
+        reader.next()?;
+        assert_eq!(reader.read_str_cow()?, "hello");
+        reader.next()?;
+        assert_eq!(reader.read_blob_cow()?.as_ref(), b"hello");
+        reader.next()?;
+        assert_eq!(reader.read_clob_cow()?.as_ref(), b"hello");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text(TEXT_DATA.as_bytes().to_vec())]
+    #[case::binary(binary_data().unwrap())]
+    #[case::short_text(b"1".to_vec())]
+    #[case::empty(Vec::new())]
+    fn build_slice_matches_build(#[case] data: Vec<u8>) -> IonResult<()> {
+        let expected = ReaderBuilder::new().build(&data[..])?.read_all_elements()?;
+        let actual = ReaderBuilder::new()
+            .build_slice(&data)?
+            .read_all_elements()?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn incomplete_binary_value_is_an_error() -> IonResult<()> {
+        let data = binary_data()?;
+        let mut reader = ReaderBuilder::new().build_slice(&data[..data.len() - 1])?;
+        assert!(reader.read_all_elements().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_ion_version_is_an_error() {
+        let data = [0xE0, 0x02, 0x00, 0xEA];
+        assert!(ReaderBuilder::new().build_slice(&data).is_err());
+    }
+}
@@ -1,6 +1,9 @@
 use crate::element::{Blob, Clob};
+#[cfg(feature = "uuid")]
+use crate::result::decoding_error_raw;
 use crate::result::IonResult;
-use crate::types::{Decimal, Int, IonType, Str, Timestamp};
+use crate::types::{Decimal, Encoding, Int, IonType, Span, Str, Timestamp};
+use alloc::boxed::Box;
 
 /**
  * This trait captures the format-agnostic parser functionality needed to navigate within an Ion
@@ -81,6 +84,20 @@ pub trait IonReader {
     /// error is encountered while reading, returns [crate::IonError].
     fn read_i64(&mut self) -> IonResult<i64>;
 
+    /// Attempts to read the current item as an Ion integer and return it as a u64. If the current
+    /// item is not an integer, the integer is negative or too large to be represented as a `u64`,
+    /// or an IO error is encountered while reading, returns [crate::IonError].
+    fn read_u64(&mut self) -> IonResult<u64> {
+        u64::try_from(self.read_int()?)
+    }
+
+    /// Attempts to read the current item as an Ion integer and return it as an i128. If the
+    /// current item is not an integer, the integer is too large to be represented as an `i128`,
+    /// or an IO error is encountered while reading, returns [crate::IonError].
+    fn read_i128(&mut self) -> IonResult<i128> {
+        i128::try_from(self.read_int()?)
+    }
+
     /// Attempts to read the current item as an Ion integer and return it as an [`Int`](crate::types::Int). If the
     /// current item is not an integer or an IO error is encountered while reading, returns
     /// [crate::IonError].
@@ -94,6 +111,25 @@ pub trait IonReader {
     /// item is not a float or an IO error is encountered while reading, returns [crate::IonError].
     fn read_f64(&mut self) -> IonResult<f64>;
 
+    /// If the current item is a value whose source encoding cannot be derived from the value
+    /// itself, returns the details of that encoding. Raw readers report what they know about the
+    /// current value; a [Reader](crate::Reader) only reports an [`Encoding`] if it was built
+    /// with [`ReaderBuilder::preserve_encoding`](crate::ReaderBuilder::preserve_encoding).
+    fn encoding(&self) -> Option<Encoding> {
+        None
+    }
+
+    /// If the reader is positioned over a value, returns the [`Span`] that the value occupies in
+    /// the stream. A [Reader](crate::Reader) only reports spans if it was built with
+    /// [`ReaderBuilder::track_spans`](crate::ReaderBuilder::track_spans).
+    ///
+    /// Readers that only discover where a container ends while stepping out of it (like the text
+    /// reader) report the container's start and end after `step_out()` returns; before that,
+    /// the reported span covers the container's annotations and opening delimiter.
+    fn span(&self) -> Option<Span> {
+        None
+    }
+
     /// Attempts to read the current item as an Ion decimal and return it as a [crate::Decimal]. If the current
     /// item is not a decimal or an IO error is encountered while reading, returns [crate::IonError].
     fn read_decimal(&mut self) -> IonResult<Decimal>;
@@ -123,6 +159,26 @@ pub trait IonReader {
     /// item is not a timestamp or an IO error is encountered while reading, returns [crate::IonError].
     fn read_timestamp(&mut self) -> IonResult<Timestamp>;
 
+    /// Attempts to read the current item as a [`Uuid`](uuid::Uuid). The item may be a 16-byte
+    /// Ion blob or an Ion string in any format accepted by [`Uuid::parse_str`](uuid::Uuid::parse_str),
+    /// such as `"67e55044-10b1-426f-9247-bb680e5fe0c8"`. If the current item is neither or an IO
+    /// error is encountered while reading, returns [crate::IonError].
+    #[cfg(feature = "uuid")]
+    fn read_uuid(&mut self) -> IonResult<uuid::Uuid> {
+        if self.ion_type() == Some(IonType::Blob) {
+            let blob = self.read_blob()?;
+            return uuid::Uuid::from_slice(blob.as_slice()).map_err(|_| {
+                decoding_error_raw(format!(
+                    "expected a 16-byte blob containing a UUID, found {} bytes",
+                    blob.as_slice().len()
+                ))
+            });
+        }
+        let text = self.read_str()?;
+        uuid::Uuid::parse_str(text)
+            .map_err(|e| decoding_error_raw(format!("'{text}' is not a valid UUID: {e}")))
+    }
+
     /// If the current value is a container (i.e. a struct, list, or s-expression), positions the
     /// cursor at the beginning of that container's sequence of child values. The application must
     /// call [Self::next()] to advance to the first child value. If the current value is not a container,
@@ -1,12 +1,16 @@
+use crate::types::ImportLocation;
 use crate::Symbol;
-use std::borrow::Borrow;
-use std::hash::{Hash, Hasher};
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
 
 /// A reference to a fully resolved symbol. Like `Symbol` (a fully resolved symbol with a
-/// static lifetime), a `SymbolRef` may have known or undefined text.
+/// static lifetime), a `SymbolRef` may have known or undefined text. A `SymbolRef` with undefined
+/// text may have an [`ImportLocation`]; as with `Symbol`, two such references are only equal if
+/// their import locations are equal.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct SymbolRef<'a> {
     text: Option<&'a str>,
+    import_location: Option<&'a ImportLocation>,
 }
 
 impl<'a> SymbolRef<'a> {
@@ -15,14 +19,35 @@ impl<'a> SymbolRef<'a> {
         self.text
     }
 
+    /// If this symbol has unknown text and was imported from a shared symbol table, returns the
+    /// location of its definition in that table. Otherwise, returns `None`.
+    pub fn import_location(&self) -> Option<&ImportLocation> {
+        self.import_location
+    }
+
     /// Constructs a `SymbolRef` with unknown text.
     pub fn with_unknown_text() -> Self {
-        SymbolRef { text: None }
+        SymbolRef {
+            text: None,
+            import_location: None,
+        }
     }
 
     /// Constructs a `SymbolRef` with the specified text.
     pub fn with_text(text: &str) -> SymbolRef {
-        SymbolRef { text: Some(text) }
+        SymbolRef {
+            text: Some(text),
+            import_location: None,
+        }
+    }
+
+    /// Constructs a `SymbolRef` with unknown text that was defined at `location` in a shared
+    /// symbol table.
+    pub fn with_import_location(location: &ImportLocation) -> SymbolRef<'_> {
+        SymbolRef {
+            text: None,
+            import_location: Some(location),
+        }
     }
 }
 
@@ -35,24 +60,23 @@ pub trait AsSymbolRef {
 // All text types can be viewed as a `SymbolRef`.
 impl<'a, A: AsRef<str> + 'a> AsSymbolRef for A {
     fn as_symbol_ref(&self) -> SymbolRef {
-        SymbolRef {
-            text: Some(self.as_ref()),
-        }
+        SymbolRef::with_text(self.as_ref())
     }
 }
 
 impl<'a> Hash for SymbolRef<'a> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        match self.text {
-            None => 0.hash(state),
-            Some(text) => text.hash(state),
+        match (self.text, self.import_location) {
+            (Some(text), _) => text.hash(state),
+            (None, None) => 0.hash(state),
+            (None, Some(location)) => location.hash(state),
         }
     }
 }
 
 impl<'a> From<&'a str> for SymbolRef<'a> {
     fn from(text: &'a str) -> Self {
-        Self { text: Some(text) }
+        SymbolRef::with_text(text)
     }
 }
 
@@ -69,17 +93,17 @@ impl<'a> Borrow<str> for SymbolRef<'a> {
 // trait definitions, this cannot be achieved with `AsRef` or `Borrow`.
 impl AsSymbolRef for Symbol {
     fn as_symbol_ref(&self) -> SymbolRef {
-        self.text()
-            .map(SymbolRef::with_text)
-            .unwrap_or_else(SymbolRef::with_unknown_text)
+        match (self.text(), self.import_location()) {
+            (Some(text), _) => SymbolRef::with_text(text),
+            (None, Some(location)) => SymbolRef::with_import_location(location),
+            (None, None) => SymbolRef::with_unknown_text(),
+        }
     }
 }
 
 impl AsSymbolRef for &Symbol {
     fn as_symbol_ref(&self) -> SymbolRef {
-        self.text()
-            .map(SymbolRef::with_text)
-            .unwrap_or_else(SymbolRef::with_unknown_text)
+        (*self).as_symbol_ref()
     }
 }
 
@@ -125,4 +149,14 @@ mod tests {
         let symbol_ref: SymbolRef = symbol.as_symbol_ref();
         assert_eq!(None, symbol_ref.text());
     }
+
+    #[test]
+    fn symbol_with_import_location_as_symbol_ref() {
+        let location = ImportLocation::new("shared", 1);
+        let symbol = Symbol::with_import_location(location.clone());
+        let symbol_ref: SymbolRef = symbol.as_symbol_ref();
+        assert_eq!(None, symbol_ref.text());
+        assert_eq!(Some(&location), symbol_ref.import_location());
+        assert_ne!(symbol_ref, SymbolRef::with_unknown_text());
+    }
 }
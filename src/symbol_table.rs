@@ -1,15 +1,79 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::sync::Arc;
 
 use crate::constants::v1_0;
-use crate::types::{Symbol, SymbolId};
+use crate::result::{decoding_error, decoding_error_raw, IonResult};
+use crate::types::{intern_str, ImportLocation, Int, Symbol, SymbolId};
+
+#[cfg(feature = "std")]
+type IdsByText = HashMap<Symbol, SymbolId>;
+// Without `std`, there is no `HashMap`. Every symbol in the map has known text, so it can be
+// searched by `&str`.
+#[cfg(not(feature = "std"))]
+type IdsByText = alloc::collections::BTreeMap<Symbol, SymbolId>;
+
+/// The largest number of symbol IDs that a local symbol table may reserve for shared symbol tables
+/// that are not available. Each of those IDs is allocated a placeholder [`Symbol`], so a stream
+/// that declares an enormous `max_id` would otherwise exhaust memory.
+pub(crate) const MAX_UNAVAILABLE_IMPORTED_SYMBOLS: usize = 1 << 20;
+
+/// An entry in the `imports` list of a local symbol table.
+pub(crate) struct ImportDescriptor {
+    pub(crate) name: String,
+    pub(crate) max_id: usize,
+}
+
+impl ImportDescriptor {
+    /// Validates the fields read from an import struct. Returns `Ok(None)` if the import should
+    /// be ignored because it does not have a valid name or because it imports the system symbol
+    /// table.
+    ///
+    /// Readers do not have a catalog of shared symbol tables, so an import must specify a valid
+    /// `max_id`.
+    pub(crate) fn new(
+        name: Option<String>,
+        version: Option<Int>,
+        max_id: Option<Int>,
+    ) -> IonResult<Option<ImportDescriptor>> {
+        let name = match name {
+            Some(name) if !name.is_empty() && name != "$ion" => name,
+            _ => return Ok(None),
+        };
+        // Versions that are missing or less than 1 are treated as 1.
+        let version = version
+            .and_then(|version| usize::try_from(version).ok())
+            .unwrap_or(1)
+            .max(1);
+        let max_id = max_id
+            .and_then(|max_id| usize::try_from(max_id).ok())
+            .ok_or_else(|| {
+                decoding_error_raw(format!(
+                    "import of shared symbol table '{name}' (version {version}) does not have a \
+                    valid max_id, and the table is not available"
+                ))
+            })?;
+        Ok(Some(ImportDescriptor { name, max_id }))
+    }
+}
+
+/// The range of symbol IDs occupied by a shared symbol table that has been imported.
+struct ImportedTable {
+    name: Arc<str>,
+    first_sid: SymbolId,
+    max_id: usize,
+}
 
 /// Stores mappings from Symbol IDs to text and vice-versa.
 // SymbolTable instances always have at least system symbols; they are never empty.
 #[allow(clippy::len_without_is_empty)]
 pub struct SymbolTable {
     symbols_by_id: Vec<Symbol>,
-    ids_by_text: HashMap<Symbol, SymbolId>,
+    ids_by_text: IdsByText,
+    imports: Vec<ImportedTable>,
 }
 
 impl Default for SymbolTable {
@@ -23,7 +87,8 @@ impl SymbolTable {
     pub fn new() -> SymbolTable {
         let mut symbol_table = SymbolTable {
             symbols_by_id: Vec::with_capacity(v1_0::SYSTEM_SYMBOLS.len()),
-            ids_by_text: HashMap::new(),
+            ids_by_text: IdsByText::new(),
+            imports: Vec::new(),
         };
         symbol_table.initialize();
         symbol_table
@@ -39,6 +104,7 @@ impl SymbolTable {
     pub fn reset(&mut self) {
         self.symbols_by_id.clear();
         self.ids_by_text.clear();
+        self.imports.clear();
         self.initialize();
     }
 
@@ -53,8 +119,7 @@ impl SymbolTable {
 
         // Otherwise, intern it and return the new ID.
         let id = self.symbols_by_id.len();
-        let arc: Arc<str> = Arc::from(text);
-        let symbol = Symbol::shared(arc);
+        let symbol = Symbol::shared(intern_str(text));
         self.symbols_by_id.push(symbol.clone());
         self.ids_by_text.insert(symbol, id);
         id
@@ -77,6 +142,82 @@ impl SymbolTable {
         }
     }
 
+    /// Adds the first `max_id` symbols of the shared symbol table named `table_name` to the end of
+    /// the symbol table and returns the ID assigned to the first of them. `symbols` holds the text
+    /// of the shared table's symbols, if it is available. Entries beyond the end of `symbols` or
+    /// with unknown text are given an [`ImportLocation`] instead of text.
+    ///
+    /// Unlike [`Self::intern`], this always assigns `max_id` new symbol IDs. Text that was
+    /// already in the table continues to map to its original ID.
+    pub(crate) fn add_import(
+        &mut self,
+        table_name: &str,
+        max_id: usize,
+        symbols: &[Option<String>],
+    ) -> SymbolId {
+        let name: Arc<str> = Arc::from(table_name);
+        let first_sid = self.symbols_by_id.len();
+        for offset in 0..max_id {
+            let symbol = match symbols.get(offset) {
+                Some(Some(text)) => {
+                    let symbol = Symbol::owned(text.as_str());
+                    let sid = self.symbols_by_id.len();
+                    self.ids_by_text.entry(symbol.clone()).or_insert(sid);
+                    symbol
+                }
+                _ => {
+                    Symbol::with_import_location(ImportLocation::new(Arc::clone(&name), offset + 1))
+                }
+            };
+            self.symbols_by_id.push(symbol);
+        }
+        self.imports.push(ImportedTable {
+            name,
+            first_sid,
+            max_id,
+        });
+        first_sid
+    }
+
+    /// Adds the symbols of shared symbol tables that are not available to the end of the symbol
+    /// table. The text of each of these symbols is unknown, so each one is identified by its
+    /// [`ImportLocation`] instead.
+    ///
+    /// Returns a decoding error without adding anything if the imports would reserve more than
+    /// [`MAX_UNAVAILABLE_IMPORTED_SYMBOLS`] symbol IDs in total.
+    pub(crate) fn add_unavailable_imports(
+        &mut self,
+        imports: Vec<ImportDescriptor>,
+    ) -> IonResult<()> {
+        let num_imported_symbols = imports
+            .iter()
+            .fold(0usize, |total, import| total.saturating_add(import.max_id));
+        if num_imported_symbols > MAX_UNAVAILABLE_IMPORTED_SYMBOLS {
+            return decoding_error(format!(
+                "local symbol table imports more than {MAX_UNAVAILABLE_IMPORTED_SYMBOLS} symbols \
+                from shared symbol tables that are not available"
+            ));
+        }
+        for import in imports {
+            self.add_import(&import.name, import.max_id, &[]);
+        }
+        Ok(())
+    }
+
+    /// If `location` identifies a symbol in one of the shared symbol tables that have been
+    /// imported, returns the local Symbol ID of that symbol.
+    pub fn sid_for_import_location(&self, location: &ImportLocation) -> Option<SymbolId> {
+        if location.sid() == 0 {
+            return None;
+        }
+        self.imports
+            .iter()
+            .find(|import| {
+                import.name.as_ref() == location.table_name() && location.sid() <= import.max_id
+            })
+            .map(|import| import.first_sid + location.sid() - 1)
+    }
+
     /// If defined, returns the Symbol ID associated with the provided text.
     pub fn sid_for<A: AsRef<str>>(&self, text: &A) -> Option<SymbolId> {
         self.ids_by_text.get(text.as_ref()).copied()
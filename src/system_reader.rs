@@ -1,15 +1,25 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::ops::Range;
 use delegate::delegate;
+#[cfg(feature = "std")]
 use std::io;
-use std::ops::Range;
 
 use crate::constants::v1_0::{system_symbol_ids, SYSTEM_SYMBOLS};
 use crate::element::{Blob, Clob};
 use crate::raw_reader::{RawReader, RawStreamItem};
 use crate::raw_symbol_token::RawSymbolToken;
 use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonError, IonResult};
+use crate::symbol_table::ImportDescriptor;
 use crate::system_reader::LstPosition::*;
-use crate::types::{Decimal, Int, Str, Symbol, Timestamp};
-use crate::{BlockingRawBinaryReader, IonReader, IonType, SymbolTable};
+use crate::types::{Decimal, Encoding, Int, Span, Str, Symbol, Timestamp};
+#[cfg(feature = "std")]
+use crate::BlockingRawBinaryReader;
+use crate::{IonReader, IonType, SymbolTable};
 
 /// Tracks where the [SystemReader] is in the process of reading a local symbol table.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -77,9 +87,16 @@ pub enum SystemStreamItem {
     Nothing,
 }
 
+// The fields of an import struct in the `imports` list of a local symbol table.
+#[derive(Default)]
+struct ImportFields {
+    name: Option<String>,
+    version: Option<Int>,
+    max_id: Option<Int>,
+}
+
 // Stores information that has been read from a local symbol table that is currently being
 // processed.
-// TODO: Support shared symbol table imports.
 struct LstData {
     // The reader's position within the LST.
     state: LstPosition,
@@ -92,13 +109,16 @@ struct LstData {
     // because the `symbols` field of the LST can appear before the `imports` field but the `imports`
     // field MUST be processed first.
     symbols: Vec<Option<String>>,
+    // The shared symbol tables imported by this LST. Like `symbols`, these are buffered until the
+    // reader leaves the LST.
+    imports: Vec<ImportFields>,
     // At present, BlockingRawTextReader and BlockingRawBinaryReader cannot read the same value more than once.
     // When the SystemReader needs to read the current value as part of processing a local symbol
     // table, it must store a copy of that value in case the user requests it via `read_string()`,
     // `read_i64()`, etc. The fields below are used to store such copies.
     current_symbol: RawSymbolToken,
     current_string: String,
-    current_int: i64,
+    current_int: Int,
 }
 
 impl LstData {
@@ -106,10 +126,11 @@ impl LstData {
         LstData {
             is_append: false,
             symbols: vec![],
+            imports: vec![],
             state: LstPosition::NotReadingAnLst,
             current_symbol: RawSymbolToken::SymbolId(0),
             current_string: String::new(),
-            current_int: 0,
+            current_int: Int::I64(0),
         }
     }
 }
@@ -197,10 +218,15 @@ impl<R: RawReader> SystemReader<R> {
                     self.finish_reading_current_level()?;
                 }
             }
+            ProcessingLstImports if self.current_value_is_import_struct() => {
+                // If the reader is positioned over an import struct when next() is called, we
+                // need to read its fields instead of skipping it.
+                self.step_in()?;
+                self.finish_reading_current_level()?;
+                self.step_out()?;
+            }
             _ => {
-                // Allow values at depths > 1 to be skipped.
-                // TODO: Process import structs that live at depth=2
-                //       imports: [{name:_, version:_, max_id:_}, {name:_, version:_, max_id:_}]
+                // Allow other values at depths > 1 to be skipped.
             }
         }
 
@@ -256,7 +282,33 @@ impl<R: RawReader> SystemReader<R> {
                 }
             }
             ProcessingLstImports => {
-                todo!("Support shared symbol table imports.");
+                // We're in the `imports` list.
+                if self.current_value_is_import_struct() {
+                    // This is the start of a new import.
+                    self.lst.imports.push(ImportFields::default());
+                } else if self.depth() == 3 && !is_null {
+                    // This is a field of the current import. As with the `symbols` field, values
+                    // are loaded eagerly and returned if the user requests them.
+                    let field_name = self.raw_reader.field_name()?;
+                    match ion_type {
+                        IonType::String => {
+                            self.load_current_string()?;
+                            if field_name.matches(system_symbol_ids::NAME, "name") {
+                                self.current_import().name = Some(self.lst.current_string.clone());
+                            }
+                        }
+                        IonType::Int => {
+                            self.load_current_int()?;
+                            let value = Some(self.lst.current_int.clone());
+                            if field_name.matches(system_symbol_ids::VERSION, "version") {
+                                self.current_import().version = value;
+                            } else if field_name.matches(system_symbol_ids::MAX_ID, "max_id") {
+                                self.current_import().max_id = value;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
             }
             ProcessingLstSymbols => {
                 // We're in the `symbols` list.
@@ -300,11 +352,8 @@ impl<R: RawReader> SystemReader<R> {
                 }
             }
             IonType::List => {
-                // Once this is supported, this branch will do nothing because the list either
-                // be processed when the user steps into/through it or when they try to skip over
-                // it, not when it's first encountered. For now though, we fail because this
-                // feature is not yet supported.
-                todo!("Support shared symbol table imports.");
+                // The list will be processed when the user steps into/through it or when they
+                // try to skip over it, not when it's first encountered.
             }
             _ => {
                 // Non-list, non-symbol values for the `imports` field are ignored.
@@ -338,14 +387,26 @@ impl<R: RawReader> SystemReader<R> {
     // Reads the raw reader's current value expecting an integer. Stores the value in
     // `self.lst.current_int` so it can be returned if the user requests it.
     fn load_current_int(&mut self) -> IonResult<()> {
-        // Note: This method will only be called on integers found inside of local symbol tables.
-        //       If an LST has an integer that's too big to fit in an i64, this will fail.
-        self.raw_reader
-            .read_i64()
-            .expect("load_current_int() called at a value that was not an integer.");
+        self.lst.current_int = self.raw_reader.read_int()?;
         Ok(())
     }
 
+    // Returns true if the raw reader is positioned over a non-null struct in the `imports` list
+    // of an LST.
+    fn current_value_is_import_struct(&self) -> bool {
+        self.lst.state == ProcessingLstImports
+            && self.depth() == 2
+            && self.raw_reader.current() == RawStreamItem::Value(IonType::Struct)
+    }
+
+    // Returns the fields of the import struct that the reader is currently inside.
+    fn current_import(&mut self) -> &mut ImportFields {
+        self.lst
+            .imports
+            .last_mut()
+            .expect("the reader is inside an import struct")
+    }
+
     fn process_ivm(&mut self, major: u8, minor: u8) -> IonResult<SystemStreamItem> {
         if self.depth() > 0 {
             return decoding_error("Encountered an IVM at a depth > 0");
@@ -358,12 +419,19 @@ impl<R: RawReader> SystemReader<R> {
 
     // When the reader steps out of an LST, this method will add the new symbols we've been
     // buffering in `self.lst.symbols` to the current symbol table.
-    fn add_lst_symbols_to_current_symbol_table(&mut self) {
+    fn add_lst_symbols_to_current_symbol_table(&mut self) -> IonResult<()> {
         if !self.lst.is_append {
             // This is not an append. Clear the current symbol table.
             self.symbol_table.reset();
         }
-        // TODO: support importing shared symbol tables
+        let mut imports = Vec::with_capacity(self.lst.imports.len());
+        for import in self.lst.imports.drain(..) {
+            if let Some(import) = ImportDescriptor::new(import.name, import.version, import.max_id)?
+            {
+                imports.push(import);
+            }
+        }
+        self.symbol_table.add_unavailable_imports(imports)?;
         // This for loop consumes the `String` values, clearing `self.lst.symbols`.
         for value in self.lst.symbols.drain(..) {
             if let Some(text) = value {
@@ -374,6 +442,7 @@ impl<R: RawReader> SystemReader<R> {
                 self.symbol_table.add_placeholder();
             }
         }
+        Ok(())
     }
 
     // The SystemReader can skip any user-level value, but cannot skip Local Symbol Tables (LSTs) in
@@ -482,10 +551,22 @@ impl<R: RawReader> SystemReader<R> {
         // The raw reader is inside the `symbols` field of an LST and its value is a string.
         // This means that the system reader has eagerly loaded the string to eventually store
         // its text in the current symbol table. Return a copy of the materialized string value.
-        self.lst.state == ProcessingLstSymbols
+        // The same is true of strings in the fields of an import struct.
+        let in_lst_field = self.lst.state == ProcessingLstSymbols
+            || (self.lst.state == ProcessingLstImports && self.depth() == 3);
+        in_lst_field
             && self.raw_reader.ion_type() == Some(IonType::String)
             && !self.raw_reader.is_null()
     }
+
+    // Returns true if the system reader already consumed the current integer from input as part of
+    // processing the fields of an import struct.
+    fn current_int_was_consumed(&self) -> bool {
+        self.lst.state == ProcessingLstImports
+            && self.depth() == 3
+            && self.raw_reader.ion_type() == Some(IonType::Int)
+            && !self.raw_reader.is_null()
+    }
 }
 
 impl<R: RawReader> IonReader for SystemReader<R> {
@@ -578,7 +659,7 @@ impl<R: RawReader> IonReader for SystemReader<R> {
                 // We're stepping out of the local symbol table altogether. Finish processing the
                 // LST instead of skipping its remaining contents.
                 self.finish_reading_current_level()?;
-                self.add_lst_symbols_to_current_symbol_table();
+                self.add_lst_symbols_to_current_symbol_table()?;
                 self.lst.is_append = false;
                 new_lst_state = NotReadingAnLst;
             }
@@ -672,6 +753,23 @@ impl<R: RawReader> IonReader for SystemReader<R> {
         self.raw_reader.read_str()
     }
 
+    fn read_int(&mut self) -> IonResult<Int> {
+        if self.current_int_was_consumed() {
+            return Ok(self.lst.current_int.clone());
+        }
+        self.raw_reader.read_int()
+    }
+
+    fn read_i64(&mut self) -> IonResult<i64> {
+        if self.current_int_was_consumed() {
+            return match self.lst.current_int {
+                Int::I64(value) => Ok(value),
+                _ => decoding_error("integer is too large to fit in an i64"),
+            };
+        }
+        self.raw_reader.read_i64()
+    }
+
     // The SystemReader needs to expose many of the same functions as the Cursor, but only some of
     // those need to be re-defined to allow for system value processing. Any method listed here will
     // be delegated to self.raw_reader directly.
@@ -682,10 +780,10 @@ impl<R: RawReader> IonReader for SystemReader<R> {
             fn ion_type(&self) -> Option<IonType>;
             fn read_null(&mut self) -> IonResult<IonType>;
             fn read_bool(&mut self) -> IonResult<bool>;
-            fn read_int(&mut self) -> IonResult<Int>;
-            fn read_i64(&mut self) -> IonResult<i64>;
             fn read_f32(&mut self) -> IonResult<f32>;
             fn read_f64(&mut self) -> IonResult<f64>;
+            fn encoding(&self) -> Option<Encoding>;
+            fn span(&self) -> Option<Span>;
             fn read_decimal(&mut self) -> IonResult<Decimal>;
             fn read_blob(&mut self) -> IonResult<Blob>;
             fn read_clob(&mut self) -> IonResult<Clob>;
@@ -698,6 +796,7 @@ impl<R: RawReader> IonReader for SystemReader<R> {
 
 /// Functionality that is only available if the data source we're reading from is in-memory, like
 /// a `Vec<u8>` or `&[u8]`.
+#[cfg(feature = "std")]
 impl<T: AsRef<[u8]>> SystemReader<BlockingRawBinaryReader<io::Cursor<T>>> {
     delegate! {
         to self.raw_reader {
@@ -726,10 +825,11 @@ impl<T: AsRef<[u8]>> SystemReader<BlockingRawBinaryReader<io::Cursor<T>>> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::SystemStreamItem::*;
     use crate::blocking_reader::*;
+    use crate::types::ImportLocation;
 
     use super::*;
 
@@ -909,4 +1009,70 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn symbol_table_with_imports() -> IonResult<()> {
+        let ion = r#"
+            $ion_symbol_table::{
+                imports: [{name: "shared", version: 1, max_id: 2}, {name: "$ion", max_id: 9}],
+                symbols: ["foo"],
+            }
+            $10 $11 $12
+            $ion_symbol_table::{
+                imports: [{name: "shared", version: 1, max_id: 2}],
+            }
+          "#;
+        let location = |sid| Symbol::with_import_location(ImportLocation::new("shared", sid));
+
+        // Skip over the LST with `next()`
+        let mut reader = system_reader_for(ion);
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::Struct));
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, location(1));
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, location(2));
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, "foo");
+        assert_eq!(reader.symbol_table.len(), 13);
+
+        // Step through the first import manually, reading its fields
+        let mut reader = system_reader_for(ion);
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::List));
+        assert_eq!(reader.field_name()?, "imports");
+        reader.step_in()?;
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::String));
+        assert_eq!(reader.read_str()?, "shared");
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::Int));
+        assert_eq!(reader.read_i64()?, 1);
+        assert_eq!(reader.next()?, SymbolTableValue(IonType::Int));
+        assert_eq!(reader.field_name()?, "max_id");
+        assert_eq!(reader.read_int()?, Int::I64(2));
+        reader.step_out()?;
+        // Skip the second import and the rest of the LST
+        reader.step_out()?;
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, location(1));
+        Ok(())
+    }
+
+    #[test]
+    fn import_with_huge_max_id_is_an_error() {
+        let mut reader = system_reader_for(
+            r#"$ion_symbol_table::{imports: [{name: "shared", max_id: 10000000000}]} 1"#,
+        );
+        assert_eq!(reader.next(), Ok(SymbolTableValue(IonType::Struct)));
+        assert!(matches!(reader.next(), Err(IonError::DecodingError { .. })));
+    }
+
+    #[test]
+    fn import_without_max_id_is_an_error() {
+        let mut reader = system_reader_for(r#"$ion_symbol_table::{imports: [{name: "shared"}]} 1"#);
+        assert_eq!(reader.next(), Ok(SymbolTableValue(IonType::Struct)));
+        assert!(reader.next().is_err());
+    }
 }
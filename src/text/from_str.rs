@@ -0,0 +1,300 @@
+//! Implementations of [`FromStr`] for the Ion scalar types. Each implementation uses the same
+//! parser as the text reader, so a string is accepted if and only if it would be read as a
+//! single, unannotated value of that type.
+
+use std::str::FromStr;
+
+use crate::element::{Blob, Clob};
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{decoding_error, decoding_error_raw, IonError, IonResult};
+use crate::text::parse_result::IonParseResult;
+use crate::text::parsers::blob::parse_blob;
+use crate::text::parsers::boolean::parse_boolean;
+use crate::text::parsers::clob::parse_clob;
+use crate::text::parsers::decimal::parse_decimal;
+use crate::text::parsers::float::parse_float as parse_float_value;
+use crate::text::parsers::integer::parse_integer;
+use crate::text::parsers::string::parse_string;
+use crate::text::parsers::symbol::parse_symbol;
+use crate::text::parsers::timestamp::parse_timestamp;
+use crate::text::parsers::WHITESPACE_CHARACTERS;
+use crate::text::text_value::TextValue;
+use crate::types::{Decimal, Int, IonType, Str, Symbol, Timestamp, UInt};
+
+/// Parses `text` using `parser`, requiring that the value span the entire string.
+fn parse_complete<P>(text: &str, ion_type: IonType, parser: P) -> IonResult<TextValue>
+where
+    P: Fn(&str) -> IonParseResult<TextValue>,
+{
+    // The parsers are written for streams; most of them need to see the character that follows a
+    // value to know that it has ended. Like the text reader at the end of its input, we append a
+    // newline and a sentinel value. The sentinel also ends a sequence of long string segments.
+    const SENTINEL_ION_TEXT: &str = "\n0\n";
+    let invalid = || decoding_error_raw(format!("'{text}' is not a valid Ion {ion_type}"));
+
+    // Leading whitespace is rejected by the parsers themselves. Trailing whitespace would be
+    // consumed along with the sentinel's newline in some cases, so it is rejected here.
+    if text.ends_with(WHITESPACE_CHARACTERS) {
+        return Err(invalid());
+    }
+    let input = format!("{text}{SENTINEL_ION_TEXT}");
+    match parser(&input) {
+        Ok((remaining, value)) if input.len() - remaining.len() >= text.len() => Ok(value),
+        Ok(_) => Err(invalid()),
+        Err(nom::Err::Failure(e)) if e.description().is_some() => decoding_error(format!(
+            "'{text}' is not a valid Ion {ion_type}: {}",
+            e.description().unwrap()
+        )),
+        Err(_) => Err(invalid()),
+    }
+}
+
+/// Parses the text representation of an Ion float, such as `2.5e0`, `-inf` or `nan`. Note that
+/// Ion requires an exponent; text like `2.5` is a decimal.
+pub fn parse_float(text: &str) -> IonResult<f64> {
+    match parse_complete(text, IonType::Float, parse_float_value)? {
+        TextValue::Float(value) => Ok(value),
+        other => unreachable!("float parser returned {other:?}"),
+    }
+}
+
+/// Parses the text representation of an Ion bool: `true` or `false`.
+pub fn parse_bool(text: &str) -> IonResult<bool> {
+    match parse_complete(text, IonType::Bool, parse_boolean)? {
+        TextValue::Bool(value) => Ok(value),
+        other => unreachable!("bool parser returned {other:?}"),
+    }
+}
+
+impl FromStr for Int {
+    type Err = IonError;
+
+    /// Parses an Ion integer in base-10, base-16 (`0x`) or base-2 (`0b`) notation. Digits may be
+    /// separated by underscores.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_complete(text, IonType::Int, parse_integer)? {
+            TextValue::Int(value) => Ok(value),
+            other => unreachable!("integer parser returned {other:?}"),
+        }
+    }
+}
+
+impl FromStr for UInt {
+    type Err = IonError;
+
+    /// Parses a non-negative Ion integer. See [`Int::from_str`].
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let value = Int::from_str(text)?;
+        if value < Int::I64(0) {
+            return decoding_error(format!(
+                "'{text}' is negative; expected an unsigned integer"
+            ));
+        }
+        Ok(value.into())
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = IonError;
+
+    /// Parses an Ion decimal such as `1.20`, `1.20d-3` or `-0.`. The coefficient and exponent are
+    /// preserved exactly as written.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_complete(text, IonType::Decimal, parse_decimal)? {
+            TextValue::Decimal(value) => Ok(value),
+            other => unreachable!("decimal parser returned {other:?}"),
+        }
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = IonError;
+
+    /// Parses an Ion timestamp of any precision, such as `2020T` or
+    /// `2020-08-01T12:34:56.789-07:00`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_complete(text, IonType::Timestamp, parse_timestamp)? {
+            TextValue::Timestamp(value) => Ok(value),
+            other => unreachable!("timestamp parser returned {other:?}"),
+        }
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = IonError;
+
+    /// Parses an identifier (`foo`), a quoted symbol (`'foo bar'`) or `$0`. Other symbol IDs
+    /// cannot be resolved without a symbol table and are rejected.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_complete(text, IonType::Symbol, parse_symbol)? {
+            TextValue::Symbol(RawSymbolToken::Text(text)) => Ok(Symbol::owned(text)),
+            TextValue::Symbol(RawSymbolToken::SymbolId(0)) => Ok(Symbol::unknown_text()),
+            TextValue::Symbol(RawSymbolToken::SymbolId(sid)) => decoding_error(format!(
+                "symbol ID ${sid} cannot be resolved without a symbol table"
+            )),
+            other => unreachable!("symbol parser returned {other:?}"),
+        }
+    }
+}
+
+impl FromStr for Str {
+    type Err = IonError;
+
+    /// Parses a short (`"foo"`) or long (`'''foo'''`) Ion string, including any escapes.
+    /// Adjacent long string segments are concatenated.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_complete(text, IonType::String, parse_string)? {
+            TextValue::String(value) => Ok(value.into()),
+            other => unreachable!("string parser returned {other:?}"),
+        }
+    }
+}
+
+impl FromStr for Blob {
+    type Err = IonError;
+
+    /// Parses a base64-encoded Ion blob, such as `{{aGVsbG8=}}`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_complete(text, IonType::Blob, parse_blob)? {
+            TextValue::Blob(value) => Ok(value.into()),
+            other => unreachable!("blob parser returned {other:?}"),
+        }
+    }
+}
+
+impl FromStr for Clob {
+    type Err = IonError;
+
+    /// Parses an Ion clob, such as `{{"hello"}}`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_complete(text, IonType::Clob, parse_clob)? {
+            TextValue::Clob(value) => Ok(value.into()),
+            other => unreachable!("clob parser returned {other:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod from_str_tests {
+    use super::*;
+    use crate::ion_data::IonEq;
+    use rstest::*;
+
+    #[rstest]
+    #[case::base_10("-1_000", Int::I64(-1000))]
+    #[case::base_16("0xFF_FF", Int::I64(65535))]
+    #[case::base_2("0b1010", Int::I64(10))]
+    #[case::big("18446744073709551616", Int::from(18446744073709551616u128))]
+    fn int_from_str(#[case] text: &str, #[case] expected: Int) {
+        assert_eq!(text.parse::<Int>().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::leading_whitespace(" 5")]
+    #[case::trailing_whitespace("5 ")]
+    #[case::trailing_text("5 6")]
+    #[case::leading_plus("+5")]
+    #[case::leading_zero("05")]
+    #[case::bad_underscore("1__000")]
+    #[case::decimal("5.0")]
+    #[case::annotated("foo::5")]
+    fn int_from_str_rejects(#[case] text: &str) {
+        assert!(text.parse::<Int>().is_err(), "{text:?} was accepted");
+    }
+
+    #[test]
+    fn uint_from_str() {
+        assert_eq!("0x10".parse::<UInt>().unwrap(), UInt::U64(16));
+        assert!("-1".parse::<UInt>().is_err());
+    }
+
+    #[rstest]
+    #[case("1.20d-3", Decimal::new(120, -5))]
+    #[case("1.20", Decimal::new(120, -2))]
+    #[case("-0.", Decimal::negative_zero())]
+    #[case("5d0", Decimal::new(5, 0))]
+    fn decimal_from_str(#[case] text: &str, #[case] expected: Decimal) {
+        assert!(text.parse::<Decimal>().unwrap().ion_eq(&expected));
+    }
+
+    #[rstest]
+    #[case::integer("5")]
+    #[case::float("5e0")]
+    fn decimal_from_str_rejects(#[case] text: &str) {
+        assert!(text.parse::<Decimal>().is_err());
+    }
+
+    #[rstest]
+    #[case("2020T")]
+    #[case("2020-08T")]
+    #[case("2020-08-01T12:34-00:00")]
+    #[case("2020-08-01T12:34:56.789-07:00")]
+    #[case("2020-08-01T12:34:56.123456789012+00:00")]
+    fn timestamp_from_str(#[case] text: &str) {
+        let timestamp: Timestamp = text.parse().unwrap();
+        assert_eq!(timestamp.to_string(), text);
+    }
+
+    #[rstest]
+    #[case::invalid_day("2020-02-30T")]
+    #[case::missing_t("2020")]
+    #[case::hour_without_minute("2020-08-01T12Z")]
+    fn timestamp_from_str_rejects(#[case] text: &str) {
+        assert!(text.parse::<Timestamp>().is_err());
+    }
+
+    #[rstest]
+    #[case::identifier("foo", Symbol::owned("foo"))]
+    #[case::quoted("'foo bar'", Symbol::owned("foo bar"))]
+    #[case::quoted_keyword("'true'", Symbol::owned("true"))]
+    #[case::unknown_text("$0", Symbol::unknown_text())]
+    fn symbol_from_str(#[case] text: &str, #[case] expected: Symbol) {
+        assert_eq!(text.parse::<Symbol>().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::keyword("true")]
+    #[case::null("null")]
+    #[case::symbol_id("$10")]
+    #[case::operator("+")]
+    fn symbol_from_str_rejects(#[case] text: &str) {
+        assert!(text.parse::<Symbol>().is_err(), "{text:?} was accepted");
+    }
+
+    #[rstest]
+    #[case::short(r#""foo\tbar""#, "foo\tbar")]
+    #[case::long("'''foo'''", "foo")]
+    #[case::long_segments("'''foo''' '''bar'''", "foobar")]
+    fn str_from_str(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(text.parse::<Str>().unwrap(), expected);
+    }
+
+    #[test]
+    fn lobs_from_str() {
+        assert_eq!("{{aGVsbG8=}}".parse::<Blob>().unwrap(), Blob::from("hello"));
+        assert_eq!(
+            r#"{{"hello"}}"#.parse::<Clob>().unwrap(),
+            Clob::from("hello")
+        );
+        assert!("{{aGVsbG8=}} {{}}".parse::<Blob>().is_err());
+        assert!(r#"{{"hello"}}"#.parse::<Blob>().is_err());
+    }
+
+    #[rstest]
+    #[case("2.5e0", 2.5)]
+    #[case("-inf", f64::NEG_INFINITY)]
+    #[case("+inf", f64::INFINITY)]
+    fn float_from_str(#[case] text: &str, #[case] expected: f64) {
+        assert_eq!(parse_float(text).unwrap(), expected);
+    }
+
+    #[test]
+    fn float_and_bool_from_str() {
+        assert!(parse_float("nan").unwrap().is_nan());
+        assert!(parse_float("2.5").is_err());
+        assert!(parse_bool("true").unwrap());
+        assert!(!parse_bool("false").unwrap());
+        assert!(parse_bool("True").is_err());
+    }
+}
@@ -1,8 +1,19 @@
+#[cfg(feature = "std")]
+pub(crate) mod from_str;
+#[cfg(feature = "std")]
 pub mod non_blocking;
 pub(crate) mod parent_container;
+#[cfg(feature = "std")]
 pub(crate) mod parse_result;
+#[cfg(feature = "std")]
 pub(in crate::text) mod parsers;
+#[cfg(feature = "std")]
 pub mod raw_text_writer;
 pub mod text_formatter;
+#[cfg(feature = "std")]
 pub(crate) mod text_value;
+#[cfg(feature = "std")]
 pub(crate) mod text_writer;
+
+#[cfg(feature = "std")]
+pub use from_str::{parse_bool, parse_float};
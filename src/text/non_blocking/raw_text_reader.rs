@@ -11,16 +11,19 @@ use crate::result::{
     IonResult, Position,
 };
 use crate::stream_reader::IonReader;
+use crate::text::non_blocking::text_buffer::advance_position;
 use crate::text::non_blocking::text_buffer::TextBuffer;
 use crate::text::parent_container::ParentContainer;
 use crate::text::parse_result::IonParseResult;
+use crate::text::parsers::comments::whitespace_or_comments;
 use crate::text::parsers::containers::{
     list_delimiter, list_value_or_end, s_expression_delimiter, s_expression_value_or_end,
     struct_delimiter, struct_field_name_or_end, struct_field_value,
 };
 use crate::text::parsers::top_level::{stream_item, RawTextStreamItem};
+use crate::text::parsers::value::annotated_value;
 use crate::text::text_value::{AnnotatedTextValue, TextValue};
-use crate::types::{Decimal, Int, Timestamp};
+use crate::types::{Decimal, Int, Span, Timestamp};
 use crate::IonType;
 
 const INITIAL_PARENTS_CAPACITY: usize = 16;
@@ -77,6 +80,17 @@ pub struct RawTextReader<A: AsRef<[u8]> + Expandable> {
     step_out_nest: usize,
     // Tracking whether or not we need to continue a previously failed state.
     need_continue: bool,
+    // Whether the reader reports the span of each value. Finding the bounds of a value requires
+    // some extra parsing, so this is off unless requested.
+    track_spans: bool,
+    // If spans are being tracked and the reader is positioned over a value (or has just stepped
+    // out of a container), this is the span of that value.
+    current_span: Option<Span>,
+    // If spans are being tracked, the start of the text most recently matched by
+    // `parse_next_nom` and its length in bytes.
+    last_parse: Option<(Position, usize)>,
+    // If spans are being tracked, the start positions of the containers in `parents`.
+    container_starts: Vec<Position>,
 }
 
 /// Represents the final outcome of a [RawTextReader]'s attempt to parse the next value in the stream.
@@ -134,9 +148,45 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
             state: ReaderState::Ready,
             step_out_nest: 0,
             need_continue: false,
+            track_spans: false,
+            current_span: None,
+            last_parse: None,
+            container_starts: Vec::new(),
         }
     }
 
+    /// Configures whether the reader reports the [`Span`] of each value. This should be set
+    /// before the reader begins reading.
+    pub(crate) fn set_track_spans(&mut self, track_spans: bool) {
+        self.track_spans = track_spans;
+        self.buffer.set_track_position(track_spans);
+    }
+
+    /// Returns the span of the value matched by the most recent call to `parse_next_nom`.
+    /// The matched text may include leading whitespace and comments as well as a trailing
+    /// delimiter, neither of which are part of the value.
+    fn last_parse_span(&self, parent_type: Option<IonType>) -> Option<Span> {
+        let (start, length) = self.last_parse.as_ref()?;
+        let text = self.buffer.text_from_offset(start.byte_offset());
+        let value_start = match whitespace_or_comments(text) {
+            Ok((remaining, _)) => text.len() - remaining.len(),
+            Err(_) => 0,
+        };
+        // Scalars in lists and structs are matched along with the delimiter that follows them.
+        // Values in other positions are matched without any trailing text.
+        let value_end = match parent_type {
+            Some(IonType::List | IonType::Struct) => match annotated_value(&text[value_start..]) {
+                Ok((remaining, _)) => text.len() - remaining.len(),
+                Err(_) => *length,
+            },
+            _ => *length,
+        };
+        Some(Span::new(
+            advance_position(start, &text[..value_start]),
+            advance_position(start, &text[..value_end]),
+        ))
+    }
+
     fn load_next_value(&mut self) -> IonResult<()> {
         // If the reader's current value is the beginning of a container and the user calls `next()`,
         // we need to skip the entire container. We can do this by stepping into and then out of
@@ -157,6 +207,7 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
         self.current_ivm = None;
         self.current_value = None;
         self.current_field_name = None;
+        self.current_span = None;
 
         if self.parents.is_empty() {
             // The `parents` stack is empty. We're at the top level.
@@ -223,6 +274,9 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
             Ok(Some(value)) => {
                 // We successfully read a value. Set it as the current value.
                 self.current_value = Some(value);
+                if self.track_spans {
+                    self.current_span = self.last_parse_span(Some(parent.ion_type()));
+                }
             }
             Err(e) => return Err(e),
         };
@@ -266,6 +320,10 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
             // Remove the parent container from the stack and clear the current value.
             let _ = self.parents.pop();
             self.current_value = None;
+            // Having consumed the container's end, we now know its complete span.
+            if let Some(start) = self.container_starts.pop() {
+                self.current_span = Some(Span::new(start, self.buffer.consumed_position()));
+            }
 
             if self.parents.is_empty() {
                 // We're at the top level; nothing left to do.
@@ -337,6 +395,9 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
             RootParseResult::Ok(RawTextStreamItem::AnnotatedTextValue(value)) => {
                 // We read a value successfully; set it as our current value.
                 self.current_value = Some(value);
+                if self.track_spans {
+                    self.current_span = self.last_parse_span(None);
+                }
                 Ok(())
             }
             RootParseResult::Incomplete(line, column) => incomplete_text_error(
@@ -490,6 +551,8 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
         let RawTextReader {
             ref mut is_eof,
             ref mut buffer,
+            track_spans,
+            ref mut last_parse,
             ..
         } = *self;
 
@@ -543,6 +606,9 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
                     // The difference in length tells us how many bytes were part of the
                     // text representation of the value that we found.
                     let bytes_consumed = length_before_parse - length_after_parse;
+                    if track_spans {
+                        *last_parse = Some((buffer.consumed_position(), bytes_consumed));
+                    }
                     buffer.consume(bytes_consumed);
                     return RootParseResult::Ok(value);
                 }
@@ -603,7 +669,7 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
                 // which indicates that our 0 was parsed.
                 RootParseResult::Eof
             }
-            Ok((_remaining_text, value)) => {
+            Ok((remaining_after_parse, value)) => {
                 // If we match, and try to consume the remaining buffer in its entirety before we
                 // know the data has been fully loaded we need to treat it as an incomplete error
                 // so that we do not inadvertently succeed on a partial parse.
@@ -616,6 +682,10 @@ impl<A: AsRef<[u8]> + Expandable> RawTextReader<A> {
                 // We found something else. The zero is still in the buffer; we can leave it there.
                 // The reader's `is_eof` flag has been set, so the text buffer will never be used
                 // again. Return the value we found.
+                if self.track_spans {
+                    let length = remaining_text.len() - remaining_after_parse.len();
+                    self.last_parse = Some((self.buffer.consumed_position(), length));
+                }
                 RootParseResult::Ok(value)
             }
             Err(Incomplete(_needed)) => {
@@ -814,7 +884,7 @@ impl<A: AsRef<[u8]> + Expandable> IonReader for RawTextReader<A> {
     fn read_i64(&mut self) -> IonResult<i64> {
         match self.current_value.as_ref().map(|current| current.value()) {
             Some(TextValue::Int(Int::I64(value))) => Ok(*value),
-            Some(TextValue::Int(Int::BigInt(value))) => {
+            Some(TextValue::Int(value)) => {
                 decoding_error(format!("Integer {value} is too large to fit in an i64."))
             }
             _ => Err(self.expected("int value")),
@@ -890,6 +960,9 @@ impl<A: AsRef<[u8]> + Expandable> IonReader for RawTextReader<A> {
                 self.parents
                     .push(ParentContainer::new(value.value().ion_type()));
                 self.current_value = None;
+                if let Some(span) = self.current_span.take() {
+                    self.container_starts.push(span.start().clone());
+                }
                 Ok(())
             }
             Some(value) => {
@@ -959,6 +1032,10 @@ impl<A: AsRef<[u8]> + Expandable> IonReader for RawTextReader<A> {
     fn depth(&self) -> usize {
         self.parents.len()
     }
+
+    fn span(&self) -> Option<Span> {
+        self.current_span.clone()
+    }
 }
 
 #[cfg(test)]
@@ -1,10 +1,9 @@
 use crate::result::Position;
 use crate::{IonError, IonResult};
 
+use std::fmt::{Display, Formatter};
 use std::io::Read;
 
-use thiserror::Error;
-
 /// Represents a span of bytes that have been validated as UTF-8 strings.
 ///
 /// The range of bytes is [.0, .1)
@@ -27,6 +26,8 @@ struct Checkpoint {
     pub line_number: usize,
     pub offset: usize,
     pub bytes_consumed: usize,
+    pub consumed_line: usize,
+    pub consumed_column: usize,
 }
 
 /// Calculates the offset into a slice, where a given subslice is located.
@@ -50,15 +51,30 @@ impl SubsliceOffset for str {
     }
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug)]
 pub(crate) enum TextError {
-    #[error("Incomplete UTF-8 data at {line}:{column}")]
     Incomplete { line: usize, column: usize },
-
-    #[error("Invalid UTF-8 sequence in provided data at byte {offset}")]
     InvalidUtf8 { offset: usize },
 }
 
+impl Display for TextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextError::Incomplete { line, column } => {
+                write!(f, "Incomplete UTF-8 data at {line}:{column}")
+            }
+            TextError::InvalidUtf8 { offset } => {
+                write!(
+                    f,
+                    "Invalid UTF-8 sequence in provided data at byte {offset}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TextError {}
+
 impl TextError {
     fn utf8_error(offset: usize) -> Self {
         Self::InvalidUtf8 { offset }
@@ -87,6 +103,14 @@ pub(crate) struct TextBuffer<A: AsRef<[u8]>> {
     line_end_column: usize,
     /// Save point for rolling back. Invalidated by `append_bytes`, and `read_from`.
     checkpoint: Option<Checkpoint>,
+
+    /// Whether `consume` should keep `consumed_line` and `consumed_column` up to date. Counting
+    /// the characters in consumed text is only worth the cost if the reader reports positions.
+    track_position: bool,
+    /// The line (starting at 1) on which the next unconsumed character appears.
+    consumed_line: usize,
+    /// The column (starting at 1, counting characters) of the next unconsumed character.
+    consumed_column: usize,
 }
 
 impl<A: AsRef<[u8]>> TextBuffer<A> {
@@ -104,6 +128,9 @@ impl<A: AsRef<[u8]>> TextBuffer<A> {
             line_number: 0,
             line_end_column: 0,
             checkpoint: None,
+            track_position: false,
+            consumed_line: 1,
+            consumed_column: 1,
         }
     }
 
@@ -117,6 +144,29 @@ impl<A: AsRef<[u8]>> TextBuffer<A> {
             .with_text_position(self.line_number, self.line_offset)
     }
 
+    /// Configures whether the buffer tracks the line and column of the consumed text so that it
+    /// can be reported by [`consumed_position`](Self::consumed_position). This should be set
+    /// before any text is consumed.
+    pub fn set_track_position(&mut self, track_position: bool) {
+        self.track_position = track_position;
+    }
+
+    /// Returns the position of the next unconsumed character. The line and column are only
+    /// meaningful if the buffer is [tracking them](Self::set_track_position).
+    pub fn consumed_position(&self) -> Position {
+        Position::with_offset(self.bytes_consumed)
+            .with_text_position(self.consumed_line, self.consumed_column)
+    }
+
+    /// Returns the text in the current line that begins at the stream offset `offset`, including
+    /// text that has not been consumed yet. `offset` must not precede the start of the line data
+    /// loaded by the most recent [`load_next_n_lines`](Self::load_next_n_lines).
+    pub fn text_from_offset(&self, offset: usize) -> &str {
+        let consumed_since_offset = self.bytes_consumed - offset;
+        let start = self.line.0 + self.line_offset - consumed_since_offset;
+        self.string_view(ValidUtf8Span(start, self.line.1))
+    }
+
     /// Save a checkpoint that can be rolled back to.
     /// This stores the line information (offset, line number, span of our UTF8 data, etc) so that
     /// we can rollback to it later if needed. The data stored here is invalidated on a read_from,
@@ -127,6 +177,8 @@ impl<A: AsRef<[u8]>> TextBuffer<A> {
             line_number: self.line_number,
             offset: self.line_offset,
             bytes_consumed: self.bytes_consumed,
+            consumed_line: self.consumed_line,
+            consumed_column: self.consumed_column,
         })
     }
 
@@ -136,6 +188,8 @@ impl<A: AsRef<[u8]>> TextBuffer<A> {
             self.line = checkpoint.line;
             self.line_number = checkpoint.line_number;
             self.bytes_consumed = checkpoint.bytes_consumed;
+            self.consumed_line = checkpoint.consumed_line;
+            self.consumed_column = checkpoint.consumed_column;
         }
     }
 
@@ -198,6 +252,12 @@ impl<A: AsRef<[u8]>> TextBuffer<A> {
             remaining_line.is_char_boundary(number_of_bytes),
             "Cannot consume() a number of bytes that will leave invalid UTF-8 in the current line."
         );
+        if self.track_position {
+            (self.consumed_line, self.consumed_column) = advance_line_and_column(
+                (self.consumed_line, self.consumed_column),
+                &remaining_line[..number_of_bytes],
+            );
+        }
         self.line_offset += number_of_bytes;
         self.bytes_consumed += number_of_bytes;
     }
@@ -303,6 +363,24 @@ impl<A: AsRef<[u8]>> TextBuffer<A> {
     }
 }
 
+/// Returns the position that follows `text` if it begins at `start`.
+pub(crate) fn advance_position(start: &Position, text: &str) -> Position {
+    let (line, column) = advance_line_and_column(start.text_position().unwrap_or((1, 1)), text);
+    Position::with_offset(start.byte_offset() + text.len()).with_text_position(line, column)
+}
+
+fn advance_line_and_column((mut line, mut column): (usize, usize), text: &str) -> (usize, usize) {
+    for c in text.chars() {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
 impl TextBuffer<Vec<u8>> {
     /// Moves any unread bytes to the front of the `Vec<u8>`, making room for more data at the
     /// tail. This method should only be called when the bytes remaining in the buffer represent an
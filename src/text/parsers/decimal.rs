@@ -91,6 +91,8 @@ fn decimal_from_text_components<'a>(
             .or_fatal_parse_error(input, "parsing coefficient magnitude as u64 failed")?
             .1;
         UInt::U64(value)
+    } else if let Ok(value) = u128::from_str(&magnitude_text) {
+        UInt::from(value)
     } else {
         let value = BigUint::from_str(&magnitude_text)
             .or_fatal_parse_error(input, "parsing coefficient magnitude as u64 failed")?
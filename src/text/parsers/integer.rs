@@ -124,9 +124,13 @@ fn parse_sanitized_text_with_radix(text: &str, radix: u32) -> IonParseResult<Int
             if e.kind() == &IntErrorKind::NegOverflow || e.kind() == &IntErrorKind::PosOverflow =>
         {
             // The text is ok, but the magnitude of the integer it represents is too large to
-            // represent using an i64. Try again with BigInt.
+            // represent using an i64. Try again with an i128, which does not allocate, and then
+            // with a BigInt.
+            if let Ok(integer) = i128::from_str_radix(text, radix) {
+                return Ok(("", Int::from(integer)));
+            }
             BigInt::from_str_radix(text, radix)
-                .map(Int::BigInt)
+                .map(Int::from)
                 .or_fatal_parse_error(text, "found big integer with invalid text")
         }
         Err(e) => {
@@ -12,7 +12,7 @@ pub(crate) mod annotations;
 pub(crate) mod blob;
 pub(crate) mod boolean;
 pub(crate) mod clob;
-mod comments;
+pub(crate) mod comments;
 pub(crate) mod containers;
 pub(crate) mod decimal;
 pub(crate) mod float;
@@ -26,7 +26,7 @@ pub(crate) mod timestamp;
 pub(crate) mod top_level;
 pub(crate) mod value;
 
-const WHITESPACE_CHARACTERS: &[char] = &[
+pub(crate) const WHITESPACE_CHARACTERS: &[char] = &[
     ' ',    // Space
     '\t',   // Tab
     '\r',   // Carriage return
@@ -6,7 +6,7 @@ use chrono::{DateTime, FixedOffset};
 use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
 use crate::result::{illegal_operation, IonResult};
 use crate::text::text_formatter::STRING_ESCAPE_CODES;
-use crate::types::{ContainerType, Decimal, Timestamp};
+use crate::types::{reject_symbol_handle, ContainerType, Decimal, Timestamp};
 use crate::writer::IonWriter;
 use crate::{Int, IonType, RawSymbolToken};
 
@@ -390,7 +390,10 @@ impl<W: Write> RawTextWriter<W> {
         })
     }
 
-    pub fn add_annotation<A: AsRawSymbolTokenRef>(&mut self, annotation: A) {
+    /// Adds an annotation to the next value that is written. Returns an `Err` if `annotation` is a
+    /// [`SymbolHandle`](crate::SymbolHandle).
+    pub fn add_annotation<A: AsRawSymbolTokenRef>(&mut self, annotation: A) -> IonResult<()> {
+        reject_symbol_handle(&annotation, "RawTextWriter")?;
         // TODO: This function currently allocates a new string for each annotation.
         //       It will be common for this text to come from the symbol table; we should
         //       make it possible to pass an Arc<str> or similar when applicable.
@@ -400,6 +403,7 @@ impl<W: Write> RawTextWriter<W> {
             RawSymbolTokenRef::Text(text) => RawSymbolToken::Text(text.to_string()),
         };
         self.annotations.push(token);
+        Ok(())
     }
 
     /// Writes the body (i.e. no start or end delimiters) of a string or symbol with any illegal
@@ -459,15 +463,19 @@ impl<W: Write> IonWriter for RawTextWriter<W> {
     }
 
     /// Sets a list of annotations that will be applied to the next value that is written.
-    fn set_annotations<I, A>(&mut self, annotations: I)
+    fn set_annotations<I, A>(&mut self, annotations: I) -> IonResult<()>
     where
         A: AsRawSymbolTokenRef,
         I: IntoIterator<Item = A>,
     {
         self.annotations.clear();
         for annotation in annotations {
-            self.add_annotation(annotation)
+            if let Err(error) = self.add_annotation(annotation) {
+                self.annotations.clear();
+                return Err(error);
+            }
         }
+        Ok(())
     }
 
     /// Writes an Ion null of the specified type.
@@ -514,6 +522,22 @@ impl<W: Write> IonWriter for RawTextWriter<W> {
         })
     }
 
+    /// Writes the provided u64 value as an Ion integer.
+    fn write_u64(&mut self, value: u64) -> IonResult<()> {
+        self.write_scalar(|output| {
+            write!(output, "{value}")?;
+            Ok(())
+        })
+    }
+
+    /// Writes the provided i128 value as an Ion integer.
+    fn write_i128(&mut self, value: i128) -> IonResult<()> {
+        self.write_scalar(|output| {
+            write!(output, "{value}")?;
+            Ok(())
+        })
+    }
+
     /// Writes an Ion `integer` with the specified value to the output stream.
     fn write_int(&mut self, value: &Int) -> IonResult<()> {
         self.write_scalar(|output| {
@@ -576,6 +600,7 @@ impl<W: Write> IonWriter for RawTextWriter<W> {
 
     /// Writes the provided &str value as an Ion symbol.
     fn write_symbol<A: AsRawSymbolTokenRef>(&mut self, value: A) -> IonResult<()> {
+        reject_symbol_handle(&value, "RawTextWriter")?;
         self.write_scalar(|output| {
             RawTextWriter::write_symbol_token(output, value)?;
             Ok(())
@@ -662,15 +687,20 @@ impl<W: Write> IonWriter for RawTextWriter<W> {
         Ok(())
     }
 
-    /// Sets the current field name to `name`. If the TextWriter is currently positioned inside
-    /// of a struct, the field name will be written before the next value. Otherwise, it will be
-    /// ignored.
-    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) {
+    /// Sets the current field name to `name`. The field name will be written before the next
+    /// value. If the RawTextWriter is not currently positioned inside of a struct, returns an
+    /// `Err`.
+    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) -> IonResult<()> {
+        if !self.is_in_struct() {
+            return illegal_operation("cannot set a field name when the writer is not in a struct");
+        }
+        reject_symbol_handle(&name, "RawTextWriter")?;
         let token = match name.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(sid) => RawSymbolToken::SymbolId(sid),
             RawSymbolTokenRef::Text(text) => RawSymbolToken::Text(text.to_string()),
         };
         self.field_name = Some(token);
+        Ok(())
     }
 
     fn parent_type(&self) -> Option<IonType> {
@@ -825,6 +855,19 @@ mod tests {
         write_scalar_test(|w| w.write_i64(7), "7");
     }
 
+    #[test]
+    fn write_u64() {
+        write_scalar_test(|w| w.write_u64(u64::MAX), "18446744073709551615");
+    }
+
+    #[test]
+    fn write_i128() {
+        write_scalar_test(
+            |w| w.write_i128(i128::MIN),
+            "-170141183460469231731687303715884105728",
+        );
+    }
+
     #[test]
     fn write_f32() {
         write_scalar_test(|w| w.write_f32(700f32), "7e2");
@@ -839,7 +882,7 @@ mod tests {
     fn write_annotated_i64() {
         write_scalar_test(
             |w| {
-                w.set_annotations(["foo", "bar", "baz quux"]);
+                w.set_annotations(["foo", "bar", "baz quux"])?;
                 w.write_i64(7)
             },
             "foo::bar::'baz quux'::7",
@@ -991,7 +1034,7 @@ mod tests {
             RawTextWriterBuilder::new().with_space_after_field_name("   "),
             |w| {
                 w.step_in(IonType::Struct)?;
-                w.set_field_name("a");
+                w.set_field_name("a")?;
                 w.write_string("foo")?;
                 w.step_out()
             },
@@ -1005,7 +1048,7 @@ mod tests {
             RawTextWriterBuilder::new().with_space_after_container_start("   "),
             |w| {
                 w.step_in(IonType::Struct)?;
-                w.set_field_name("a");
+                w.set_field_name("a")?;
                 w.write_string("foo")?;
                 w.step_out()
             },
@@ -1082,12 +1125,12 @@ mod tests {
         writer_test(
             |w| {
                 w.step_in(IonType::Struct)?;
-                w.set_field_name("a");
+                w.set_field_name("a")?;
                 w.write_string("foo")?;
-                w.set_field_name("b");
+                w.set_field_name("b")?;
                 w.write_i64(21)?;
-                w.set_field_name("c");
-                w.set_annotations(["quux"]);
+                w.set_field_name("c")?;
+                w.set_annotations(["quux"])?;
                 w.write_symbol("bar")?;
                 w.step_out()
             },
@@ -1096,4 +1139,19 @@ mod tests {
             "{a: \"foo\", b: 21, c: quux::bar}",
         );
     }
+
+    #[test]
+    fn field_name_outside_of_struct_is_an_error() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = RawTextWriterBuilder::default().build(&mut output)?;
+        assert!(writer.set_field_name("a").is_err());
+        writer.step_in(IonType::List)?;
+        assert!(writer.set_field_name("a").is_err());
+        writer.write_i64(1)?;
+        writer.step_out()?;
+        writer.flush()?;
+        drop(writer);
+        assert_eq!(str::from_utf8(&output).unwrap(), "[1]");
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 use crate::element::{Annotations, Sequence, Struct};
 use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
 use crate::{Decimal, Int, IonResult, IonType, RawSymbolTokenRef, Timestamp};
+use alloc::string::String;
 
 pub const STRING_ESCAPE_CODES: &[&str] = &string_escape_code_init();
 
@@ -179,11 +180,11 @@ pub(crate) const fn string_escape_code_init() -> [&'static str; 256] {
 
 /// Provides a text formatter for Ion values
 /// This is used with the Display implementation of `OwnedElement`
-pub struct IonValueFormatter<'a, W: std::fmt::Write> {
+pub struct IonValueFormatter<'a, W: core::fmt::Write> {
     pub(crate) output: &'a mut W,
 }
 
-impl<'a, W: std::fmt::Write> IonValueFormatter<'a, W> {
+impl<'a, W: core::fmt::Write> IonValueFormatter<'a, W> {
     /// Returns `true` if the provided `token`'s text is an 'identifier'. That is, the text starts
     /// with a `$`, `_` or ASCII letter and is followed by a sequence of `$`, `_`, or ASCII letters
     /// and numbers. Examples:
@@ -490,11 +491,8 @@ mod formatter_test {
 
     #[test]
     fn test_format_big_int() -> IonResult<()> {
-        formatter(|ivf| ivf.format_integer(&Int::BigInt(BigInt::from(4))), "4");
-        formatter(
-            |ivf| ivf.format_integer(&Int::BigInt(BigInt::from(-4))),
-            "-4",
-        );
+        formatter(|ivf| ivf.format_integer(&Int::from(BigInt::from(4))), "4");
+        formatter(|ivf| ivf.format_integer(&Int::from(BigInt::from(-4))), "-4");
         Ok(())
     }
 
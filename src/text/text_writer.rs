@@ -1,11 +1,12 @@
 use crate::element::writer::TextKind;
 use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
-use crate::result::IonResult;
+use crate::result::{illegal_operation, IonResult};
 use crate::text::raw_text_writer::RawTextWriter;
-use crate::types::{Decimal, Timestamp};
+use crate::types::{reject_symbol_handle, Decimal, Timestamp};
 use crate::writer::IonWriter;
 use crate::{Int, IonType, RawTextWriterBuilder, SymbolTable};
 use delegate::delegate;
+use smallvec::SmallVec;
 use std::io::Write;
 
 pub struct TextWriterBuilder {
@@ -77,6 +78,7 @@ impl TextWriterBuilder {
         let text_writer = TextWriter {
             raw_writer,
             symbol_table: SymbolTable::new(),
+            text_kind: self.text_kind,
         };
         Ok(text_writer)
     }
@@ -96,6 +98,14 @@ impl Default for TextWriterBuilder {
 pub struct TextWriter<W: Write> {
     raw_writer: RawTextWriter<W>,
     symbol_table: SymbolTable,
+    text_kind: TextKind,
+}
+
+impl<W: Write> TextWriter<W> {
+    /// Returns the [`TextKind`] that determines how this writer formats its output.
+    pub fn text_kind(&self) -> TextKind {
+        self.text_kind
+    }
 }
 
 impl<W: Write> IonWriter for TextWriter<W> {
@@ -106,52 +116,34 @@ impl<W: Write> IonWriter for TextWriter<W> {
         true
     }
 
-    fn set_annotations<I, A>(&mut self, annotations: I)
+    fn set_annotations<I, A>(&mut self, annotations: I) -> IonResult<()>
     where
         A: AsRawSymbolTokenRef,
         I: IntoIterator<Item = A>,
     {
-        for annotation in annotations {
-            let raw_symbol_token_ref = match annotation.as_raw_symbol_token_ref() {
-                RawSymbolTokenRef::SymbolId(symbol_id) => {
-                    // Get the text associated with this symbol ID
-                    match self.symbol_table.text_for(symbol_id) {
-                        Some(text) => RawSymbolTokenRef::Text(text),
-                        None => RawSymbolTokenRef::SymbolId(symbol_id),
-                    }
-                }
-                text_token => text_token,
-            };
-            self.raw_writer.add_annotation(raw_symbol_token_ref);
+        // Resolve all of the annotations before applying any of them so that an invalid
+        // annotation does not leave the next value partially annotated.
+        let annotations: SmallVec<[A; 4]> = annotations.into_iter().collect();
+        for annotation in &annotations {
+            resolve_symbol_id(&self.symbol_table, annotation, "as annotation")?;
+        }
+        for annotation in &annotations {
+            let raw_symbol_token_ref =
+                resolve_symbol_id(&self.symbol_table, annotation, "as annotation")?;
+            self.raw_writer.add_annotation(raw_symbol_token_ref)?;
         }
+        Ok(())
     }
 
     fn write_symbol<A: AsRawSymbolTokenRef>(&mut self, value: A) -> IonResult<()> {
-        let raw_symbol_token_ref = match value.as_raw_symbol_token_ref() {
-            RawSymbolTokenRef::SymbolId(symbol_id) => {
-                // Get the text associated with this symbol ID
-                match self.symbol_table.text_for(symbol_id) {
-                    Some(text) => RawSymbolTokenRef::Text(text),
-                    None => RawSymbolTokenRef::SymbolId(symbol_id),
-                }
-            }
-            text_token => text_token,
-        };
+        let raw_symbol_token_ref =
+            resolve_symbol_id(&self.symbol_table, &value, "as a symbol value")?;
         self.raw_writer.write_symbol(raw_symbol_token_ref)
     }
 
-    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) {
-        let raw_symbol_token_ref = match name.as_raw_symbol_token_ref() {
-            RawSymbolTokenRef::SymbolId(symbol_id) => {
-                // Get the text associated with this symbol ID
-                match self.symbol_table.text_for(symbol_id) {
-                    Some(text) => RawSymbolTokenRef::Text(text),
-                    None => RawSymbolTokenRef::SymbolId(symbol_id),
-                }
-            }
-            text_token => text_token,
-        };
-        self.raw_writer.set_field_name(raw_symbol_token_ref);
+    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) -> IonResult<()> {
+        let raw_symbol_token_ref = resolve_symbol_id(&self.symbol_table, &name, "as field name")?;
+        self.raw_writer.set_field_name(raw_symbol_token_ref)
     }
 
     delegate! {
@@ -161,6 +153,8 @@ impl<W: Write> IonWriter for TextWriter<W> {
             fn write_null(&mut self, ion_type: IonType) -> IonResult<()>;
             fn write_bool(&mut self, value: bool) -> IonResult<()>;
             fn write_i64(&mut self, value: i64) -> IonResult<()>;
+            fn write_u64(&mut self, value: u64) -> IonResult<()>;
+            fn write_i128(&mut self, value: i128) -> IonResult<()>;
             fn write_int(&mut self, value: &Int) -> IonResult<()>;
             fn write_f32(&mut self, value: f32) -> IonResult<()>;
             fn write_f64(&mut self, value: f64) -> IonResult<()>;
@@ -180,6 +174,27 @@ impl<W: Write> IonWriter for TextWriter<W> {
     }
 }
 
+/// If `token` is a symbol ID, returns its text from `symbol_table`. Symbol ID 0 (unknown text) is
+/// returned unchanged; any other symbol ID without text is an error, since it would be written as
+/// an undefined `$ID`. `usage` describes how the token was being used, for the error message.
+fn resolve_symbol_id<'a, A: AsRawSymbolTokenRef>(
+    symbol_table: &'a SymbolTable,
+    token: &'a A,
+    usage: &str,
+) -> IonResult<RawSymbolTokenRef<'a>> {
+    reject_symbol_handle(token, "TextWriter")?;
+    match token.as_raw_symbol_token_ref() {
+        RawSymbolTokenRef::SymbolId(0) => Ok(RawSymbolTokenRef::SymbolId(0)),
+        RawSymbolTokenRef::SymbolId(symbol_id) => match symbol_table.text_for(symbol_id) {
+            Some(text) => Ok(RawSymbolTokenRef::Text(text)),
+            None => illegal_operation(format!(
+                "Cannot write symbol ID ${symbol_id} {usage}. It is undefined."
+            )),
+        },
+        text_token => Ok(text_token),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,8 +212,8 @@ mod tests {
         // The following symbol IDs are in the system symbol table.
         // https://amazon-ion.github.io/ion-docs/docs/symbols.html#system-symbols
         text_writer.step_in(IonType::Struct)?;
-        text_writer.set_field_name(4);
-        text_writer.set_annotations([1]);
+        text_writer.set_field_name(4)?;
+        text_writer.set_annotations([1])?;
         text_writer.write_symbol(5)?;
         text_writer.step_out()?;
         text_writer.flush()?;
@@ -215,4 +230,29 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn undefined_symbol_ids_are_errors() -> IonResult<()> {
+        let mut text_writer = TextWriterBuilder::new().build(Vec::new())?;
+        assert!(text_writer.write_symbol(100).is_err());
+        // The annotations before the invalid symbol ID are not applied either.
+        assert!(text_writer.set_annotations([4, 100]).is_err());
+        text_writer.write_i64(5)?;
+        text_writer.step_in(IonType::Struct)?;
+        assert!(text_writer.set_field_name(100).is_err());
+        text_writer.set_field_name(0)?;
+        text_writer.write_symbol(0)?;
+        text_writer.step_out()?;
+        text_writer.flush()?;
+
+        let mut reader = ReaderBuilder::new().build(text_writer.output().as_slice())?;
+        assert_eq!(Value(IonType::Int), reader.next()?);
+        assert_eq!(0, reader.number_of_annotations());
+        assert_eq!(Value(IonType::Struct), reader.next()?);
+        reader.step_in()?;
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert_eq!(reader.field_name()?, crate::Symbol::unknown_text());
+        assert_eq!(reader.read_symbol()?, crate::Symbol::unknown_text());
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@
 
 use crate::result::illegal_operation;
 use crate::IonResult;
-use std::fmt::{Debug, Formatter};
+use core::fmt::{Debug, Formatter};
 
 /// Describes the state of a [`Thunk`].
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -18,7 +18,9 @@ pub enum ThunkState {
     Error,
 }
 
-type ThunkFn<'a, T> = Box<dyn FnMut() -> IonResult<T> + 'a>;
+// The closure must be `Send` so that a `Thunk` can be moved to another thread along with the
+// value that it will produce.
+type ThunkFn<'a, T> = Box<dyn FnMut() -> IonResult<T> + Send + 'a>;
 
 enum ThunkVal<'a, T> {
     Deferred(ThunkFn<'a, T>),
@@ -29,7 +31,7 @@ impl<'a, T> Debug for ThunkVal<'a, T>
 where
     T: Debug,
 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         use ThunkVal::*;
         match self {
             Deferred(_) => write!(f, "ThunkVal::Deferred(...)"),
@@ -60,7 +62,7 @@ impl<'a, T> Thunk<'a, T> {
     #[inline]
     pub fn defer<F>(closure: F) -> Thunk<'a, T>
     where
-        F: FnMut() -> IonResult<T> + 'a,
+        F: FnMut() -> IonResult<T> + Send + 'a,
     {
         Thunk(Ok(ThunkVal::Deferred(Box::new(closure))))
     }
@@ -117,7 +119,7 @@ impl<'a, T> Thunk<'a, T> {
     pub fn remove(&mut self) -> IonResult<T> {
         use ThunkVal::*;
         // move out the current value
-        let thunk_res = std::mem::replace(&mut self.0, illegal_operation(EMPTY_THUNK_ERROR_TEXT));
+        let thunk_res = core::mem::replace(&mut self.0, illegal_operation(EMPTY_THUNK_ERROR_TEXT));
         // attempt to evaluate (if possible/needed)
         match thunk_res {
             Ok(Deferred(mut func)) => func(),
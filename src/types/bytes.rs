@@ -1,5 +1,7 @@
 use crate::ion_data::{IonEq, IonOrd};
-use std::cmp::Ordering;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 /// An owned, immutable byte array.
 /// ```rust
@@ -20,7 +22,9 @@ use std::cmp::Ordering;
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
 pub struct Bytes {
-    data: Vec<u8>,
+    // Stored as a boxed slice rather than a `Vec` to save a word of space by not tracking spare
+    // capacity.
+    data: Box<[u8]>,
 }
 
 impl IonEq for Bytes {
@@ -43,22 +47,28 @@ impl PartialEq<[u8]> for Bytes {
 
 impl From<Vec<u8>> for Bytes {
     fn from(data: Vec<u8>) -> Self {
-        Bytes { data }
+        Bytes {
+            data: data.into_boxed_slice(),
+        }
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(value: Bytes) -> Self {
+        value.data.into_vec()
     }
 }
 
 impl From<&[u8]> for Bytes {
     fn from(data: &[u8]) -> Self {
-        Bytes {
-            data: data.to_vec(),
-        }
+        Bytes { data: data.into() }
     }
 }
 
 impl<const N: usize> From<&[u8; N]> for Bytes {
     fn from(data: &[u8; N]) -> Self {
         Bytes {
-            data: data.to_vec(),
+            data: data.as_slice().into(),
         }
     }
 }
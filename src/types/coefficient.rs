@@ -3,9 +3,9 @@ use num_traits::Zero;
 
 use crate::result::{illegal_operation, IonError};
 use crate::types::UInt;
-use std::convert::TryFrom;
-use std::fmt::{Display, Formatter};
-use std::ops::{MulAssign, Neg};
+use core::convert::TryFrom;
+use core::fmt::{Display, Formatter};
+use core::ops::{MulAssign, Neg};
 
 /// Indicates whether the Coefficient's magnitude is less than 0 (negative) or not (positive).
 /// When the magnitude is zero, the Sign can be used to distinguish between -0 and 0.
@@ -54,29 +54,17 @@ impl Coefficient {
 
     /// Returns true if the Coefficient represents positive zero.
     pub(crate) fn is_negative_zero(&self) -> bool {
-        match (self.sign, &self.magnitude) {
-            (Sign::Negative, UInt::U64(0)) => true,
-            (Sign::Negative, UInt::BigUInt(b)) if b.is_zero() => true,
-            _ => false,
-        }
+        self.sign == Sign::Negative && self.magnitude.is_zero()
     }
 
     /// Returns true if the Coefficient represents positive zero.
     pub(crate) fn is_positive_zero(&self) -> bool {
-        match (self.sign, &self.magnitude) {
-            (Sign::Positive, UInt::U64(0)) => true,
-            (Sign::Positive, UInt::BigUInt(b)) if b.is_zero() => true,
-            _ => false,
-        }
+        self.sign == Sign::Positive && self.magnitude.is_zero()
     }
 
     /// Returns true if the Coefficient represents a zero of any sign.
     pub(crate) fn is_zero(&self) -> bool {
-        match (self.sign, &self.magnitude) {
-            (_, UInt::U64(0)) => true,
-            (_, UInt::BigUInt(b)) if b.is_zero() => true,
-            _ => false,
-        }
+        self.magnitude.is_zero()
     }
 
     /// If the value can fit in an i64, return it as such. This is useful for
@@ -90,7 +78,7 @@ impl Coefficient {
                 },
                 Err(_) => None,
             },
-            UInt::BigUInt(_) => None,
+            UInt::U128(_) | UInt::BigUInt(_) => None,
         }
     }
 }
@@ -132,6 +120,7 @@ impl TryFrom<Coefficient> for BigInt {
         }
         let mut big_int: BigInt = match value.magnitude {
             UInt::U64(m) => m.into(),
+            UInt::U128(m) => m.into(),
             UInt::BigUInt(m) => m.into(),
         };
         if value.sign == Sign::Negative {
@@ -142,13 +131,14 @@ impl TryFrom<Coefficient> for BigInt {
 }
 
 impl Display for Coefficient {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self.sign {
             Sign::Positive => {}
             Sign::Negative => write!(f, "-")?,
         };
         match &self.magnitude {
             UInt::U64(m) => write!(f, "{}", *m),
+            UInt::U128(m) => write!(f, "{}", *m),
             UInt::BigUInt(m) => write!(f, "{m}"),
         }
     }
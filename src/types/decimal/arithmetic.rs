@@ -0,0 +1,862 @@
+//! Arithmetic and rounding for [`Decimal`] values.
+//!
+//! The semantics follow the General Decimal Arithmetic specification (which is also the basis
+//! for Ion's decimal type):
+//! * The exponent of a sum or difference is the smaller of the operands' exponents; the exponent
+//!   of a product is the sum of the operands' exponents. Trailing zeros are never discarded, so
+//!   `1.0 + 1.00` is `2.00`.
+//! * A zero result carries a sign. The sum of two negative zeros is `-0`; any other sum that is
+//!   exactly zero is `0` unless the rounding mode is [`RoundingMode::Floor`]. The sign of a
+//!   product or quotient is negative if exactly one operand is negative, even if it is zero.
+//! * Operations performed with a [`DecimalContext`] round their result to the context's
+//!   precision using the context's [`RoundingMode`].
+//! * A result whose coefficient would have more than 1,000,000 digits cannot be represented. This
+//!   keeps operands with distant exponents (like `1` and `1E-1000000000`) from causing an exact
+//!   operation to allocate an arbitrarily large amount of memory. Methods whose names begin with
+//!   `checked_` return `None` for such results; the others panic.
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+use crate::result::{illegal_operation, illegal_operation_raw, IonResult};
+use crate::types::{Coefficient, Decimal, Sign};
+
+/// Determines which value is chosen when a [`Decimal`] has more digits than can be kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Discards the extra digits, rounding toward zero.
+    Down,
+    /// Rounds away from zero if any of the discarded digits are non-zero.
+    Up,
+    /// Rounds toward positive infinity.
+    Ceiling,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds to the nearest value; ties are rounded away from zero.
+    HalfUp,
+    /// Rounds to the nearest value; ties are rounded toward zero.
+    HalfDown,
+    /// Rounds to the nearest value; ties are rounded to the value with an even final digit.
+    /// This is sometimes called "banker's rounding".
+    #[default]
+    HalfEven,
+}
+
+/// The precision and rounding mode to use for [`Decimal`] arithmetic.
+///
+/// A context with a precision of `0` performs exact arithmetic; its rounding mode is only used
+/// when a value is explicitly rounded. Division requires a limited precision unless the quotient
+/// can be represented exactly.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::types::{DecimalContext, RoundingMode};
+/// use ion_rs::Decimal;
+/// let context = DecimalContext::new(5, RoundingMode::HalfEven);
+/// let one = Decimal::new(1, 0);
+/// let three = Decimal::new(3, 0);
+/// assert_eq!(context.div(&one, &three)?, Decimal::new(33333, -5));
+/// assert_eq!(context.mul(&Decimal::new(12345, -2), &three), Decimal::new(37035, -2));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecimalContext {
+    precision: u64,
+    rounding_mode: RoundingMode,
+}
+
+impl DecimalContext {
+    /// Constructs a context whose results have at most `precision` digits in their coefficient.
+    /// A `precision` of zero means that results are not rounded.
+    pub fn new(precision: u64, rounding_mode: RoundingMode) -> DecimalContext {
+        DecimalContext {
+            precision,
+            rounding_mode,
+        }
+    }
+
+    /// Constructs a context that performs exact arithmetic and rounds half-even when asked to
+    /// round explicitly.
+    pub fn unlimited() -> DecimalContext {
+        DecimalContext::new(0, RoundingMode::HalfEven)
+    }
+
+    /// The context for IEEE 754 `decimal32` values: 7 digits, rounding half-even.
+    pub fn decimal32() -> DecimalContext {
+        DecimalContext::new(7, RoundingMode::HalfEven)
+    }
+
+    /// The context for IEEE 754 `decimal64` values: 16 digits, rounding half-even.
+    pub fn decimal64() -> DecimalContext {
+        DecimalContext::new(16, RoundingMode::HalfEven)
+    }
+
+    /// The context for IEEE 754 `decimal128` values: 34 digits, rounding half-even.
+    pub fn decimal128() -> DecimalContext {
+        DecimalContext::new(34, RoundingMode::HalfEven)
+    }
+
+    /// Returns a copy of this context that uses the specified rounding mode.
+    pub fn with_rounding_mode(self, rounding_mode: RoundingMode) -> DecimalContext {
+        DecimalContext {
+            rounding_mode,
+            ..self
+        }
+    }
+
+    /// Returns the maximum number of digits in a result's coefficient, or zero if results are
+    /// not rounded.
+    pub fn precision(&self) -> u64 {
+        self.precision
+    }
+
+    pub fn rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    /// Rounds `value` to this context's precision.
+    pub fn round(&self, value: &Decimal) -> Decimal {
+        self.checked_round(value)
+            .unwrap_or_else(|| exponent_overflow("round"))
+    }
+
+    /// Returns `a + b`, rounded to this context's precision.
+    pub fn add(&self, a: &Decimal, b: &Decimal) -> Decimal {
+        self.checked_add(a, b)
+            .unwrap_or_else(|| exponent_overflow("add"))
+    }
+
+    /// Returns `a - b`, rounded to this context's precision.
+    pub fn sub(&self, a: &Decimal, b: &Decimal) -> Decimal {
+        self.checked_sub(a, b)
+            .unwrap_or_else(|| exponent_overflow("subtract"))
+    }
+
+    /// Returns `a * b`, rounded to this context's precision.
+    pub fn mul(&self, a: &Decimal, b: &Decimal) -> Decimal {
+        self.checked_mul(a, b)
+            .unwrap_or_else(|| exponent_overflow("multiply"))
+    }
+
+    /// Returns `a / b`, rounded to this context's precision.
+    ///
+    /// If the quotient is exact, it is given the exponent `a.exponent - b.exponent` if its
+    /// coefficient allows; otherwise, it has as many digits as the precision allows. Returns an
+    /// error if `b` is zero, if the context's precision is unlimited and the quotient cannot be
+    /// represented exactly (as with `1/3`), or if the result cannot be represented.
+    pub fn div(&self, a: &Decimal, b: &Decimal) -> IonResult<Decimal> {
+        if b.is_zero() {
+            return illegal_operation("Cannot divide a Decimal by zero.");
+        }
+        let quotient = divide(Parts::from(a), Parts::from(b), self)?;
+        Ok(quotient.into())
+    }
+
+    /// Rounds `value` to this context's precision. Returns `None` if the result's exponent
+    /// cannot be represented.
+    pub fn checked_round(&self, value: &Decimal) -> Option<Decimal> {
+        Some(self.round_parts(Parts::from(value))?.into())
+    }
+
+    /// Returns `a + b`, rounded to this context's precision. Returns `None` if the result
+    /// cannot be represented.
+    pub fn checked_add(&self, a: &Decimal, b: &Decimal) -> Option<Decimal> {
+        Some(add(Parts::from(a), Parts::from(b), self)?.into())
+    }
+
+    /// Returns `a - b`, rounded to this context's precision. Returns `None` if the result
+    /// cannot be represented.
+    pub fn checked_sub(&self, a: &Decimal, b: &Decimal) -> Option<Decimal> {
+        Some(add(Parts::from(a), Parts::from(b).negate(), self)?.into())
+    }
+
+    /// Returns `a * b`, rounded to this context's precision. Returns `None` if the result
+    /// cannot be represented.
+    pub fn checked_mul(&self, a: &Decimal, b: &Decimal) -> Option<Decimal> {
+        Some(multiply(Parts::from(a), Parts::from(b), self)?.into())
+    }
+
+    fn round_parts(&self, parts: Parts) -> Option<Parts> {
+        if self.precision == 0 {
+            return Some(parts);
+        }
+        let digits = decimal_digits(&parts.magnitude);
+        if digits <= self.precision {
+            return Some(parts);
+        }
+        let excess_digits = digits - self.precision;
+        let exponent = parts
+            .exponent
+            .checked_add(i64::try_from(excess_digits).ok()?)?;
+        let rounded = Parts {
+            magnitude: round_magnitude(&parts, excess_digits, self.rounding_mode),
+            exponent,
+            ..parts
+        };
+        // Rounding up may have carried into a new digit (for example, 999 to 1000). The new
+        // trailing digit is always a zero and can be discarded.
+        if decimal_digits(&rounded.magnitude) > self.precision {
+            return Some(Parts {
+                magnitude: rounded.magnitude / 10u32,
+                exponent: rounded.exponent.checked_add(1)?,
+                ..rounded
+            });
+        }
+        Some(rounded)
+    }
+}
+
+impl Default for DecimalContext {
+    fn default() -> Self {
+        DecimalContext::unlimited()
+    }
+}
+
+impl Decimal {
+    /// Returns `self + other`, or `None` if the result cannot be represented. The sum is exact;
+    /// see [`DecimalContext`] for arithmetic with limited precision.
+    pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        DecimalContext::unlimited().checked_add(self, other)
+    }
+
+    /// Returns `self - other`, or `None` if the result cannot be represented. The difference is
+    /// exact; see [`DecimalContext`] for arithmetic with limited precision.
+    pub fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+        DecimalContext::unlimited().checked_sub(self, other)
+    }
+
+    /// Returns `self * other`, or `None` if the result's exponent cannot be represented. The
+    /// product is exact; see [`DecimalContext`] for arithmetic with limited precision.
+    pub fn checked_mul(&self, other: &Decimal) -> Option<Decimal> {
+        DecimalContext::unlimited().checked_mul(self, other)
+    }
+
+    /// Returns `self / other` if the quotient can be represented exactly. Returns `None` if
+    /// `other` is zero or the quotient does not terminate (as with `1/3`); use
+    /// [`DecimalContext::div`] to compute a rounded quotient instead.
+    pub fn checked_div(&self, other: &Decimal) -> Option<Decimal> {
+        DecimalContext::unlimited().div(self, other).ok()
+    }
+
+    /// Returns the absolute value of `self`: a non-negative Decimal with the same coefficient
+    /// magnitude and exponent. The absolute value of a negative zero is a positive zero.
+    pub fn abs(&self) -> Decimal {
+        Decimal::new(
+            Coefficient::new(Sign::Positive, self.coefficient.magnitude().clone()),
+            self.exponent,
+        )
+    }
+
+    /// Returns an equivalent Decimal with the specified exponent, rounding if `exponent` is
+    /// greater than the current one.
+    ///
+    /// ```
+    /// use ion_rs::types::RoundingMode;
+    /// use ion_rs::Decimal;
+    /// let price = Decimal::new(10455, -3); // 10.455
+    /// assert_eq!(price.rescale(-2, RoundingMode::HalfEven), Decimal::new(1046, -2));
+    /// assert_eq!(price.rescale(-2, RoundingMode::Down), Decimal::new(1045, -2));
+    /// // The sign of a value that rounds to zero is preserved
+    /// let tiny = Decimal::new(-1, -3);
+    /// assert_eq!(tiny.rescale(0, RoundingMode::HalfEven), Decimal::negative_zero());
+    /// ```
+    pub fn rescale(&self, exponent: i64, rounding_mode: RoundingMode) -> Decimal {
+        self.checked_rescale(exponent, rounding_mode)
+            .unwrap_or_else(|| exponent_overflow("rescale"))
+    }
+
+    /// Like [`rescale`](Self::rescale), but returns `None` if the coefficient of the result
+    /// would be too large to represent.
+    pub fn checked_rescale(&self, exponent: i64, rounding_mode: RoundingMode) -> Option<Decimal> {
+        Some(rescale(Parts::from(self), exponent, rounding_mode)?.into())
+    }
+
+    /// Returns an equivalent Decimal with the same exponent as `other`, rounding if necessary.
+    /// This is commonly used to round a value to the same number of decimal places as a
+    /// template value, for example `0.01`.
+    pub fn quantize(&self, other: &Decimal, rounding_mode: RoundingMode) -> Decimal {
+        self.rescale(other.exponent, rounding_mode)
+    }
+}
+
+fn exponent_overflow(operation: &str) -> ! {
+    panic!("attempted to {operation} Decimals with a result that cannot be represented")
+}
+
+macro_rules! impl_decimal_binary_operator {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl $trait<&Decimal> for &Decimal {
+            type Output = Decimal;
+
+            fn $method(self, other: &Decimal) -> Decimal {
+                self.$checked(other)
+                    .unwrap_or_else(|| exponent_overflow(stringify!($method)))
+            }
+        }
+
+        impl $trait<Decimal> for Decimal {
+            type Output = Decimal;
+
+            fn $method(self, other: Decimal) -> Decimal {
+                (&self).$method(&other)
+            }
+        }
+    };
+}
+
+impl_decimal_binary_operator!(Add, add, checked_add);
+impl_decimal_binary_operator!(Sub, sub, checked_sub);
+impl_decimal_binary_operator!(Mul, mul, checked_mul);
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    /// Flips the sign of the Decimal, including the sign of a zero.
+    fn neg(self) -> Decimal {
+        Parts::from(&self).negate().into()
+    }
+}
+
+impl Neg for &Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        Parts::from(self).negate().into()
+    }
+}
+
+// The components of a Decimal in a form that is convenient for arithmetic.
+#[derive(Debug, Clone)]
+struct Parts {
+    negative: bool,
+    magnitude: BigUint,
+    exponent: i64,
+}
+
+impl Parts {
+    fn negate(self) -> Parts {
+        Parts {
+            negative: !self.negative,
+            ..self
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+}
+
+impl From<&Decimal> for Parts {
+    fn from(value: &Decimal) -> Self {
+        Parts {
+            negative: value.coefficient.sign() == Sign::Negative,
+            magnitude: value.coefficient.magnitude().clone().into(),
+            exponent: value.exponent,
+        }
+    }
+}
+
+impl From<Parts> for Decimal {
+    fn from(parts: Parts) -> Self {
+        let sign = if parts.negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        Decimal::new(Coefficient::new(sign, parts.magnitude), parts.exponent)
+    }
+}
+
+/// The largest number of digits that a coefficient computed by this module may have.
+const MAX_COEFFICIENT_DIGITS: u64 = 1_000_000;
+
+/// Returns 10 raised to the specified power, or `None` if the result would have more than
+/// [`MAX_COEFFICIENT_DIGITS`] digits.
+fn power_of_ten(exponent: u64) -> Option<BigUint> {
+    if exponent >= MAX_COEFFICIENT_DIGITS {
+        return None;
+    }
+    Some(pow10(exponent as u32))
+}
+
+/// Returns 10 raised to the specified power. Callers must ensure that the result is no larger
+/// than a value they already hold.
+fn pow10(exponent: u32) -> BigUint {
+    BigUint::from(10u32).pow(exponent)
+}
+
+/// Returns the number of digits in the base-10 representation of `magnitude`.
+fn decimal_digits(magnitude: &BigUint) -> u64 {
+    if magnitude.is_zero() {
+        return 1;
+    }
+    // log10(2) is slightly more than 0.30103, so this estimate is either exact or one too small.
+    let estimate = ((magnitude.bits() - 1) as f64 * core::f64::consts::LOG10_2) as u64 + 1;
+    // `10^estimate` has no more digits than `magnitude`.
+    match u32::try_from(estimate) {
+        Ok(power) if *magnitude >= pow10(power) => estimate + 1,
+        _ => estimate,
+    }
+}
+
+/// Returns `magnitude * 10^shift`, or `None` if the result would have more than
+/// [`MAX_COEFFICIENT_DIGITS`] digits.
+fn shift_left(magnitude: &BigUint, shift: u64) -> Option<BigUint> {
+    if magnitude.is_zero() || shift == 0 {
+        return Some(magnitude.clone());
+    }
+    // Check the size of the result before allocating it.
+    if decimal_digits(magnitude).checked_add(shift)? > MAX_COEFFICIENT_DIGITS {
+        return None;
+    }
+    Some(magnitude * power_of_ten(shift)?)
+}
+
+// How the discarded digits of a rounded value compare to half of a unit in the last place that
+// is kept.
+enum Remainder {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+/// Discards the lowest `digits` digits of `parts.magnitude`, rounding according to
+/// `rounding_mode`.
+fn round_magnitude(parts: &Parts, digits: u64, rounding_mode: RoundingMode) -> BigUint {
+    let (quotient, remainder) = if digits > decimal_digits(&parts.magnitude) {
+        // Every digit is discarded and the magnitude is less than a tenth of the last place.
+        let remainder = if parts.is_zero() {
+            Remainder::Zero
+        } else {
+            Remainder::BelowHalf
+        };
+        (BigUint::zero(), remainder)
+    } else {
+        // `digits` is no larger than the number of digits in the magnitude, so the divisor is no
+        // larger than the magnitude.
+        let divisor = pow10(u32::try_from(digits).expect("magnitude has too many digits"));
+        let (quotient, remainder) = parts.magnitude.div_rem(&divisor);
+        let remainder = if remainder.is_zero() {
+            Remainder::Zero
+        } else {
+            match (remainder * 2u32).cmp(&divisor) {
+                Ordering::Less => Remainder::BelowHalf,
+                Ordering::Equal => Remainder::Half,
+                Ordering::Greater => Remainder::AboveHalf,
+            }
+        };
+        (quotient, remainder)
+    };
+    let round_away_from_zero = match (remainder, rounding_mode) {
+        (Remainder::Zero, _) => false,
+        (_, RoundingMode::Down) => false,
+        (_, RoundingMode::Up) => true,
+        (_, RoundingMode::Ceiling) => !parts.negative,
+        (_, RoundingMode::Floor) => parts.negative,
+        (Remainder::BelowHalf, _) => false,
+        (Remainder::AboveHalf, _) => true,
+        (Remainder::Half, RoundingMode::HalfUp) => true,
+        (Remainder::Half, RoundingMode::HalfDown) => false,
+        (Remainder::Half, RoundingMode::HalfEven) => quotient.is_odd(),
+    };
+    if round_away_from_zero {
+        quotient + 1u32
+    } else {
+        quotient
+    }
+}
+
+fn rescale(parts: Parts, exponent: i64, rounding_mode: RoundingMode) -> Option<Parts> {
+    let delta = exponent as i128 - parts.exponent as i128;
+    let magnitude = match delta.cmp(&0) {
+        Ordering::Equal => return Some(parts),
+        Ordering::Less => shift_left(&parts.magnitude, u64::try_from(-delta).ok()?)?,
+        // A delta larger than u64::MAX discards every digit, just like u64::MAX would.
+        Ordering::Greater => round_magnitude(
+            &parts,
+            u64::try_from(delta).unwrap_or(u64::MAX),
+            rounding_mode,
+        ),
+    };
+    Some(Parts {
+        magnitude,
+        exponent,
+        ..parts
+    })
+}
+
+fn add(a: Parts, b: Parts, context: &DecimalContext) -> Option<Parts> {
+    // Make `a` the operand with the larger exponent.
+    let (a, mut b) = if a.exponent >= b.exponent {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    if context.precision > 0 && !a.is_zero() {
+        b = trim_insignificant_operand(&a, b, context.precision);
+    }
+
+    let shift = u64::try_from(a.exponent as i128 - b.exponent as i128).ok()?;
+    let a_magnitude = shift_left(&a.magnitude, shift)?;
+    let (negative, magnitude) = if a.negative == b.negative {
+        (a.negative, a_magnitude + b.magnitude)
+    } else {
+        match a_magnitude.cmp(&b.magnitude) {
+            Ordering::Greater => (a.negative, a_magnitude - b.magnitude),
+            Ordering::Less => (b.negative, b.magnitude - a_magnitude),
+            // The operands cancel out. The sum is `0` unless the context rounds toward negative
+            // infinity, in which case it is `-0`.
+            Ordering::Equal => (
+                context.rounding_mode == RoundingMode::Floor,
+                BigUint::zero(),
+            ),
+        }
+    };
+    context.round_parts(Parts {
+        negative,
+        magnitude,
+        exponent: b.exponent,
+    })
+}
+
+/// When adding with a limited precision, an operand that is far smaller than the other (`a`)
+/// only affects the result through its sign and the fact that it isn't zero. Replacing it with
+/// a small stand-in avoids scaling `a` by an arbitrarily large power of ten. `a` must have the
+/// larger exponent and must not be zero.
+fn trim_insignificant_operand(a: &Parts, b: Parts, precision: u64) -> Parts {
+    let a_digits = decimal_digits(&a.magnitude) as i128;
+    let precision = precision as i128;
+    let a_exponent = a.exponent as i128;
+    if b.is_zero() {
+        // Adding a zero can only lower the exponent of the sum, but rounding the sum will then
+        // raise it to at least this value.
+        let floor = a_exponent.min(a_exponent + a_digits - precision);
+        return Parts {
+            exponent: (b.exponent as i128).max(floor) as i64,
+            ..b
+        };
+    }
+    // The sum's leading digit is no lower than `a_exponent + a_digits - 2`, so rounding it
+    // discards every digit below `a_exponent + a_digits - precision - 1`. If all of `b` is at
+    // least one digit below both that point and `a`'s last digit, any value of `b` with the same
+    // sign rounds the same way.
+    let threshold = a_exponent.min(a_exponent + a_digits - precision - 1) - 1;
+    let b_end = b.exponent as i128 + decimal_digits(&b.magnitude) as i128;
+    if b_end > threshold {
+        return b;
+    }
+    Parts {
+        magnitude: BigUint::one(),
+        exponent: (threshold - 1) as i64,
+        ..b
+    }
+}
+
+fn multiply(a: Parts, b: Parts, context: &DecimalContext) -> Option<Parts> {
+    context.round_parts(Parts {
+        negative: a.negative != b.negative,
+        magnitude: a.magnitude * b.magnitude,
+        exponent: a.exponent.checked_add(b.exponent)?,
+    })
+}
+
+/// Divides `a` by `b`, which must not be zero.
+fn divide(a: Parts, b: Parts, context: &DecimalContext) -> IonResult<Parts> {
+    let overflow = || illegal_operation_raw("The exponent of the quotient is out of range.");
+    let too_many_digits =
+        || illegal_operation_raw("The quotient requires too many digits to compute.");
+    let negative = a.negative != b.negative;
+    let ideal_exponent = a.exponent.checked_sub(b.exponent).ok_or_else(overflow)?;
+    if a.is_zero() {
+        return Ok(Parts {
+            negative,
+            magnitude: BigUint::zero(),
+            exponent: ideal_exponent,
+        });
+    }
+
+    if context.precision == 0 {
+        // The quotient terminates if the divisor has no prime factors other than 2 and 5 once
+        // the fraction is reduced. Scaling the dividend by 10^shift, where `shift` is the larger
+        // of the two factors' multiplicities, makes the division exact.
+        let divisor = &b.magnitude / a.magnitude.gcd(&b.magnitude);
+        let (divisor, twos) = remove_factor(divisor, 2);
+        let (divisor, fives) = remove_factor(divisor, 5);
+        if !divisor.is_one() {
+            return illegal_operation(
+                "The quotient cannot be represented exactly; divide with a limited precision.",
+            );
+        }
+        let shift = twos.max(fives);
+        let dividend = shift_left(&a.magnitude, shift).ok_or_else(too_many_digits)?;
+        return Ok(Parts {
+            negative,
+            magnitude: dividend / b.magnitude,
+            exponent: subtract_shift(ideal_exponent, shift).ok_or_else(overflow)?,
+        });
+    }
+
+    // Scale the dividend so that the quotient has at least one more digit than the precision.
+    let a_digits = decimal_digits(&a.magnitude);
+    let b_digits = decimal_digits(&b.magnitude);
+    let shift = context
+        .precision
+        .saturating_add(b_digits + 1)
+        .saturating_sub(a_digits);
+    let dividend = shift_left(&a.magnitude, shift).ok_or_else(too_many_digits)?;
+    let (mut quotient, remainder) = dividend.div_rem(&b.magnitude);
+    let mut exponent = subtract_shift(ideal_exponent, shift).ok_or_else(overflow)?;
+    if remainder.is_zero() {
+        // The quotient is exact. Remove trailing zeros until it reaches the ideal exponent.
+        while exponent < ideal_exponent {
+            let (reduced, digit) = quotient.div_rem(&BigUint::from(10u32));
+            if !digit.is_zero() {
+                break;
+            }
+            quotient = reduced;
+            exponent += 1;
+        }
+    } else {
+        // Append a non-zero digit so that rounding knows the discarded digits were not zero.
+        quotient = quotient * 10u32 + 1u32;
+        exponent = exponent.checked_sub(1).ok_or_else(overflow)?;
+    }
+    context
+        .round_parts(Parts {
+            negative,
+            magnitude: quotient,
+            exponent,
+        })
+        .ok_or_else(overflow)
+}
+
+fn remove_factor(mut value: BigUint, factor: u32) -> (BigUint, u64) {
+    let factor = BigUint::from(factor);
+    let mut count = 0;
+    loop {
+        let (quotient, remainder) = value.div_rem(&factor);
+        if !remainder.is_zero() {
+            return (value, count);
+        }
+        value = quotient;
+        count += 1;
+    }
+}
+
+fn subtract_shift(exponent: i64, shift: u64) -> Option<i64> {
+    exponent.checked_sub(i64::try_from(shift).ok()?)
+}
+
+#[cfg(test)]
+mod arithmetic_tests {
+    use super::*;
+    use crate::ion_data::IonEq;
+    use rstest::*;
+
+    fn d(coefficient: i64, exponent: i64) -> Decimal {
+        Decimal::new(coefficient, exponent)
+    }
+
+    fn neg_zero(exponent: i64) -> Decimal {
+        Decimal::negative_zero_with_exponent(exponent)
+    }
+
+    // Asserts that the decimals have the same coefficient, sign, and exponent.
+    fn assert_identical(actual: Decimal, expected: Decimal) {
+        assert!(
+            actual.ion_eq(&expected),
+            "expected {expected} ({expected:?}), found {actual} ({actual:?})"
+        );
+    }
+
+    #[rstest]
+    #[case::same_exponent(d(15, -1), d(25, -1), d(40, -1))]
+    #[case::keeps_smaller_exponent(d(1, 0), d(100, -2), d(200, -2))]
+    #[case::negative_result(d(1, 0), d(-25, -1), d(-15, -1))]
+    #[case::cancellation(d(5, -1), d(-50, -2), d(0, -2))]
+    #[case::negative_zeros(neg_zero(0), neg_zero(-1), neg_zero(-1))]
+    #[case::mixed_zeros(neg_zero(0), d(0, 0), d(0, 0))]
+    #[case::negative_zero_and_value(neg_zero(-3), d(-2, 0), d(-2000, -3))]
+    #[case::big(d(i64::MAX, 0), d(i64::MAX, 0), Decimal::new(2 * i64::MAX as i128, 0))]
+    fn exact_addition(#[case] a: Decimal, #[case] b: Decimal, #[case] expected: Decimal) {
+        assert_identical(&a + &b, expected.clone());
+        assert_identical(b.clone() + a.clone(), expected.clone());
+        assert_eq!(&expected - &b, a);
+    }
+
+    #[rstest]
+    #[case::signs(d(-15, -1), d(2, 0), d(-30, -1))]
+    #[case::exponents(d(25, 3), d(4, -5), d(100, -2))]
+    #[case::negative_zero(neg_zero(-1), d(7, 0), neg_zero(-1))]
+    #[case::two_negatives(d(-3, 0), d(-3, 1), d(9, 1))]
+    fn exact_multiplication(#[case] a: Decimal, #[case] b: Decimal, #[case] expected: Decimal) {
+        assert_identical(a * b, expected);
+    }
+
+    #[test]
+    fn distant_exponents_are_not_materialized() {
+        let tiny = d(1, -1_000_000_000);
+        let huge = d(1, 1_000_000_000);
+        // The exact results would need a billion digits.
+        assert!(d(1, 0).checked_add(&tiny).is_none());
+        assert!(tiny.checked_add(&d(1, 0)).is_none());
+        assert!(d(1, 0).checked_sub(&tiny).is_none());
+        assert!(huge.checked_add(&d(1, 0)).is_none());
+        assert!(d(1, 0)
+            .checked_rescale(-1_000_000_000, RoundingMode::HalfEven)
+            .is_none());
+        assert!(d(1, 0)
+            .checked_rescale(i64::MIN, RoundingMode::HalfEven)
+            .is_none());
+        // A context whose precision exceeds the limit cannot compute them either.
+        let context = DecimalContext::new(u64::MAX, RoundingMode::HalfEven);
+        assert!(context.checked_add(&d(1, 0), &tiny).is_none());
+        assert!(context.div(&d(1, 0), &d(3, 0)).is_err());
+        // Results within the limit are computed.
+        let sum = d(1, 0).checked_add(&d(1, -1000)).unwrap();
+        assert_eq!(sum.exponent, -1000);
+        assert!(d(5, 0)
+            .checked_rescale(-1000, RoundingMode::HalfEven)
+            .is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn quantize_with_distant_exponent_panics() {
+        d(1, 0).quantize(&d(1, -1_000_000_000), RoundingMode::HalfEven);
+    }
+
+    #[test]
+    fn exact_operations_detect_overflow() {
+        assert!(d(1, i64::MAX).checked_mul(&d(1, 1)).is_none());
+        assert!(d(1, i64::MAX).checked_add(&d(1, i64::MIN)).is_none());
+        assert!(d(1, -3).checked_mul(&d(1, 3)).is_some());
+    }
+
+    #[rstest]
+    #[case::down(RoundingMode::Down, [2, 2, 2, -2, -2])]
+    #[case::up(RoundingMode::Up, [3, 3, 3, -3, -3])]
+    #[case::ceiling(RoundingMode::Ceiling, [3, 3, 3, -2, -2])]
+    #[case::floor(RoundingMode::Floor, [2, 2, 2, -3, -3])]
+    #[case::half_up(RoundingMode::HalfUp, [2, 3, 3, -3, -3])]
+    #[case::half_down(RoundingMode::HalfDown, [2, 2, 3, -2, -3])]
+    #[case::half_even(RoundingMode::HalfEven, [2, 2, 3, -2, -3])]
+    fn rounding_modes(#[case] mode: RoundingMode, #[case] expected: [i64; 5]) {
+        // 2.1, 2.5, 2.51, -2.5, -2.51
+        let inputs = [d(21, -1), d(25, -1), d(251, -2), d(-25, -1), d(-251, -2)];
+        for (input, expected) in inputs.iter().zip(expected) {
+            assert_identical(input.rescale(0, mode), d(expected, 0));
+        }
+    }
+
+    #[test]
+    fn half_even_rounds_ties_to_even_digit() {
+        assert_identical(d(35, -1).rescale(0, RoundingMode::HalfEven), d(4, 0));
+        assert_identical(d(45, -1).rescale(0, RoundingMode::HalfEven), d(4, 0));
+    }
+
+    #[rstest]
+    #[case::adds_zeros(d(15, -1), -3, d(1500, -3))]
+    #[case::rounds(d(15, -1), 0, d(2, 0))]
+    #[case::rounds_to_zero(d(4, -1), 0, d(0, 0))]
+    #[case::keeps_negative_zero(d(-4, -1), 0, neg_zero(0))]
+    #[case::far_exponent(d(9, -1), i64::MAX, d(0, i64::MAX))]
+    #[case::positive_exponent(d(1250, 0), 2, d(12, 2))]
+    fn rescaling(#[case] value: Decimal, #[case] exponent: i64, #[case] expected: Decimal) {
+        assert_identical(value.rescale(exponent, RoundingMode::HalfEven), expected);
+    }
+
+    #[test]
+    fn quantize_matches_template_exponent() {
+        let cents = d(1, -2);
+        assert_identical(
+            d(123456, -4).quantize(&cents, RoundingMode::HalfUp),
+            d(1235, -2),
+        );
+        assert_identical(d(7, 0).quantize(&cents, RoundingMode::HalfUp), d(700, -2));
+    }
+
+    #[rstest]
+    #[case::no_rounding_needed(d(12, 0), d(34, 0), d(46, 0))]
+    #[case::rounds_sum(d(99999, 0), d(1, 0), d(10000, 1))]
+    #[case::carry(d(999995, -6), d(0, 0), d(10000, -4))]
+    #[case::tiny_operand(d(1, 0), d(1, -1000000000), d(10000, -4))]
+    #[case::tiny_negative_operand(d(1, 0), d(-1, -1000000000), d(10000, -4))]
+    #[case::tiny_zero(d(1, 0), d(0, -1000000000), d(10000, -4))]
+    #[case::huge_operand(d(1, 1000000000), d(1, 0), d(10000, 999999996))]
+    fn context_addition(#[case] a: Decimal, #[case] b: Decimal, #[case] expected: Decimal) {
+        let context = DecimalContext::new(5, RoundingMode::HalfEven);
+        assert_identical(context.add(&a, &b), expected.clone());
+        assert_identical(context.add(&b, &a), expected);
+    }
+
+    #[test]
+    fn cancellation_sign_depends_on_rounding_mode() {
+        let context = DecimalContext::new(5, RoundingMode::HalfEven);
+        assert_identical(context.sub(&d(3, 0), &d(3, 0)), d(0, 0));
+        let floor = context.with_rounding_mode(RoundingMode::Floor);
+        assert_identical(floor.sub(&d(3, 0), &d(3, 0)), neg_zero(0));
+    }
+
+    #[test]
+    fn context_multiplication() {
+        let context = DecimalContext::new(3, RoundingMode::HalfUp);
+        assert_identical(context.mul(&d(125, -2), &d(125, -2)), d(156, -2));
+        assert_identical(context.mul(&d(-5, 0), &neg_zero(0)), d(0, 0));
+    }
+
+    #[rstest]
+    #[case::exact_ideal_exponent(d(100, 0), d(4, 0), d(25, 0))]
+    #[case::exact_fraction(d(1, 0), d(8, 0), d(125, -3))]
+    #[case::exact_keeps_trailing_zeros(d(2400, -2), d(2, 0), d(1200, -2))]
+    #[case::rounded(d(2, 0), d(3, 0), d(66667, -5))]
+    #[case::negative(d(-1, 0), d(3, 0), d(-33333, -5))]
+    #[case::negative_zero(neg_zero(0), d(3, 1), neg_zero(-1))]
+    #[case::ideal_exponent_from_operands(d(1, 2), d(5, -1), d(2, 2))]
+    fn context_division(#[case] a: Decimal, #[case] b: Decimal, #[case] expected: Decimal) {
+        let context = DecimalContext::new(5, RoundingMode::HalfEven);
+        assert_identical(context.div(&a, &b).unwrap(), expected);
+    }
+
+    #[test]
+    fn exact_division() {
+        assert_identical(d(1, 0).checked_div(&d(8, 0)).unwrap(), d(125, -3));
+        assert_identical(d(6, 0).checked_div(&d(-3, 0)).unwrap(), d(-2, 0));
+        assert!(d(1, 0).checked_div(&d(3, 0)).is_none());
+        assert!(d(1, 0).checked_div(&d(0, 0)).is_none());
+        assert!(DecimalContext::decimal64()
+            .div(&d(1, 0), &neg_zero(0))
+            .is_err());
+    }
+
+    #[test]
+    fn negation() {
+        assert_identical(-d(5, -1), d(-5, -1));
+        assert_identical(-d(0, 2), neg_zero(2));
+        assert_identical(-neg_zero(2), d(0, 2));
+        assert_identical(d(-5, -1).abs(), d(5, -1));
+        assert_identical(neg_zero(-2).abs(), d(0, -2));
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(9, 1)]
+    #[case(10, 2)]
+    #[case(999_999_999_999, 12)]
+    #[case(1_000_000_000_000, 13)]
+    #[case(u64::MAX, 20)]
+    fn counts_decimal_digits(#[case] value: u64, #[case] expected: u64) {
+        assert_eq!(decimal_digits(&BigUint::from(value)), expected);
+        let big = BigUint::from(value) * BigUint::from(10u32).pow(30);
+        let expected_big = if value == 0 { 1 } else { expected + 30 };
+        assert_eq!(decimal_digits(&big), expected_big);
+    }
+}
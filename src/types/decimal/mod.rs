@@ -1,15 +1,27 @@
-use std::cmp::Ordering;
+use alloc::string::ToString;
+use core::cmp::Ordering;
 
+#[cfg(feature = "bigdecimal")]
 use bigdecimal::{BigDecimal, Signed};
-use num_bigint::{BigInt, BigUint, ToBigUint};
+#[cfg(feature = "bigdecimal")]
+use num_bigint::BigInt;
+use num_bigint::{BigUint, ToBigUint};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::float::FloatCore;
 
 use crate::ion_data::{IonEq, IonOrd};
 use crate::result::{illegal_operation, IonError};
 use crate::types::{Coefficient, Sign, UInt};
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Display, Formatter};
+use core::ops::Neg;
 use num_traits::Zero;
-use std::convert::{TryFrom, TryInto};
-use std::fmt::{Display, Formatter};
-use std::ops::Neg;
+
+mod arithmetic;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal_conversions;
+
+pub use arithmetic::{DecimalContext, RoundingMode};
 
 /// An arbitrary-precision Decimal type with a distinct representation of negative zero (`-0`).
 #[derive(Clone, Debug)]
@@ -65,31 +77,21 @@ impl Decimal {
 
     /// Returns `true` if this Decimal is a zero of any sign or exponent.
     pub fn is_zero(&self) -> bool {
-        match self.coefficient.magnitude() {
-            UInt::U64(0) => true,
-            UInt::BigUInt(m) => m.is_zero(),
-            _ => false,
-        }
+        self.coefficient.magnitude().is_zero()
     }
 
     /// Returns true if this Decimal's coefficient has a negative sign AND a magnitude greater than
     /// zero. Otherwise, returns false. (Negative zero returns false.)
     pub fn is_less_than_zero(&self) -> bool {
-        match (self.coefficient.sign(), self.coefficient.magnitude()) {
-            (Sign::Negative, UInt::U64(m)) if *m > 0 => true,
-            (Sign::Negative, UInt::BigUInt(m)) if m > &BigUint::zero() => true,
-            _ => false,
-        }
+        self.coefficient.sign() == Sign::Negative && !self.coefficient.magnitude().is_zero()
     }
 
     /// Semantically identical to `self >= Decimal::new(1, 0)`, but much cheaper to compute.
     pub(crate) fn is_greater_than_or_equal_to_one(&self) -> bool {
         // If the coefficient has a magnitude of zero, the Decimal is a zero of some precision
         // and so is not >= 1.
-        match &self.coefficient.magnitude {
-            UInt::U64(magnitude) if magnitude.is_zero() => return false,
-            UInt::BigUInt(magnitude) if magnitude.is_zero() => return false,
-            _ => {}
+        if self.coefficient.magnitude.is_zero() {
+            return false;
         }
 
         // If the coefficient is non-zero, look at the exponent. A positive exponent means the
@@ -317,7 +319,7 @@ impl TryFrom<f64> for Decimal {
 
 impl Display for Decimal {
     #[rustfmt::skip] // https://github.com/rust-lang/rustfmt/issues/3255
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         // Inspired by the formatting conventions of Java's BigDecimal.toString()
         const WIDE_NUMBER: usize = 6; // if you think about it, six is a lot 🙃
 
@@ -353,6 +355,7 @@ impl Display for Decimal {
 }
 
 /// Make a Decimal from a BigDecimal. This is a lossless operation.
+#[cfg(feature = "bigdecimal")]
 impl From<BigDecimal> for Decimal {
     fn from(value: BigDecimal) -> Self {
         let sign = if value.sign() == num_bigint::Sign::Minus {
@@ -371,6 +374,7 @@ impl From<BigDecimal> for Decimal {
     }
 }
 
+#[cfg(feature = "bigdecimal")]
 impl TryFrom<Decimal> for BigDecimal {
     type Error = IonError;
     /// Attempts to create a BigDecimal from a Decimal. Returns an Error if the Decimal being
@@ -386,9 +390,12 @@ impl TryFrom<Decimal> for BigDecimal {
 mod decimal_tests {
     use crate::result::IonResult;
     use crate::types::{Coefficient, Decimal, Sign};
+    #[cfg(feature = "std")]
     use bigdecimal::BigDecimal;
     use num_bigint::BigUint;
-    use num_traits::{Float, ToPrimitive};
+    use num_traits::Float;
+    #[cfg(feature = "std")]
+    use num_traits::ToPrimitive;
     use std::cmp::Ordering;
     use std::convert::TryInto;
     use std::fmt::Write;
@@ -523,6 +530,7 @@ mod decimal_tests {
         assert!(conversion_result.is_err());
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_convert_to_big_decimal() {
         let decimal = Decimal::new(-24601, -3);
@@ -545,6 +553,7 @@ mod decimal_tests {
         assert!(conversion_result.is_err());
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_convert_from_big_decimal() {
         let big_decimal: BigDecimal = BigDecimal::new((-24601).into(), 3);
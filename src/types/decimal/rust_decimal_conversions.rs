@@ -0,0 +1,131 @@
+//! Conversions between [`Decimal`] and [`rust_decimal::Decimal`]. These are only available when
+//! the `rust_decimal` feature is enabled.
+
+use crate::result::{illegal_operation_raw, IonError};
+use crate::types::{Coefficient, Decimal, Sign};
+
+// A rust_decimal::Decimal stores a 96-bit coefficient with a scale of at most 28.
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+const MAX_SCALE: u64 = 28;
+
+/// Make a Decimal from a rust_decimal::Decimal. This is a lossless operation; negative zero and
+/// the number of digits after the decimal point are preserved.
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        let sign = if value.is_sign_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        let magnitude = value.mantissa().unsigned_abs();
+        Decimal::new(Coefficient::new(sign, magnitude), -i64::from(value.scale()))
+    }
+}
+
+impl TryFrom<Decimal> for rust_decimal::Decimal {
+    type Error = IonError;
+
+    /// Attempts to create a rust_decimal::Decimal that is numerically equal to the Decimal.
+    /// A rust_decimal::Decimal cannot have a positive exponent, so values like `1d2` are
+    /// converted to `100`; trailing zeros beyond its maximum scale of 28 are likewise dropped.
+    /// Returns an Error if the value cannot be represented without rounding.
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        let out_of_range = || {
+            illegal_operation_raw(format!(
+                "{value} cannot be represented as a rust_decimal::Decimal without rounding"
+            ))
+        };
+        let mut mantissa = value
+            .coefficient
+            .magnitude()
+            .as_u128()
+            .ok_or_else(out_of_range)?;
+        let mut scale = if value.exponent > 0 {
+            if mantissa != 0 {
+                let factor = u32::try_from(value.exponent)
+                    .ok()
+                    .and_then(|exponent| 10u128.checked_pow(exponent));
+                mantissa = factor
+                    .and_then(|factor| mantissa.checked_mul(factor))
+                    .ok_or_else(out_of_range)?;
+            }
+            0
+        } else {
+            value.exponent.unsigned_abs()
+        };
+        if mantissa == 0 {
+            scale = scale.min(MAX_SCALE);
+        }
+        while scale > MAX_SCALE && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        if mantissa > MAX_MANTISSA || scale > MAX_SCALE {
+            return Err(out_of_range());
+        }
+        let mut converted = rust_decimal::Decimal::from_parts(
+            mantissa as u32,
+            (mantissa >> 32) as u32,
+            (mantissa >> 64) as u32,
+            false,
+            scale as u32,
+        );
+        // `from_parts` ignores the sign of a zero, but setting it afterwards preserves `-0`.
+        converted.set_sign_negative(value.coefficient.sign() == Sign::Negative);
+        Ok(converted)
+    }
+}
+
+#[cfg(test)]
+mod rust_decimal_conversions_tests {
+    use super::*;
+    use crate::ion_data::IonEq;
+    use rstest::*;
+    use std::str::FromStr;
+
+    fn rust_decimal(text: &str) -> rust_decimal::Decimal {
+        let mut value = rust_decimal::Decimal::from_str(text).unwrap();
+        // The parser discards the sign of a zero.
+        value.set_sign_negative(text.starts_with('-'));
+        value
+    }
+
+    #[rstest]
+    #[case(rust_decimal("1.20"), Decimal::new(120, -2))]
+    #[case(rust_decimal("-1.20"), Decimal::new(-120, -2))]
+    #[case(rust_decimal("0"), Decimal::new(0, 0))]
+    #[case(rust_decimal("-0.0"), Decimal::negative_zero_with_exponent(-1))]
+    #[case(
+        rust_decimal::Decimal::MAX,
+        Decimal::new(79228162514264337593543950335u128, 0)
+    )]
+    #[case(rust_decimal("0.0000000000000000000000000001"), Decimal::new(1, -28))]
+    fn round_trip(#[case] value: rust_decimal::Decimal, #[case] ion_decimal: Decimal) {
+        assert!(Decimal::from(value).ion_eq(&ion_decimal));
+        let converted = rust_decimal::Decimal::try_from(ion_decimal).unwrap();
+        assert_eq!(converted, value);
+        assert_eq!(converted.scale(), value.scale());
+        assert_eq!(converted.is_sign_negative(), value.is_sign_negative());
+    }
+
+    #[rstest]
+    #[case::positive_exponent(Decimal::new(12, 2), "1200")]
+    #[case::zero_with_positive_exponent(Decimal::new(0, 1_000_000), "0")]
+    #[case::trailing_zeros_beyond_max_scale(
+        Decimal::new(1000, -30),
+        "0.000000000000000000000000001"
+    )]
+    #[case::zero_beyond_max_scale(Decimal::new(0, -1_000_000), "0")]
+    fn numerically_equal_conversions(#[case] ion_decimal: Decimal, #[case] expected: &str) {
+        let converted = rust_decimal::Decimal::try_from(ion_decimal).unwrap();
+        assert_eq!(converted, rust_decimal(expected));
+    }
+
+    #[rstest]
+    #[case::coefficient_too_large(Decimal::new(79228162514264337593543950336u128, 0))]
+    #[case::exponent_too_large(Decimal::new(1, 29))]
+    #[case::too_precise(Decimal::new(1, -29))]
+    fn unrepresentable_decimals_are_rejected(#[case] ion_decimal: Decimal) {
+        assert!(rust_decimal::Decimal::try_from(ion_decimal).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+/// Details of how a value was encoded in its source that are not part of the Ion data model.
+///
+/// Two values that differ only in their `Encoding` are considered equal; readers only report
+/// an `Encoding` when asked to preserve it (see
+/// [`ReaderBuilder::preserve_encoding`](crate::ReaderBuilder::preserve_encoding)), and the
+/// binary writer uses it to choose the width of floats.
+///
+/// Currently, only the width of binary floats is recorded. Some other encoding choices that
+/// affect the bytes of a binary value (for example, a typed `null`, an unknown `-00:00`
+/// timestamp offset or a negative zero decimal) are captured by the value itself, but the
+/// following are not preserved, and writers re-encode them in their minimal form:
+///
+/// * length prefixes that are longer than necessary, including VarUInt padding
+/// * padded or non-minimal integer magnitudes, including the fields of decimals and timestamps
+/// * integers encoded as negative zero
+/// * `NOP` padding between or within values
+///
+/// As a result, reading a binary stream and writing it back out does not always reproduce the
+/// original bytes, even when encodings are preserved.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// A float whose binary encoding used the specified width.
+    Float(FloatWidth),
+}
+
+/// The size of the body of a binary Ion float.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FloatWidth {
+    /// A float with an empty body, which represents positive zero.
+    Empty,
+    /// A float encoded as a 4-byte IEEE-754 value.
+    F32,
+    /// A float encoded as an 8-byte IEEE-754 value.
+    F64,
+}
+
+impl FloatWidth {
+    /// Returns the `FloatWidth` corresponding to a binary float body of `length` bytes, if any.
+    pub(crate) fn from_length(length: usize) -> Option<FloatWidth> {
+        match length {
+            0 => Some(FloatWidth::Empty),
+            4 => Some(FloatWidth::F32),
+            8 => Some(FloatWidth::F64),
+            _ => None,
+        }
+    }
+}
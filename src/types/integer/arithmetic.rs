@@ -0,0 +1,707 @@
+//! Arithmetic and bitwise operators for [`Int`] and [`UInt`].
+//!
+//! Every operation first tries to compute its result using 128-bit primitives, which never
+//! allocate. Only when that overflows are the operands promoted to a [`BigInt`] or [`BigUint`].
+//! Results are always stored in the most compact representation that fits.
+
+use super::{Int, UInt};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+impl Int {
+    /// Stores `value` as an `I64` or `I128` if it fits, or as a `BigInt` otherwise.
+    fn from_big_int(value: BigInt) -> Int {
+        match value.to_i128() {
+            Some(i) => Int::from(i),
+            None => Int::BigInt(Box::new(value)),
+        }
+    }
+
+    pub(super) fn to_big_int(&self) -> Cow<'_, BigInt> {
+        match self {
+            Int::I64(i) => Cow::Owned(BigInt::from(*i)),
+            Int::I128(i) => Cow::Owned(BigInt::from(i.get())),
+            Int::BigInt(big) => Cow::Borrowed(big),
+        }
+    }
+
+    /// Applies `fast` if both operands fit in an `i128`, falling back to `slow` if either operand
+    /// is larger or if `fast` overflows.
+    fn binary_op(
+        &self,
+        rhs: &Int,
+        fast: impl FnOnce(i128, i128) -> Option<i128>,
+        slow: impl FnOnce(&BigInt, &BigInt) -> BigInt,
+    ) -> Int {
+        if let (Some(this), Some(that)) = (self.as_i128(), rhs.as_i128()) {
+            if let Some(result) = fast(this, that) {
+                return Int::from(result);
+            }
+        }
+        Int::from_big_int(slow(&self.to_big_int(), &rhs.to_big_int()))
+    }
+
+    /// Raises this value to the power of `exponent`.
+    ///
+    /// ```
+    /// # use ion_rs::types::Int;
+    /// # use num_bigint::BigInt;
+    /// assert_eq!(Int::I64(3).pow(4), Int::I64(81));
+    /// assert_eq!(Int::I64(2).pow(64), Int::from(BigInt::from(2).pow(64)));
+    /// ```
+    pub fn pow(&self, exponent: u32) -> Int {
+        if let Some(value) = self.as_i128() {
+            if let Some(result) = value.checked_pow(exponent) {
+                return Int::from(result);
+            }
+        }
+        Int::from_big_int(self.to_big_int().pow(exponent))
+    }
+
+    /// Returns the absolute value of this integer.
+    pub fn abs(&self) -> Int {
+        match self.as_i128().and_then(i128::checked_abs) {
+            Some(result) => Int::from(result),
+            None => Int::from_big_int(self.to_big_int().abs()),
+        }
+    }
+
+    /// Divides this value by `rhs`, rounding toward zero. Returns `None` if `rhs` is zero.
+    pub fn checked_div(&self, rhs: &Int) -> Option<Int> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self / rhs)
+    }
+
+    /// Returns the remainder of dividing this value by `rhs`. The result has the same sign as
+    /// `self`. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(&self, rhs: &Int) -> Option<Int> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self % rhs)
+    }
+}
+
+impl UInt {
+    pub(super) fn to_big_uint(&self) -> Cow<'_, BigUint> {
+        match self {
+            UInt::U64(u) => Cow::Owned(BigUint::from(*u)),
+            UInt::U128(u) => Cow::Owned(BigUint::from(*u)),
+            UInt::BigUInt(big) => Cow::Borrowed(big),
+        }
+    }
+
+    /// Applies `fast` if both operands fit in a `u128`, falling back to `slow` if either operand
+    /// is larger or if `fast` overflows.
+    fn binary_op(
+        &self,
+        rhs: &UInt,
+        fast: impl FnOnce(u128, u128) -> Option<u128>,
+        slow: impl FnOnce(&BigUint, &BigUint) -> BigUint,
+    ) -> UInt {
+        if let (Some(this), Some(that)) = (self.as_u128(), rhs.as_u128()) {
+            if let Some(result) = fast(this, that) {
+                return UInt::from(result);
+            }
+        }
+        // `From<BigUint>` stores the result as a `U64` or `U128` if it fits.
+        UInt::from(slow(&self.to_big_uint(), &rhs.to_big_uint()))
+    }
+
+    /// Raises this value to the power of `exponent`.
+    pub fn pow(&self, exponent: u32) -> UInt {
+        if let Some(value) = self.as_u128() {
+            if let Some(result) = value.checked_pow(exponent) {
+                return UInt::from(result);
+            }
+        }
+        UInt::from(self.to_big_uint().pow(exponent))
+    }
+
+    /// Subtracts `rhs` from this value. Returns `None` if `rhs` is larger than `self`.
+    ///
+    /// ```
+    /// # use ion_rs::types::UInt;
+    /// assert_eq!(UInt::U64(5).checked_sub(&UInt::U64(3)), Some(UInt::U64(2)));
+    /// assert_eq!(UInt::U64(3).checked_sub(&UInt::U64(5)), None);
+    /// ```
+    pub fn checked_sub(&self, rhs: &UInt) -> Option<UInt> {
+        if rhs > self {
+            return None;
+        }
+        Some(self - rhs)
+    }
+
+    /// Subtracts `rhs` from this value, stopping at zero instead of underflowing.
+    pub fn saturating_sub(&self, rhs: &UInt) -> UInt {
+        self.checked_sub(rhs).unwrap_or(UInt::U64(0))
+    }
+
+    /// Divides this value by `rhs`. Returns `None` if `rhs` is zero.
+    pub fn checked_div(&self, rhs: &UInt) -> Option<UInt> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self / rhs)
+    }
+
+    /// Returns the remainder of dividing this value by `rhs`. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(&self, rhs: &UInt) -> Option<UInt> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self % rhs)
+    }
+}
+
+// Implements a binary operator and its assigning counterpart for every combination of owned
+// and borrowed operands. All of them delegate to the `&T op &T` implementation, which calls
+// `binary_op` with the given fast path (for compact operands) and slow path (for everything
+// else).
+macro_rules! impl_binary_op {
+    ($int:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $fast:expr, $slow:expr) => {
+        impl $trait<&$int> for &$int {
+            type Output = $int;
+
+            fn $method(self, rhs: &$int) -> $int {
+                self.binary_op(rhs, $fast, $slow)
+            }
+        }
+
+        impl $trait<$int> for $int {
+            type Output = $int;
+
+            fn $method(self, rhs: $int) -> $int {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $trait<&$int> for $int {
+            type Output = $int;
+
+            fn $method(self, rhs: &$int) -> $int {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $trait<$int> for &$int {
+            type Output = $int;
+
+            fn $method(self, rhs: $int) -> $int {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $assign_trait<&$int> for $int {
+            fn $assign_method(&mut self, rhs: &$int) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+
+        impl $assign_trait<$int> for $int {
+            fn $assign_method(&mut self, rhs: $int) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+    };
+}
+
+// Division and remainder panic if the divisor is zero, just like the primitive integer types.
+// `i128::MIN / -1` and `i128::MIN % -1` fail the fast path and are computed using `BigInt`s.
+impl_binary_op!(
+    Int,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    i128::checked_add,
+    |a, b| a + b
+);
+impl_binary_op!(
+    Int,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    i128::checked_sub,
+    |a, b| a - b
+);
+impl_binary_op!(
+    Int,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    i128::checked_mul,
+    |a, b| a * b
+);
+impl_binary_op!(
+    Int,
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    i128::checked_div,
+    |a, b| a / b
+);
+impl_binary_op!(
+    Int,
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    i128::checked_rem,
+    |a, b| a % b
+);
+// Bitwise operations treat negative values as though they were stored in two's complement.
+impl_binary_op!(
+    Int,
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    |a, b| Some(a & b),
+    |a, b| a & b
+);
+impl_binary_op!(
+    Int,
+    BitOr,
+    bitor,
+    BitOrAssign,
+    bitor_assign,
+    |a, b| Some(a | b),
+    |a, b| a | b
+);
+impl_binary_op!(
+    Int,
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    |a, b| Some(a ^ b),
+    |a, b| a ^ b
+);
+
+// Subtraction panics if the result would be negative; see `UInt::checked_sub` and
+// `UInt::saturating_sub`.
+impl_binary_op!(
+    UInt,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    u128::checked_add,
+    |a, b| a + b
+);
+impl_binary_op!(
+    UInt,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    u128::checked_sub,
+    |a, b| a - b
+);
+impl_binary_op!(
+    UInt,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    u128::checked_mul,
+    |a, b| a * b
+);
+impl_binary_op!(
+    UInt,
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    u128::checked_div,
+    |a, b| a / b
+);
+impl_binary_op!(
+    UInt,
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    u128::checked_rem,
+    |a, b| a % b
+);
+impl_binary_op!(
+    UInt,
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    |a, b| Some(a & b),
+    |a, b| a & b
+);
+impl_binary_op!(
+    UInt,
+    BitOr,
+    bitor,
+    BitOrAssign,
+    bitor_assign,
+    |a, b| Some(a | b),
+    |a, b| a | b
+);
+impl_binary_op!(
+    UInt,
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    |a, b| Some(a ^ b),
+    |a, b| a ^ b
+);
+
+// Implements `Shl<u32>` and `Shr<u32>` (and their assigning counterparts) for owned and
+// borrowed values.
+macro_rules! impl_shift_ops {
+    ($int:ty, $shl:ident, $shr:ident) => {
+        impl Shl<u32> for &$int {
+            type Output = $int;
+
+            fn shl(self, bits: u32) -> $int {
+                $shl(self, bits)
+            }
+        }
+
+        impl Shl<u32> for $int {
+            type Output = $int;
+
+            fn shl(self, bits: u32) -> $int {
+                $shl(&self, bits)
+            }
+        }
+
+        impl ShlAssign<u32> for $int {
+            fn shl_assign(&mut self, bits: u32) {
+                *self = $shl(self, bits);
+            }
+        }
+
+        impl Shr<u32> for &$int {
+            type Output = $int;
+
+            fn shr(self, bits: u32) -> $int {
+                $shr(self, bits)
+            }
+        }
+
+        impl Shr<u32> for $int {
+            type Output = $int;
+
+            fn shr(self, bits: u32) -> $int {
+                $shr(&self, bits)
+            }
+        }
+
+        impl ShrAssign<u32> for $int {
+            fn shr_assign(&mut self, bits: u32) {
+                *self = $shr(self, bits);
+            }
+        }
+    };
+}
+
+impl_shift_ops!(Int, int_shl, int_shr);
+impl_shift_ops!(UInt, uint_shl, uint_shr);
+
+fn int_shl(value: &Int, bits: u32) -> Int {
+    if let Some(value) = value.as_i128() {
+        if value == 0 {
+            return Int::I64(0);
+        }
+        // The shift is lossless if shifting back recovers the original value.
+        if bits < i128::BITS && (value << bits) >> bits == value {
+            return Int::from(value << bits);
+        }
+    }
+    Int::from_big_int(value.to_big_int().as_ref() << bits as usize)
+}
+
+// Right shifts are arithmetic; negative values round toward negative infinity.
+fn int_shr(value: &Int, bits: u32) -> Int {
+    match value {
+        Int::I64(value) => Int::I64(value >> bits.min(i64::BITS - 1)),
+        Int::I128(value) => Int::from(value.get() >> bits.min(i128::BITS - 1)),
+        Int::BigInt(value) => Int::from_big_int(value.as_ref() >> bits as usize),
+    }
+}
+
+fn uint_shl(value: &UInt, bits: u32) -> UInt {
+    if let Some(value) = value.as_u128() {
+        if value == 0 {
+            return UInt::U64(0);
+        }
+        if bits <= value.leading_zeros() {
+            return UInt::from(value << bits);
+        }
+    }
+    UInt::from(value.to_big_uint().as_ref() << bits as usize)
+}
+
+fn uint_shr(value: &UInt, bits: u32) -> UInt {
+    match value {
+        UInt::U64(value) => UInt::U64(value.checked_shr(bits).unwrap_or(0)),
+        UInt::U128(value) => UInt::from(value.checked_shr(bits).unwrap_or(0)),
+        UInt::BigUInt(value) => UInt::from(value >> bits as usize),
+    }
+}
+
+impl Neg for &Int {
+    type Output = Int;
+
+    fn neg(self) -> Int {
+        match self.as_i128().and_then(i128::checked_neg) {
+            Some(result) => Int::from(result),
+            // `-i128::MIN` cannot be represented as an i128
+            None => Int::from_big_int(-self.to_big_int().as_ref()),
+        }
+    }
+}
+
+impl Neg for Int {
+    type Output = Int;
+
+    fn neg(self) -> Int {
+        -&self
+    }
+}
+
+// Bitwise negation treats the value as though it were stored in two's complement, so
+// `!x == -x - 1`.
+impl Not for &Int {
+    type Output = Int;
+
+    fn not(self) -> Int {
+        match self {
+            Int::I64(value) => Int::I64(!value),
+            Int::I128(value) => Int::from(!value.get()),
+            Int::BigInt(value) => Int::from_big_int(!value.as_ref()),
+        }
+    }
+}
+
+impl Not for Int {
+    type Output = Int;
+
+    fn not(self) -> Int {
+        !&self
+    }
+}
+
+impl One for Int {
+    fn one() -> Self {
+        Int::I64(1)
+    }
+}
+
+impl Zero for UInt {
+    fn zero() -> Self {
+        UInt::U64(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            UInt::U64(value) => *value == 0,
+            UInt::U128(value) => *value == 0,
+            UInt::BigUInt(value) => value.is_zero(),
+        }
+    }
+}
+
+impl One for UInt {
+    fn one() -> Self {
+        UInt::U64(1)
+    }
+}
+
+#[cfg(test)]
+mod arithmetic_tests {
+    use super::*;
+    use crate::types::I128;
+    use rstest::*;
+
+    fn big(text: &str) -> Int {
+        Int::from(text.parse::<BigInt>().unwrap())
+    }
+
+    fn big_u(text: &str) -> UInt {
+        UInt::from(text.parse::<BigUint>().unwrap())
+    }
+
+    fn is_compact(value: &Int) -> bool {
+        matches!(value, Int::I64(_))
+    }
+
+    #[rstest]
+    #[case::add_fast(Int::I64(5) + Int::I64(-7), Int::I64(-2))]
+    #[case::add_overflow(Int::I64(i64::MAX) + Int::I64(1), big("9223372036854775808"))]
+    #[case::sub_fast(Int::I64(5) - Int::I64(7), Int::I64(-2))]
+    #[case::sub_overflow(Int::I64(i64::MIN) - Int::I64(1), big("-9223372036854775809"))]
+    #[case::mul_fast(Int::I64(-6) * Int::I64(7), Int::I64(-42))]
+    #[case::mul_overflow(Int::I64(i64::MAX) * Int::I64(2), big("18446744073709551614"))]
+    #[case::div_fast(Int::I64(-7) / Int::I64(2), Int::I64(-3))]
+    #[case::div_overflow(Int::I64(i64::MIN) / Int::I64(-1), big("9223372036854775808"))]
+    #[case::rem_fast(Int::I64(-7) % Int::I64(2), Int::I64(-1))]
+    #[case::rem_overflow(Int::I64(i64::MIN) % Int::I64(-1), Int::I64(0))]
+    #[case::mixed(big("9223372036854775808") - Int::I64(1), Int::I64(i64::MAX))]
+    #[case::big_mul(
+        big("18446744073709551616") * big("-18446744073709551616"),
+        big("-340282366920938463463374607431768211456")
+    )]
+    fn int_arithmetic(#[case] actual: Int, #[case] expected: Int) {
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int_results_use_compact_representation_when_possible() {
+        let sum = big("9223372036854775808") + Int::I64(-1);
+        assert!(is_compact(&sum));
+        let product = Int::from(BigInt::from(6)) * Int::from(BigInt::from(7));
+        assert!(is_compact(&product));
+        assert!(!is_compact(&(Int::I64(i64::MAX) + Int::I64(1))));
+    }
+
+    #[test]
+    fn int_results_that_fit_in_128_bits_are_stored_inline() {
+        assert!(matches!(Int::I64(i64::MAX) + Int::I64(1), Int::I128(_)));
+        assert!(matches!(Int::I64(i64::MIN).abs(), Int::I128(_)));
+        assert!(matches!(-Int::I64(i64::MIN), Int::I128(_)));
+        assert!(matches!(Int::I64(1) << 100, Int::I128(_)));
+        assert!(matches!(Int::I64(2).pow(100), Int::I128(_)));
+        assert!(matches!(Int::I64(2).pow(127), Int::BigInt(_)));
+        assert!(matches!(
+            Int::I128(I128::new(i128::MAX)) + Int::I64(1),
+            Int::BigInt(_)
+        ));
+        assert!(matches!(UInt::U64(u64::MAX) * UInt::U64(2), UInt::U128(_)));
+        assert!(matches!(
+            UInt::U128(u128::MAX) + UInt::U64(1),
+            UInt::BigUInt(_)
+        ));
+    }
+
+    #[test]
+    fn int_operand_ownership_and_assignment() {
+        let a = Int::I64(10);
+        let b = Int::I64(3);
+        assert_eq!(&a + &b, Int::I64(13));
+        assert_eq!(a.clone() - &b, Int::I64(7));
+        assert_eq!(&a * b.clone(), Int::I64(30));
+        let mut value = a;
+        value += &b;
+        value -= Int::I64(1);
+        value *= Int::I64(i64::MAX);
+        value /= Int::I64(i64::MAX);
+        value %= b;
+        assert_eq!(value, Int::I64(0));
+    }
+
+    #[rstest]
+    #[case::neg(-Int::I64(5), Int::I64(-5))]
+    #[case::neg_min(-Int::I64(i64::MIN), big("9223372036854775808"))]
+    #[case::neg_big(-big("9223372036854775808"), Int::I64(i64::MIN))]
+    #[case::not(!Int::I64(5), Int::I64(-6))]
+    #[case::not_big(!big("9223372036854775808"), big("-9223372036854775809"))]
+    #[case::and(Int::I64(0b1100) & Int::I64(0b1010), Int::I64(0b1000))]
+    #[case::or(Int::I64(0b1100) | Int::I64(0b1010), Int::I64(0b1110))]
+    #[case::xor(Int::I64(0b1100) ^ Int::I64(0b1010), Int::I64(0b0110))]
+    #[case::and_negative(Int::I64(-1) & big("18446744073709551616"), big("18446744073709551616"))]
+    #[case::shl(Int::I64(3) << 4, Int::I64(48))]
+    #[case::shl_negative(Int::I64(-3) << 4, Int::I64(-48))]
+    #[case::shl_overflow(Int::I64(1) << 64, big("18446744073709551616"))]
+    #[case::shl_into_sign_bit(Int::I64(1) << 63, big("9223372036854775808"))]
+    #[case::shl_min(Int::I64(-1) << 63, Int::I64(i64::MIN))]
+    #[case::shl_zero(Int::I64(0) << 1000, Int::I64(0))]
+    #[case::shr(Int::I64(48) >> 4, Int::I64(3))]
+    #[case::shr_negative(Int::I64(-7) >> 1, Int::I64(-4))]
+    #[case::shr_all_bits(Int::I64(-7) >> 100, Int::I64(-1))]
+    #[case::shr_big(big("18446744073709551616") >> 64, Int::I64(1))]
+    fn int_bit_operations(#[case] actual: Int, #[case] expected: Int) {
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int_methods() {
+        assert_eq!(Int::I64(-2).pow(3), Int::I64(-8));
+        assert_eq!(Int::I64(10).pow(20), big("100000000000000000000"));
+        assert_eq!(Int::I64(7).pow(0), Int::I64(1));
+        assert_eq!(Int::I64(-5).abs(), Int::I64(5));
+        assert_eq!(Int::I64(i64::MIN).abs(), big("9223372036854775808"));
+        assert_eq!(
+            big("-18446744073709551616").abs(),
+            big("18446744073709551616")
+        );
+        assert_eq!(Int::I64(7).checked_div(&Int::I64(2)), Some(Int::I64(3)));
+        assert_eq!(Int::I64(7).checked_div(&big("0")), None);
+        assert_eq!(Int::I64(7).checked_rem(&Int::I64(2)), Some(Int::I64(1)));
+        assert_eq!(Int::I64(7).checked_rem(&Int::I64(0)), None);
+        assert!(Int::one().is_one());
+    }
+
+    #[test]
+    #[should_panic]
+    fn int_division_by_zero_panics() {
+        let _ = Int::I64(1) / Int::I64(0);
+    }
+
+    #[rstest]
+    #[case::add_fast(UInt::U64(5) + UInt::U64(7), UInt::U64(12))]
+    #[case::add_overflow(UInt::U64(u64::MAX) + UInt::U64(1), big_u("18446744073709551616"))]
+    #[case::sub_fast(UInt::U64(7) - UInt::U64(5), UInt::U64(2))]
+    #[case::sub_big(big_u("18446744073709551616") - UInt::U64(1), UInt::U64(u64::MAX))]
+    #[case::mul_overflow(UInt::U64(u64::MAX) * UInt::U64(2), big_u("36893488147419103230"))]
+    #[case::div(big_u("36893488147419103230") / UInt::U64(2), UInt::U64(u64::MAX))]
+    #[case::rem(UInt::U64(7) % UInt::U64(4), UInt::U64(3))]
+    #[case::and(UInt::U64(0b1100) & UInt::U64(0b1010), UInt::U64(0b1000))]
+    #[case::or(UInt::U64(0b1100) | UInt::U64(0b1010), UInt::U64(0b1110))]
+    #[case::xor(UInt::U64(0b1100) ^ UInt::U64(0b1010), UInt::U64(0b0110))]
+    #[case::shl(UInt::U64(3) << 4, UInt::U64(48))]
+    #[case::shl_top_bit(UInt::U64(1) << 63, UInt::U64(1 << 63))]
+    #[case::shl_overflow(UInt::U64(1) << 64, big_u("18446744073709551616"))]
+    #[case::shr(UInt::U64(48) >> 4, UInt::U64(3))]
+    #[case::shr_all_bits(UInt::U64(48) >> 64, UInt::U64(0))]
+    #[case::shr_big(big_u("18446744073709551616") >> 63, UInt::U64(2))]
+    #[case::pow(UInt::U64(2).pow(64), big_u("18446744073709551616"))]
+    fn uint_arithmetic(#[case] actual: UInt, #[case] expected: UInt) {
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn uint_checked_and_saturating() {
+        assert_eq!(UInt::U64(3).checked_sub(&UInt::U64(3)), Some(UInt::U64(0)));
+        assert_eq!(
+            UInt::U64(3).checked_sub(&big_u("18446744073709551616")),
+            None
+        );
+        assert_eq!(UInt::U64(3).saturating_sub(&UInt::U64(5)), UInt::U64(0));
+        assert_eq!(UInt::U64(8).checked_div(&UInt::U64(2)), Some(UInt::U64(4)));
+        assert_eq!(UInt::U64(8).checked_div(&UInt::zero()), None);
+        assert_eq!(UInt::U64(8).checked_rem(&UInt::U64(3)), Some(UInt::U64(2)));
+        assert_eq!(UInt::U64(8).checked_rem(&UInt::zero()), None);
+        assert!(UInt::one().is_one());
+        let mut value = UInt::U64(u64::MAX);
+        value += UInt::U64(1);
+        value -= &UInt::U64(1);
+        assert!(matches!(value, UInt::U64(u64::MAX)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn uint_subtraction_underflow_panics() {
+        let _ = UInt::U64(3) - UInt::U64(5);
+    }
+}
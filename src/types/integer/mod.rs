@@ -0,0 +1,813 @@
+use crate::element::Element;
+use crate::ion_data::{IonEq, IonOrd};
+use crate::result::{decoding_error, IonError};
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::format;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use num_bigint::{BigInt, BigUint, Sign, ToBigUint};
+use num_traits::{ToPrimitive, Zero};
+
+mod arithmetic;
+
+/// Provides convenient integer accessors for integer values that are like [`Int`]
+pub trait IntAccess {
+    /// Returns the value as an `i64` if it can be represented as such.
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::element::*;
+    /// # use ion_rs::element::*;
+    /// # use ion_rs::types::*;
+    /// # use num_bigint::*;
+    /// let big_int = Int::from(BigInt::from(100));
+    /// let i64_int = Int::I64(100);
+    /// assert_eq!(big_int.as_i64(), i64_int.as_i64());
+    ///
+    /// // works on element too
+    /// let big_elem: Element = big_int.into();
+    /// let i64_elem: Element = i64_int.into();
+    ///
+    /// assert_eq!(big_elem.as_i64(), i64_elem.as_i64());
+    /// ```
+    fn as_i64(&self) -> Option<i64>;
+
+    /// Returns a reference as a [`BigInt`] if it is represented as such.  Note that this
+    /// method may return `None` if the underlying representation *is not* stored in a [`BigInt`]
+    /// such as if it is represented as an `i64` so it is somewhat asymmetric with respect
+    /// to [`IntAccess::as_i64`].
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::element::*;
+    /// # use ion_rs::element::*;
+    /// # use ion_rs::types::*;
+    /// # use num_bigint::*;
+    /// # use std::str::FromStr;
+    /// let big_int = Int::from(BigInt::from(100));
+    /// assert_eq!(
+    ///     BigInt::from_str("100").unwrap(),
+    ///     *big_int.as_big_int().unwrap()
+    /// );
+    /// let i64_int = Int::I64(100);
+    /// assert_eq!(None, i64_int.as_big_int());
+    ///
+    /// // works on element too
+    /// let big_elem: Element = big_int.into();
+    /// assert_eq!(
+    ///     BigInt::from_str("100").unwrap(),
+    ///     *big_elem.as_big_int().unwrap()
+    /// );
+    /// let i64_elem: Element = i64_int.into();
+    /// assert_eq!(None, i64_elem.as_big_int());
+    /// ```
+    fn as_big_int(&self) -> Option<&BigInt>;
+}
+
+/// Represents a UInt of any size. Used for reading binary integers and symbol Ids.
+/// Used to represent the unsigned magnitude of Decimal values and fractional seconds.
+///
+/// Values that fit in a `u128` are stored inline; only larger values require a heap-allocated
+/// [`BigUint`].
+#[derive(Debug, Clone)]
+pub enum UInt {
+    U64(u64),
+    U128(u128),
+    BigUInt(BigUint),
+}
+
+impl UInt {
+    /// Returns the value as a `u128` if it can be represented as such. This method never
+    /// allocates.
+    pub(crate) fn as_u128(&self) -> Option<u128> {
+        match self {
+            UInt::U64(value) => Some(*value as u128),
+            UInt::U128(value) => Some(*value),
+            UInt::BigUInt(value) => value.to_u128(),
+        }
+    }
+
+    /// Returns the number of digits in the base-10 representation of the UInteger.
+    pub(crate) fn number_of_decimal_digits(&self) -> u64 {
+        match self {
+            UInt::U64(u64_value) => super::num_decimal_digits_in_u64(*u64_value),
+            UInt::U128(u128_value) => UInt::calculate_u128_digits(*u128_value),
+            UInt::BigUInt(big_uint_value) => UInt::calculate_big_uint_digits(big_uint_value),
+        }
+    }
+
+    fn calculate_u128_digits(mut value: u128) -> u64 {
+        let mut digits = 1;
+        while value >= 10 {
+            value /= 10;
+            digits += 1;
+        }
+        digits
+    }
+
+    fn calculate_big_uint_digits(int: &BigUint) -> u64 {
+        if int.is_zero() {
+            return 1;
+        }
+        let mut digits = 0;
+        let mut dividend = int.to_owned();
+        let ten: BigUint = BigUint::from(10u64);
+        while dividend > BigUint::zero() {
+            dividend /= &ten;
+            digits += 1;
+        }
+        digits
+    }
+}
+
+impl PartialEq for UInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for UInt {}
+
+impl PartialOrd for UInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare the values as u128s if possible; this never allocates.
+        match (self.as_u128(), other.as_u128()) {
+            (Some(m1), Some(m2)) => m1.cmp(&m2),
+            // A value that cannot be represented as a u128 is larger than one that can.
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => self.to_big_uint().cmp(&other.to_big_uint()),
+        }
+    }
+}
+
+impl From<UInt> for Int {
+    fn from(value: UInt) -> Self {
+        match value {
+            // Every u64 fits in an i128, so this never allocates.
+            UInt::U64(uint) => Int::from(uint),
+            UInt::U128(uint) => Int::from(uint),
+            UInt::BigUInt(big_uint) => big_integer_from_big_uint(big_uint),
+        }
+    }
+}
+
+impl From<BigUint> for UInt {
+    fn from(value: BigUint) -> Self {
+        // prefer a compact representation for the magnitude
+        if let Some(unsigned) = value.to_u64() {
+            UInt::U64(unsigned)
+        } else if let Some(unsigned) = value.to_u128() {
+            UInt::U128(unsigned)
+        } else {
+            UInt::BigUInt(value)
+        }
+    }
+}
+
+impl From<UInt> for BigUint {
+    fn from(value: UInt) -> Self {
+        use UInt::*;
+        match value {
+            U64(m) => BigUint::from(m),
+            U128(m) => BigUint::from(m),
+            BigUInt(m) => m,
+        }
+    }
+}
+
+impl ToBigUint for UInt {
+    fn to_biguint(&self) -> Option<BigUint> {
+        // This implementation never fails, but the trait requires an `Option` return type.
+        Some(self.clone().into())
+    }
+}
+
+// This macro makes it possible to turn unsigned int primitives into a UInteger using `.into()`.
+// Note that it works for both signed and unsigned ints. The resulting UInteger will be the
+// absolute value of the integer being converted.
+macro_rules! impl_uint_from_small_unsigned_int_types {
+    ($($t:ty),*) => ($(
+        impl From<$t> for UInt {
+            fn from(value: $t) -> UInt {
+                UInt::U64(value as u64)
+            }
+        }
+    )*)
+}
+
+impl_uint_from_small_unsigned_int_types!(u8, u16, u32, u64, usize);
+
+macro_rules! impl_uint_from_small_signed_int_types {
+    ($($t:ty),*) => ($(
+        impl From<$t> for UInt {
+            fn from(value: $t) -> UInt {
+                let abs_value = value.unsigned_abs();
+                UInt::U64(abs_value.try_into().unwrap())
+            }
+        }
+    )*)
+}
+
+impl_uint_from_small_signed_int_types!(i8, i16, i32, i64, isize);
+
+impl From<u128> for UInt {
+    fn from(value: u128) -> UInt {
+        match u64::try_from(value) {
+            Ok(u64_value) => UInt::U64(u64_value),
+            Err(_) => UInt::U128(value),
+        }
+    }
+}
+
+impl From<i128> for UInt {
+    fn from(value: i128) -> UInt {
+        value.unsigned_abs().into()
+    }
+}
+
+impl From<Int> for UInt {
+    fn from(value: Int) -> Self {
+        match value {
+            Int::I64(i) => i.into(),
+            Int::I128(i) => i.get().into(),
+            // num_bigint::BigInt's `into_parts` consumes the BigInt and returns a
+            // (sign: Sign, magnitude: BigUint) tuple. We only care about the magnitude, so we
+            // extract it here with `.1` ---------------v and then convert the BigUint to a UInteger
+            Int::BigInt(i) => i.into_parts().1.into(), // <-- using `.into()`
+        }
+    }
+}
+
+#[inline(never)]
+fn big_integer_from_big_uint(value: BigUint) -> Int {
+    Int::BigInt(Box::new(BigInt::from(value)))
+}
+
+// Conversions from UInt to primitive integer types that may not be able to represent its value.
+macro_rules! impl_primitive_try_from_uint {
+    ($($t:ty),*) => ($(
+        impl TryFrom<&UInt> for $t {
+            type Error = IonError;
+
+            fn try_from(value: &UInt) -> Result<Self, Self::Error> {
+                let converted = match value {
+                    UInt::U64(uint) => <$t>::try_from(*uint).ok(),
+                    UInt::U128(uint) => <$t>::try_from(*uint).ok(),
+                    UInt::BigUInt(big_uint) => <$t>::try_from(big_uint).ok(),
+                };
+                converted.map_or_else(
+                    || decoding_error(format!(
+                        "Unsigned integer {value} was too large to be represented as a(n) {}.",
+                        stringify!($t)
+                    )),
+                    Ok,
+                )
+            }
+        }
+
+        impl TryFrom<UInt> for $t {
+            type Error = IonError;
+
+            fn try_from(value: UInt) -> Result<Self, Self::Error> {
+                <$t>::try_from(&value)
+            }
+        }
+    )*)
+}
+impl_primitive_try_from_uint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Container for an integer of any size. Integers that fit in an `i64` or an `i128` are stored
+/// inline; larger values are stored in an arbitrarily sized [`BigInt`].
+///
+/// The [`BigInt`] is boxed and the `i128` is stored as an [`I128`] so that neither increases the
+/// size or alignment of `Int`.
+///
+/// See [`IntAccess`] for common operations.
+#[derive(Debug, Clone)]
+pub enum Int {
+    I64(i64),
+    I128(I128),
+    BigInt(Box<BigInt>),
+}
+
+/// An `i128` stored as two 64-bit halves.
+///
+/// An `i128` is 16-byte aligned on most platforms, which would add padding to [`Int`] and to every
+/// `Value` and `Element` that holds one. The halves only require 8-byte alignment.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I128 {
+    // Declared high half first so that the derived ordering matches that of `i128`.
+    high: i64,
+    low: u64,
+}
+
+impl I128 {
+    pub const fn new(value: i128) -> Self {
+        I128 {
+            high: (value >> 64) as i64,
+            low: value as u64,
+        }
+    }
+
+    pub const fn get(self) -> i128 {
+        ((self.high as i128) << 64) | self.low as i128
+    }
+}
+
+impl From<i128> for I128 {
+    fn from(value: i128) -> Self {
+        I128::new(value)
+    }
+}
+
+impl From<I128> for i128 {
+    fn from(value: I128) -> Self {
+        value.get()
+    }
+}
+
+impl Debug for I128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl Display for I128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Int {
+    /// Returns the value as an `i128` if it can be represented as such. This method never
+    /// allocates.
+    pub(crate) fn as_i128(&self) -> Option<i128> {
+        match self {
+            Int::I64(value) => Some(*value as i128),
+            Int::I128(value) => Some(value.get()),
+            Int::BigInt(value) => value.to_i128(),
+        }
+    }
+
+    /// Returns `true` if this value is less than zero.
+    pub(crate) fn is_negative(&self) -> bool {
+        match self {
+            Int::I64(value) => *value < 0,
+            Int::I128(value) => value.get() < 0,
+            Int::BigInt(value) => value.sign() == Sign::Minus,
+        }
+    }
+}
+
+impl IntAccess for Int {
+    #[inline]
+    fn as_i64(&self) -> Option<i64> {
+        match &self {
+            Int::I64(i) => Some(*i),
+            Int::I128(i) => i64::try_from(i.get()).ok(),
+            Int::BigInt(big) => big.to_i64(),
+        }
+    }
+
+    #[inline]
+    fn as_big_int(&self) -> Option<&BigInt> {
+        match &self {
+            Int::I64(_) | Int::I128(_) => None,
+            Int::BigInt(big) => Some(big),
+        }
+    }
+}
+
+impl PartialEq for Int {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Int {}
+
+impl IonEq for Int {
+    fn ion_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl IonOrd for Int {
+    fn ion_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl PartialOrd for Int {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Int {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare the values as i128s if possible; this never allocates.
+        match (self.as_i128(), other.as_i128()) {
+            (Some(m1), Some(m2)) => m1.cmp(&m2),
+            // A value that cannot be represented as an i128 is further from zero than one that
+            // can, so its sign determines the ordering.
+            (Some(_), None) if other.is_negative() => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) if self.is_negative() => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => self.to_big_int().cmp(&other.to_big_int()),
+        }
+    }
+}
+
+impl Zero for Int {
+    fn zero() -> Self {
+        Int::I64(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            Int::I64(value) => *value == 0i64,
+            Int::I128(value) => value.get() == 0i128,
+            Int::BigInt(value) => value.is_zero(),
+        }
+    }
+}
+
+impl Display for UInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match &self {
+            UInt::U64(i) => write!(f, "{i}"),
+            UInt::U128(i) => write!(f, "{i}"),
+            UInt::BigUInt(i) => write!(f, "{i}"),
+        }
+    }
+}
+
+// Trivial conversion to Int::I64 from integers that can safely be converted to an i64
+macro_rules! impl_int_i64_from {
+    ($($t:ty),*) => ($(
+        impl From<$t> for Int {
+            fn from(value: $t) -> Int {
+                let i64_value = i64::from(value);
+                Int::I64(i64_value)
+            }
+        }
+    )*)
+}
+impl_int_i64_from!(u8, u16, u32, i8, i16, i32, i64);
+
+// Conversion to Integer from integer types that may or may not fit in an i64. Every value of
+// these types fits in an i128, so none of these conversions allocate.
+macro_rules! impl_int_from {
+    ($($t:ty),*) => ($(
+        impl From<$t> for Int {
+            fn from(value: $t) -> Int {
+                match i64::try_from(value) {
+                    Ok(i64_value) => Int::I64(i64_value),
+                    Err(_) => Int::I128(I128::new(value as i128)),
+                }
+            }
+        }
+    )*)
+}
+
+impl_int_from!(isize, usize, u64);
+
+impl From<i128> for Int {
+    fn from(value: i128) -> Int {
+        match i64::try_from(value) {
+            Ok(i64_value) => Int::I64(i64_value),
+            Err(_) => Int::I128(I128::new(value)),
+        }
+    }
+}
+
+impl From<u128> for Int {
+    fn from(value: u128) -> Int {
+        match i128::try_from(value) {
+            Ok(i128_value) => Int::from(i128_value),
+            Err(_) => Int::BigInt(Box::new(BigInt::from(value))),
+        }
+    }
+}
+
+impl From<BigUint> for Int {
+    fn from(value: BigUint) -> Self {
+        let big_int = BigInt::from(value);
+        Int::BigInt(Box::new(big_int))
+    }
+}
+
+impl From<BigInt> for Int {
+    fn from(value: BigInt) -> Self {
+        Int::BigInt(Box::new(value))
+    }
+}
+
+// Conversions from Int to primitive integer types that may not be able to represent its value.
+macro_rules! impl_primitive_try_from_int {
+    ($($t:ty),*) => ($(
+        impl TryFrom<&Int> for $t {
+            type Error = IonError;
+
+            fn try_from(value: &Int) -> Result<Self, Self::Error> {
+                let converted = match value {
+                    Int::I64(int) => <$t>::try_from(*int).ok(),
+                    Int::I128(int) => <$t>::try_from(int.get()).ok(),
+                    Int::BigInt(big_int) => <$t>::try_from(big_int.as_ref()).ok(),
+                };
+                converted.map_or_else(
+                    || decoding_error(format!(
+                        "Integer {value} is out of range for a(n) {}.",
+                        stringify!($t)
+                    )),
+                    Ok,
+                )
+            }
+        }
+
+        impl TryFrom<Int> for $t {
+            type Error = IonError;
+
+            fn try_from(value: Int) -> Result<Self, Self::Error> {
+                <$t>::try_from(&value)
+            }
+        }
+    )*)
+}
+impl_primitive_try_from_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl IntAccess for Element {
+    fn as_i64(&self) -> Option<i64> {
+        match self.as_int() {
+            Some(any) => any.as_i64(),
+            _ => None,
+        }
+    }
+
+    fn as_big_int(&self) -> Option<&BigInt> {
+        match self.as_int() {
+            Some(any) => any.as_big_int(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Int {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match &self {
+            Int::I64(i) => write!(f, "{i}"),
+            Int::I128(i) => write!(f, "{i}"),
+            Int::BigInt(i) => write!(f, "{i}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod integer_tests {
+    use num_bigint::BigInt;
+    use std::io::Write;
+
+    use crate::types::Int::{self, I64};
+    use crate::types::{UInt, I128};
+    use num_bigint::BigUint;
+    use num_traits::Zero;
+    use rstest::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn is_zero() {
+        assert!(I64(0).is_zero());
+        assert!(Int::from(BigInt::from(0)).is_zero());
+        assert!(!I64(55).is_zero());
+        assert!(!Int::from(BigInt::from(55)).is_zero());
+        assert!(!I64(-55).is_zero());
+        assert!(!Int::from(BigInt::from(-55)).is_zero());
+    }
+
+    #[test]
+    fn zero() {
+        assert!(Int::zero().is_zero());
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(I64(0) + I64(0), I64(0));
+        assert_eq!(I64(5) + I64(7), I64(12));
+        assert_eq!(I64(-5) + I64(7), I64(2));
+        assert_eq!(
+            I64(100) + Int::from(BigInt::from(1000)),
+            Int::from(BigInt::from(1100))
+        );
+        assert_eq!(
+            Int::from(BigInt::from(100)) + I64(1000),
+            Int::from(BigInt::from(1100))
+        );
+        assert_eq!(
+            Int::from(BigInt::from(100)) + Int::from(BigInt::from(1000)),
+            Int::from(BigInt::from(1100))
+        );
+    }
+
+    #[rstest]
+    #[case::i64(Int::I64(5), Int::I64(4), Ordering::Greater)]
+    #[case::i64_equal(Int::I64(-5), Int::I64(-5), Ordering::Equal)]
+    #[case::i64_gt_big_int(Int::I64(4), Int::from(BigInt::from(3)), Ordering::Greater)]
+    #[case::i64_eq_big_int(Int::I64(3), Int::from(BigInt::from(3)), Ordering::Equal)]
+    #[case::i64_lt_big_int(Int::I64(-3), Int::from(BigInt::from(5)), Ordering::Less)]
+    #[case::big_int(
+        Int::from(BigInt::from(1100)),
+        Int::from(BigInt::from(-1005)),
+        Ordering::Greater
+    )]
+    #[case::big_int(
+        Int::from(BigInt::from(1100)),
+        Int::from(BigInt::from(1100)),
+        Ordering::Equal
+    )]
+    #[case::i128_gt_i64(Int::I128(I128::new(i128::MAX)), Int::I64(i64::MAX), Ordering::Greater)]
+    #[case::i128_eq_i64(Int::I128(I128::new(-5)), Int::I64(-5), Ordering::Equal)]
+    #[case::i128_eq_big_int(
+        Int::I128(I128::new(i128::MIN)),
+        Int::from(BigInt::from(i128::MIN)),
+        Ordering::Equal
+    )]
+    #[case::i128_lt_positive_big_int(
+        Int::I128(I128::new(i128::MAX)),
+        Int::from(BigInt::from(u128::MAX)),
+        Ordering::Less
+    )]
+    #[case::i128_gt_negative_big_int(
+        Int::I128(I128::new(i128::MIN)),
+        -Int::from(BigInt::from(u128::MAX)),
+        Ordering::Greater
+    )]
+    fn integer_ordering_tests(#[case] this: Int, #[case] other: Int, #[case] expected: Ordering) {
+        assert_eq!(this.cmp(&other), expected)
+    }
+
+    #[rstest]
+    #[case::u64(UInt::U64(5), UInt::U64(4), Ordering::Greater)]
+    #[case::u64_equal(UInt::U64(5), UInt::U64(5), Ordering::Equal)]
+    #[case::u64_gt_big_uint(UInt::U64(4), UInt::BigUInt(BigUint::from(3u64)), Ordering::Greater)]
+    #[case::u64_lt_big_uint(UInt::U64(3), UInt::BigUInt(BigUint::from(5u64)), Ordering::Less)]
+    #[case::u64_eq_big_uint(UInt::U64(3), UInt::BigUInt(BigUint::from(3u64)), Ordering::Equal)]
+    #[case::big_uint(
+        UInt::BigUInt(BigUint::from(1100u64)),
+        UInt::BigUInt(BigUint::from(1005u64)),
+        Ordering::Greater
+    )]
+    #[case::big_uint(
+        UInt::BigUInt(BigUint::from(1005u64)),
+        UInt::BigUInt(BigUint::from(1005u64)),
+        Ordering::Equal
+    )]
+    #[case::u128_gt_u64(UInt::U128(u128::MAX), UInt::U64(u64::MAX), Ordering::Greater)]
+    #[case::u128_eq_u64(UInt::U128(5), UInt::U64(5), Ordering::Equal)]
+    #[case::u128_lt_big_uint(
+        UInt::U128(u128::MAX),
+        UInt::from(BigUint::from(u128::MAX) + 1u32),
+        Ordering::Less
+    )]
+    fn unsigned_integer_ordering_tests(
+        #[case] this: UInt,
+        #[case] other: UInt,
+        #[case] expected: Ordering,
+    ) {
+        assert_eq!(this.cmp(&other), expected)
+    }
+
+    #[rstest]
+    #[case(UInt::U64(1), 1)] // only one test case for U64 as that's delegated to another impl
+    #[case(UInt::BigUInt(BigUint::from(0u64)), 1)]
+    #[case(UInt::BigUInt(BigUint::from(1u64)), 1)]
+    #[case(UInt::BigUInt(BigUint::from(10u64)), 2)]
+    #[case(UInt::BigUInt(BigUint::from(3117u64)), 4)]
+    #[case(UInt::U128(0), 1)]
+    #[case(UInt::U128(u128::MAX), 39)]
+    fn uint_decimal_digits_test(#[case] uint: UInt, #[case] expected: i32) {
+        assert_eq!(uint.number_of_decimal_digits(), expected as u64)
+    }
+
+    #[rstest]
+    #[case(Int::I64(5), "5")]
+    #[case(Int::I64(-5), "-5")]
+    #[case(Int::I64(0), "0")]
+    #[case(Int::from(BigInt::from(1100)), "1100")]
+    #[case(Int::from(BigInt::from(-1100)), "-1100")]
+    fn int_display_test(#[case] value: Int, #[case] expect: String) {
+        let mut buf = Vec::new();
+        write!(&mut buf, "{value}").unwrap();
+        assert_eq!(expect, String::from_utf8(buf).unwrap());
+    }
+
+    #[rstest]
+    #[case(UInt::U64(5), "5")]
+    #[case(UInt::U64(0), "0")]
+    #[case(UInt::BigUInt(BigUint::from(0u64)), "0")]
+    #[case(UInt::BigUInt(BigUint::from(1100u64)), "1100")]
+    fn uint_display_test(#[case] value: UInt, #[case] expect: String) {
+        let mut buf = Vec::new();
+        write!(&mut buf, "{value}").unwrap();
+        assert_eq!(expect, String::from_utf8(buf).unwrap());
+    }
+
+    #[test]
+    fn conversions_use_inline_representations() {
+        assert!(matches!(Int::from(u64::MAX), Int::I128(_)));
+        assert!(matches!(Int::from(UInt::U64(u64::MAX)), Int::I128(_)));
+        assert!(matches!(Int::from(i128::MIN), Int::I128(v) if v.get() == i128::MIN));
+        assert!(matches!(
+            Int::from(i128::from(i64::MIN)),
+            Int::I64(i64::MIN)
+        ));
+        assert!(matches!(Int::from(u128::MAX), Int::BigInt(_)));
+        assert!(matches!(UInt::from(u128::MAX), UInt::U128(u128::MAX)));
+        assert!(matches!(UInt::from(i128::MIN), UInt::U128(_)));
+        assert!(matches!(
+            UInt::from(u128::from(u64::MAX)),
+            UInt::U64(u64::MAX)
+        ));
+        assert!(matches!(
+            UInt::from(Int::I128(I128::new(-1i128 << 100))),
+            UInt::U128(_)
+        ));
+        assert_eq!(
+            Int::I128(I128::new(-1i128 << 100)).to_string(),
+            "-1267650600228229401496703205376"
+        );
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(-1)]
+    #[case(u64::MAX as i128)]
+    #[case(-(u64::MAX as i128))]
+    #[case(i128::MIN)]
+    #[case(i128::MAX)]
+    fn i128_halves_round_trip(#[case] value: i128) {
+        let halves = I128::new(value);
+        assert_eq!(halves.get(), value);
+        assert_eq!(format!("{halves}"), value.to_string());
+        assert_eq!(format!("{halves:?}"), value.to_string());
+    }
+
+    #[test]
+    fn i128_halves_order_like_i128() {
+        let values = [
+            i128::MIN,
+            -(u64::MAX as i128),
+            -1,
+            0,
+            1,
+            u64::MAX as i128,
+            i128::MAX,
+        ];
+        for pair in values.windows(2) {
+            assert!(I128::new(pair[0]) < I128::new(pair[1]));
+        }
+    }
+
+    #[test]
+    fn int_try_into_primitives() {
+        assert_eq!(u8::try_from(&Int::I64(255)).unwrap(), 255u8);
+        assert!(u8::try_from(&Int::I64(256)).is_err());
+        assert!(u32::try_from(&Int::I64(-1)).is_err());
+        assert_eq!(i8::try_from(Int::I64(-128)).unwrap(), -128i8);
+        assert!(i16::try_from(&Int::I64(40_000)).is_err());
+        assert_eq!(i64::try_from(&Int::from(BigInt::from(-5))).unwrap(), -5);
+        assert!(i64::try_from(&Int::from(u64::MAX)).is_err());
+        assert_eq!(u64::try_from(&Int::from(u64::MAX)).unwrap(), u64::MAX);
+        assert_eq!(i128::try_from(&Int::from(i128::MIN)).unwrap(), i128::MIN);
+        assert_eq!(u128::try_from(&Int::from(u128::MAX)).unwrap(), u128::MAX);
+        assert!(u128::try_from(&(Int::from(u128::MAX) + Int::I64(1))).is_err());
+        assert_eq!(usize::try_from(&Int::I64(7)).unwrap(), 7usize);
+        assert!(isize::try_from(&Int::from(BigInt::from(u128::MAX))).is_err());
+    }
+
+    #[test]
+    fn uint_try_into_primitives() {
+        assert_eq!(u8::try_from(&UInt::U64(255)).unwrap(), 255u8);
+        assert!(u8::try_from(&UInt::U64(256)).is_err());
+        assert!(i64::try_from(&UInt::U64(u64::MAX)).is_err());
+        assert_eq!(
+            i128::try_from(UInt::U64(u64::MAX)).unwrap(),
+            u64::MAX as i128
+        );
+        assert_eq!(u128::try_from(&UInt::from(u128::MAX)).unwrap(), u128::MAX);
+        assert!(u64::try_from(&UInt::from(u128::MAX)).is_err());
+        assert_eq!(
+            usize::try_from(&UInt::BigUInt(BigUint::from(9u64))).unwrap(),
+            9usize
+        );
+    }
+}
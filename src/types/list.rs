@@ -3,8 +3,8 @@ use crate::element::iterators::ElementsIterator;
 use crate::element::{Element, Sequence};
 use crate::ion_data::IonEq;
 use crate::text::text_formatter::IonValueFormatter;
+use core::fmt::{Display, Formatter};
 use delegate::delegate;
-use std::fmt::{Display, Formatter};
 
 /// An in-memory representation of an Ion list.
 /// ```
@@ -36,6 +36,10 @@ impl List {
             pub fn get(&self, index: usize) -> Option<&Element>;
             pub fn len(&self) -> usize;
             pub fn is_empty(&self) -> bool;
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut Element>;
+            pub fn elements_mut(&mut self) -> core::slice::IterMut<'_, Element>;
+            pub fn push<E: Into<Element>>(&mut self, element: E);
+            pub fn remove(&mut self, index: usize) -> Option<Element>;
         }
     }
 }
@@ -64,9 +68,9 @@ impl<'a> IntoIterator for &'a List {
 }
 
 impl Display for List {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let mut ivf = IonValueFormatter { output: f };
-        ivf.format_list(self).map_err(|_| std::fmt::Error)?;
+        ivf.format_list(self).map_err(|_| core::fmt::Error)?;
         Ok(())
     }
 }
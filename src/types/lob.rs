@@ -1,4 +1,5 @@
 use crate::element::Bytes;
+use alloc::vec::Vec;
 
 /// An in-memory representation of an Ion blob.
 ///
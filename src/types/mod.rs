@@ -7,35 +7,50 @@ pub type SymbolId = usize;
 mod bytes;
 mod coefficient;
 mod decimal;
+mod encoding;
 mod integer;
 mod list;
 mod lob;
 mod sequence;
 mod sexp;
+mod span;
 mod string;
 mod r#struct;
 mod symbol;
+mod symbol_handle;
+#[cfg(feature = "std")]
+mod symbol_interner;
 mod timestamp;
 
 pub use crate::types::bytes::Bytes;
 pub use coefficient::{Coefficient, Sign};
-pub use decimal::Decimal;
-pub use integer::{Int, IntAccess, UInt};
+pub use decimal::{Decimal, DecimalContext, RoundingMode};
+pub use encoding::{Encoding, FloatWidth};
+pub use integer::{Int, IntAccess, UInt, I128};
 pub use list::List;
 pub use lob::{Blob, Clob};
 pub use r#struct::Struct;
 pub use sequence::Sequence;
 pub use sexp::SExp;
+pub use span::Span;
 pub use string::Str;
-pub use symbol::Symbol;
+pub use symbol::{ImportLocation, Symbol};
+pub(crate) use symbol_handle::reject_symbol_handle;
+pub use symbol_handle::SymbolHandle;
+#[cfg(feature = "std")]
+pub use symbol_interner::SymbolInterner;
+#[cfg(feature = "std")]
+pub(crate) use symbol_interner::{intern_str, intern_string};
+#[cfg(feature = "chrono-tz")]
+pub use timestamp::Disambiguation;
 pub use timestamp::{
-    DaySetter, FractionalSecondSetter, HourAndMinuteSetter, Mantissa, MonthSetter, Precision,
-    SecondSetter, Timestamp,
+    DaySetter, FractionalSecondSetter, HourAndMinuteSetter, MonthSetter, Precision, SecondSetter,
+    Timestamp,
 };
 
 use crate::ion_data::IonOrd;
-use std::cmp::Ordering;
-use std::fmt;
+use core::cmp::Ordering;
+use core::fmt;
 
 /// Represents the Ion data type of a given value. To learn more about each data type,
 /// read [the Ion Data Model](https://amazon-ion.github.io/ion-docs/docs/spec.html#the-ion-data-model)
@@ -107,20 +122,22 @@ pub(crate) enum ContainerType {
 
 /// Returns the number of base-10 digits needed to represent `value`.
 fn num_decimal_digits_in_u64(value: u64) -> u64 {
-    if value == 0 {
-        return 1;
-    }
-    let log_base_ten = (value as f64).log10();
-    let count = log_base_ten.ceil();
-    if log_base_ten == count {
-        // If ceil() didn't change the count, then `value` is an exact power of ten.
-        // We need to add one to get the correct count.
-        // Examples:
-        //    (1).log10() ==   (1).log10().ceil() == 0
-        //   (10).log10() ==  (10).log10().ceil() == 1
-        //  (100).log10() == (100).log10().ceil() == 2
-        count as u64 + 1
-    } else {
-        count as u64
+    let mut digits = 1;
+    let mut remaining = value / 10;
+    while remaining > 0 {
+        digits += 1;
+        remaining /= 10;
     }
+    digits
+}
+
+// Without `std`, there is no `SymbolInterner`; each symbol stores its own copy of its text.
+#[cfg(not(feature = "std"))]
+pub(crate) fn intern_str(text: &str) -> alloc::sync::Arc<str> {
+    alloc::sync::Arc::from(text)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn intern_string(text: alloc::string::String) -> alloc::sync::Arc<str> {
+    alloc::sync::Arc::from(text)
 }
@@ -2,17 +2,28 @@ use crate::element::builders::SequenceBuilder;
 use crate::element::iterators::ElementsIterator;
 use crate::element::Element;
 use crate::ion_data::{IonEq, IonOrd};
-use std::cmp::Ordering;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
+/// An ordered collection of [`Element`]s that backs both [`List`](crate::element::List) and
+/// [`SExp`](crate::element::SExp).
+///
+/// The elements are stored behind a reference count, so cloning a `Sequence` is O(1) regardless
+/// of how many values it contains. Mutating a clone (via [`get_mut`](Self::get_mut),
+/// [`push`](Self::push), etc.) copies only this sequence's backing vector, and only if it is
+/// still shared; nested containers continue to be shared until they are modified themselves.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Sequence {
-    elements: Vec<Element>,
+    elements: Arc<Vec<Element>>,
 }
 
 impl Sequence {
     pub fn new<E: Into<Element>, I: IntoIterator<Item = E>>(elements: I) -> Sequence {
         let elements = elements.into_iter().map(|e| e.into()).collect();
-        Sequence { elements }
+        Sequence {
+            elements: Arc::new(elements),
+        }
     }
 
     pub fn builder() -> SequenceBuilder {
@@ -38,6 +49,56 @@ impl Sequence {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns a mutable reference to the element at the specified index, if it exists.
+    ///
+    /// If this sequence's storage is shared with other clones, it will be copied first.
+    /// ```
+    /// use ion_rs::element::{Element, Sequence};
+    /// let original = Sequence::new([1, 2, 3]);
+    /// let mut modified = original.clone();
+    /// *modified.get_mut(1).unwrap() = Element::integer(20);
+    /// assert_eq!(original.get(1), Some(&Element::integer(2)));
+    /// assert_eq!(modified.get(1), Some(&Element::integer(20)));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Element> {
+        if index >= self.len() {
+            // Don't copy the storage if there's nothing to modify
+            return None;
+        }
+        Arc::make_mut(&mut self.elements).get_mut(index)
+    }
+
+    /// Returns an iterator that yields a mutable reference to each element in the sequence.
+    ///
+    /// If this sequence's storage is shared with other clones, it will be copied first.
+    pub fn elements_mut(&mut self) -> core::slice::IterMut<'_, Element> {
+        Arc::make_mut(&mut self.elements).iter_mut()
+    }
+
+    /// Adds the provided element to the end of the sequence.
+    ///
+    /// If this sequence's storage is shared with other clones, it will be copied first.
+    pub fn push<E: Into<Element>>(&mut self, element: E) {
+        Arc::make_mut(&mut self.elements).push(element.into())
+    }
+
+    /// Removes and returns the element at the specified position. If the index is out of bounds,
+    /// returns `None`.
+    ///
+    /// If this sequence's storage is shared with other clones, it will be copied first.
+    pub fn remove(&mut self, index: usize) -> Option<Element> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(Arc::make_mut(&mut self.elements).remove(index))
+    }
+
+    /// Returns `true` if `self` and `other` share the same underlying storage. Sequences that
+    /// share storage are always equal; sequences that do not may or may not be.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.elements, &other.elements)
+    }
 }
 
 impl AsRef<Sequence> for Sequence {
@@ -50,7 +111,9 @@ impl AsRef<Sequence> for Sequence {
 // an Element for better ergonomics.
 impl From<Vec<Element>> for Sequence {
     fn from(elements: Vec<Element>) -> Self {
-        Sequence { elements }
+        Sequence {
+            elements: Arc::new(elements),
+        }
     }
 }
 
@@ -67,12 +130,15 @@ impl<'a> IntoIterator for &'a Sequence {
 
 impl IonEq for Sequence {
     fn ion_eq(&self, other: &Self) -> bool {
-        self.elements.ion_eq(&other.elements)
+        self.ptr_eq(other) || self.elements.ion_eq(&other.elements)
     }
 }
 
 impl IonOrd for Sequence {
     fn ion_cmp(&self, other: &Self) -> Ordering {
+        if self.ptr_eq(other) {
+            return Ordering::Equal;
+        }
         self.elements.ion_cmp(&other.elements)
     }
 }
@@ -0,0 +1,51 @@
+use crate::result::Position;
+use core::ops::Range;
+
+/// The location of a value in the stream it was read from.
+///
+/// Both ends of a span carry a byte offset from the beginning of the stream. Spans of values
+/// read from text also carry a line and column, both of which start at 1; columns count
+/// characters rather than bytes. The span of a value covers its annotations and, for
+/// containers, everything up to and including the closing delimiter. It does not include a
+/// struct field's name.
+///
+/// Spans are only reported by readers that were asked to track them (see
+/// [`ReaderBuilder::track_spans`](crate::ReaderBuilder::track_spans)). Like
+/// [`Encoding`](crate::types::Encoding), a span is not part of the Ion data model; two values
+/// that differ only in their spans are considered equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    start: Position,
+    end: Position,
+}
+
+impl Span {
+    pub(crate) fn new(start: Position, end: Position) -> Span {
+        Span { start, end }
+    }
+
+    /// Returns the position of the first byte of the value.
+    pub fn start(&self) -> &Position {
+        &self.start
+    }
+
+    /// Returns the position immediately following the last byte of the value.
+    pub fn end(&self) -> &Position {
+        &self.end
+    }
+
+    /// Returns the range of byte offsets that the value occupies in its stream.
+    pub fn range(&self) -> Range<usize> {
+        self.start.byte_offset()..self.end.byte_offset()
+    }
+
+    /// If the value was read from text, returns the line on which it begins.
+    pub fn line(&self) -> Option<usize> {
+        self.start.line()
+    }
+
+    /// If the value was read from text, returns the column at which it begins.
+    pub fn column(&self) -> Option<usize> {
+        self.start.column()
+    }
+}
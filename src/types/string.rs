@@ -1,7 +1,9 @@
 use crate::ion_data::{IonEq, IonOrd};
 use crate::text::text_formatter::IonValueFormatter;
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
 
 /// An owned, immutable in-memory representation of an Ion `string`.
 ///
@@ -15,8 +17,9 @@ pub struct Str {
     // For the time being, the `Str` type is an opaque wrapper around the standard Rust `String`
     // type. Having this opaque wrapper means that we can swap out its implementation without a
     // breaking change, allowing us to offer string interning, stack-allocated small strings, or
-    // other optimizations as needed.
-    text: String,
+    // other optimizations as needed. It is currently a boxed `str` rather than a `String`,
+    // saving a word of space by not tracking spare capacity.
+    text: Box<str>,
 }
 
 impl Str {
@@ -58,30 +61,36 @@ impl Str {
     /// assert!(s.text().is_ascii());
     /// ```
     pub fn text(&self) -> &str {
-        self.text.as_str()
+        &self.text
     }
 }
 
 impl Display for Str {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let mut formatter = IonValueFormatter { output: f };
         formatter
             .format_string(self.as_ref())
-            .map_err(|_| std::fmt::Error)
+            .map_err(|_| core::fmt::Error)
     }
 }
 
 impl From<&str> for Str {
     fn from(value: &str) -> Self {
-        Str {
-            text: value.to_string(),
-        }
+        Str { text: value.into() }
     }
 }
 
 impl From<String> for Str {
     fn from(value: String) -> Self {
-        Str { text: value }
+        Str {
+            text: value.into_boxed_str(),
+        }
+    }
+}
+
+impl From<Str> for String {
+    fn from(value: Str) -> Self {
+        value.text.into_string()
     }
 }
 
@@ -4,49 +4,108 @@ use crate::ion_data::{IonEq, IonOrd};
 use crate::symbol_ref::AsSymbolRef;
 use crate::text::text_formatter::IonValueFormatter;
 use crate::Symbol;
+use alloc::borrow::Cow;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+#[cfg(feature = "std")]
+use once_cell::sync::OnceCell;
 use smallvec::SmallVec;
-use std::cmp::Ordering;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::fmt::{Display, Formatter};
 
 // A convenient type alias for a vector capable of storing a single `usize` inline
 // without heap allocation. This type should not be used in public interfaces directly.
 type IndexVec = SmallVec<[usize; 1]>;
 
+// Structs with this many fields or fewer are searched with a linear scan of `by_index` rather than
+// by building and consulting a `HashMap`. For short field lists, comparing a handful of strings is
+// cheaper than hashing the query, and skipping the map saves a sizable allocation per struct.
+// See `benches/element_layout.rs`.
+const MAX_LINEAR_SCAN_FIELDS: usize = 8;
+
+// Without `std` there is no `HashMap`, and a `BTreeMap` cannot be searched by `&str` because
+// symbols with unknown text cannot be borrowed as one. The index is never built; all lookups use
+// a linear scan.
+#[cfg(feature = "std")]
+type FieldIndex = HashMap<Symbol, IndexVec>;
+
 // This collection is broken out into its own type to allow instances of it to be shared with Arc/Rc.
 #[derive(Debug, Clone)]
 struct Fields {
     // Key/value pairs in the order they were inserted
     by_index: Vec<(Symbol, Element)>,
-    // Maps symbols to a list of indexes where values may be found in `by_index` above
-    by_name: HashMap<Symbol, IndexVec>,
+    // Maps symbols to a list of indexes where values may be found in `by_index` above. This is
+    // only populated the first time a field is looked up by name in a struct with more than
+    // `MAX_LINEAR_SCAN_FIELDS` fields.
+    #[cfg(feature = "std")]
+    by_name: OnceCell<FieldIndex>,
 }
 
 impl Fields {
+    fn new(by_index: Vec<(Symbol, Element)>) -> Self {
+        Fields {
+            by_index,
+            #[cfg(feature = "std")]
+            by_name: OnceCell::new(),
+        }
+    }
+
+    /// Returns the name-to-indexes map for this collection, building it if necessary. If the
+    /// collection is small enough to be scanned linearly, returns `None`.
+    #[cfg(feature = "std")]
+    fn by_name(&self) -> Option<&FieldIndex> {
+        if self.by_index.len() <= MAX_LINEAR_SCAN_FIELDS {
+            return None;
+        }
+        Some(self.by_name.get_or_init(|| {
+            let mut by_name = FieldIndex::new();
+            for (index, (name, _value)) in self.by_index.iter().enumerate() {
+                by_name.entry(name.clone()).or_default().push(index);
+            }
+            by_name
+        }))
+    }
+
     /// Gets all of the indexes that contain a value associated with the given field name.
-    fn get_indexes<A: AsSymbolRef>(&self, field_name: A) -> Option<&IndexVec> {
-        field_name
-            .as_symbol_ref()
-            .text()
-            .map(|text| {
+    fn get_indexes<A: AsSymbolRef>(&self, field_name: A) -> Cow<'_, IndexVec> {
+        let field_name = field_name.as_symbol_ref();
+        #[cfg(feature = "std")]
+        if let Some(by_name) = self.by_name() {
+            let indexes = match (field_name.text(), field_name.import_location()) {
                 // If the symbol has defined text, look it up by &str
-                self.by_name.get(text)
-            })
-            .unwrap_or_else(|| {
-                // Otherwise, construct a (cheap, stack-allocated) Symbol with unknown text...
-                let symbol = Symbol::unknown_text();
-                // ...and use the unknown text symbol to look up matching field values
-                self.by_name.get(&symbol)
-            })
-    }
-
-    /// Iterates over the values found at the specified indexes.
-    fn get_values_at_indexes<'a>(&'a self, indexes: &'a IndexVec) -> FieldValuesIterator<'a> {
-        FieldValuesIterator {
-            current: 0,
-            indexes: Some(indexes),
-            by_index: &self.by_index,
+                (Some(text), _) => by_name.get(text),
+                // Otherwise, build the equivalent Symbol with unknown text. Only symbols with
+                // an import location allocate.
+                (None, Some(location)) => {
+                    by_name.get(&Symbol::with_import_location(location.clone()))
+                }
+                (None, None) => by_name.get(&Symbol::unknown_text()),
+            };
+            return indexes.map(Cow::Borrowed).unwrap_or_default();
+        }
+        Cow::Owned(
+            self.by_index
+                .iter()
+                .enumerate()
+                .filter(|(_index, (name, _value))| name.as_symbol_ref() == field_name)
+                .map(|(index, _field)| index)
+                .collect(),
+        )
+    }
+
+    /// Gets the position of the last value in the Struct that is associated with the specified
+    /// field name.
+    fn get_last_index<A: AsSymbolRef>(&self, field_name: A) -> Option<usize> {
+        #[cfg(feature = "std")]
+        if self.by_name().is_some() {
+            return self.get_indexes(field_name).last().copied();
         }
+        let field_name = field_name.as_symbol_ref();
+        self.by_index
+            .iter()
+            .rposition(|(name, _value)| name.as_symbol_ref() == field_name)
     }
 
     /// Gets the last value in the Struct that is associated with the specified field name.
@@ -58,14 +117,13 @@ impl Fields {
     /// the value associated with the last appearance. If your application uses structs that repeat
     /// field names, you are encouraged to use [`get_all`](Self::get_all) instead.
     fn get_last<A: AsSymbolRef>(&self, field_name: A) -> Option<&Element> {
-        self.get_indexes(field_name)
-            .and_then(|indexes| indexes.last())
-            .and_then(|index| self.by_index.get(*index))
+        self.get_last_index(field_name)
+            .and_then(|index| self.by_index.get(index))
             .map(|(_name, value)| value)
     }
 
     /// Iterates over all of the values associated with the given field name.
-    fn get_all<A: AsSymbolRef>(&self, field_name: A) -> FieldValuesIterator {
+    fn get_all<A: AsSymbolRef>(&self, field_name: A) -> FieldValuesIterator<'_> {
         let indexes = self.get_indexes(field_name);
         FieldValuesIterator {
             current: 0,
@@ -74,15 +132,54 @@ impl Fields {
         }
     }
 
+    /// Returns the number of values associated with the given field name.
+    fn count_values(&self, field_name: &Symbol) -> usize {
+        #[cfg(feature = "std")]
+        if self.by_name().is_some() {
+            // Looking up the indexes borrows them from the index, so this does not allocate.
+            return self.get_indexes(field_name).len();
+        }
+        self.by_index
+            .iter()
+            .filter(|(name, _value)| name == field_name)
+            .count()
+    }
+
+    /// Returns `true` if any of the values associated with the given field name is Ion-equal to
+    /// `value`.
+    fn contains_value(&self, field_name: &Symbol, value: &Element) -> bool {
+        #[cfg(feature = "std")]
+        if self.by_name().is_some() {
+            return self
+                .get_all(field_name)
+                .any(|field_value| field_value.ion_eq(value));
+        }
+        self.by_index
+            .iter()
+            .any(|(name, field_value)| name == field_name && field_value.ion_eq(value))
+    }
+
     /// Iterates over all of the (field name, field value) pairs in the struct.
     fn iter(&self) -> impl Iterator<Item = &(Symbol, Element)> {
         self.by_index.iter()
     }
+
+    /// Appends a (field name, field value) pair, updating the name index if it has been built.
+    fn push(&mut self, field_name: Symbol, field_value: Element) {
+        #[cfg(feature = "std")]
+        if let Some(by_name) = self.by_name.get_mut() {
+            by_name
+                .entry(field_name.clone())
+                .or_default()
+                .push(self.by_index.len());
+        }
+        self.by_index.push((field_name, field_value));
+    }
 }
 
 /// Iterates over the (field name, field value) pairs in a Struct.
 pub struct FieldIterator<'a> {
-    values: Option<std::slice::Iter<'a, (Symbol, Element)>>,
+    values: Option<core::slice::Iter<'a, (Symbol, Element)>>,
 }
 
 impl<'a> FieldIterator<'a> {
@@ -111,21 +208,17 @@ impl<'a> Iterator for FieldIterator<'a> {
 /// Iterates over the values associated with a given field name in a Struct.
 pub struct FieldValuesIterator<'a> {
     current: usize,
-    indexes: Option<&'a IndexVec>,
-    by_index: &'a Vec<(Symbol, Element)>,
+    indexes: Cow<'a, IndexVec>,
+    by_index: &'a [(Symbol, Element)],
 }
 
 impl<'a> Iterator for FieldValuesIterator<'a> {
     type Item = &'a Element;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.indexes
-            .and_then(|i| i.get(self.current))
-            .and_then(|i| {
-                self.current += 1;
-                self.by_index.get(*i)
-            })
-            .map(|(_name, value)| value)
+        let index = *self.indexes.get(self.current)?;
+        self.current += 1;
+        self.by_index.get(index).map(|(_name, value)| value)
     }
 }
 
@@ -145,15 +238,35 @@ impl<'a> Iterator for FieldValuesIterator<'a> {
 /// # Ok(())
 /// # }
 /// ```
+///
+/// A `Struct`'s fields are stored behind a reference count, so cloning a `Struct` is O(1)
+/// regardless of its size. Modifying a clone (via [`get_mut`](Self::get_mut),
+/// [`add_field`](Self::add_field), etc.) copies only this struct's fields, and only if they are
+/// still shared; nested containers continue to be shared until they are modified themselves.
+/// ```
+/// use ion_rs::element::Element;
+/// use ion_rs::ion_struct;
+/// let original = ion_struct! {
+///   "config": ion_struct! { "retries": 3 },
+///   "name": "service"
+/// };
+/// let mut modified = original.clone();
+/// let config = modified.get_mut("config").and_then(Element::as_struct_mut).unwrap();
+/// *config.get_mut("retries").unwrap() = Element::integer(5);
+///
+/// // Only the path to the modified field was copied
+/// assert_eq!(original.get("config").unwrap().as_struct().unwrap().get("retries"), Some(&Element::integer(3)));
+/// assert_eq!(modified.get("config").unwrap().as_struct().unwrap().get("retries"), Some(&Element::integer(5)));
+/// ```
 #[derive(Debug, Clone)]
 pub struct Struct {
-    fields: Fields,
+    fields: Arc<Fields>,
 }
 
 impl Display for Struct {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let mut ivf = IonValueFormatter { output: f };
-        ivf.format_struct(self).map_err(|_| std::fmt::Error)?;
+        ivf.format_struct(self).map_err(|_| core::fmt::Error)?;
         Ok(())
     }
 }
@@ -178,28 +291,15 @@ impl Struct {
     }
 
     fn fields_eq(&self, other: &Self) -> bool {
-        // For each field name in `self`, get the list of indexes that contain a value with that name.
-        for (field_name, field_value_indexes) in &self.fields.by_name {
-            let other_value_indexes = match other.fields.get_indexes(field_name) {
-                Some(indexes) => indexes,
-                // The other struct doesn't have a field with this name so they're not equal.
-                None => return false,
-            };
-
-            if field_value_indexes.len() != other_value_indexes.len() {
-                // The other struct has fields with the same name, but a different number of them.
+        for (field_name, field_value) in self.fields.iter() {
+            if self.fields.count_values(field_name) != other.fields.count_values(field_name) {
+                // The other struct has a different number of fields with this name (possibly zero).
                 return false;
             }
 
-            for field_value in self.fields.get_values_at_indexes(field_value_indexes) {
-                if other
-                    .fields
-                    .get_values_at_indexes(other_value_indexes)
-                    .all(|other_value| !field_value.ion_eq(other_value))
-                {
-                    // Couldn't find an equivalent field in the other struct
-                    return false;
-                }
+            if !other.fields.contains_value(field_name, field_value) {
+                // Couldn't find an equivalent field in the other struct
+                return false;
             }
         }
 
@@ -228,6 +328,44 @@ impl Struct {
     pub fn get_all<A: AsSymbolRef>(&self, field_name: A) -> FieldValuesIterator<'_> {
         self.fields.get_all(field_name)
     }
+
+    /// Returns a mutable reference to the last value associated with the specified field name.
+    /// See [`get`](Self::get) for details on how repeated field names are handled.
+    ///
+    /// If this struct's fields are shared with other clones, they will be copied first.
+    pub fn get_mut<A: AsSymbolRef>(&mut self, field_name: A) -> Option<&mut Element> {
+        // Find the index before calling `make_mut` so a missing field doesn't trigger a copy
+        let index = self.fields.get_last_index(field_name)?;
+        Arc::make_mut(&mut self.fields)
+            .by_index
+            .get_mut(index)
+            .map(|(_name, value)| value)
+    }
+
+    /// Returns an iterator over the field name/value pairs in this Struct that allows each value
+    /// to be modified in place.
+    ///
+    /// If this struct's fields are shared with other clones, they will be copied first.
+    pub fn fields_mut(&mut self) -> impl Iterator<Item = (&Symbol, &mut Element)> {
+        Arc::make_mut(&mut self.fields)
+            .by_index
+            .iter_mut()
+            .map(|(name, value)| (&*name, value))
+    }
+
+    /// Appends the provided `(name, value)` pair to this Struct. If a field with the same name
+    /// already exists, it is retained; [`get`](Self::get) will return the newly added value.
+    ///
+    /// If this struct's fields are shared with other clones, they will be copied first.
+    pub fn add_field<S: Into<Symbol>, E: Into<Element>>(&mut self, field_name: S, field_value: E) {
+        Arc::make_mut(&mut self.fields).push(field_name.into(), field_value.into())
+    }
+
+    /// Returns `true` if `self` and `other` share the same underlying storage. Structs that
+    /// share storage are always equal; structs that do not may or may not be.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.fields, &other.fields)
+    }
 }
 
 // Allows `for (name, value) in &my_struct {...}` syntax
@@ -247,21 +385,14 @@ where
 {
     /// Returns an owned struct from the given iterator of field names/values.
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut by_index: Vec<(Symbol, Element)> = Vec::new();
-        let mut by_name: HashMap<Symbol, IndexVec> = HashMap::new();
+        let mut fields = Fields::new(Vec::new());
         for (field_name, field_value) in iter {
-            let field_name = field_name.into();
-            let field_value = field_value.into();
-
-            by_name
-                .entry(field_name.clone())
-                .or_insert_with(IndexVec::new)
-                .push(by_index.len());
-            by_index.push((field_name, field_value));
+            fields.push(field_name.into(), field_value.into());
         }
 
-        let fields = Fields { by_index, by_name };
-        Self { fields }
+        Self {
+            fields: Arc::new(fields),
+        }
     }
 }
 
@@ -281,12 +412,15 @@ impl Eq for Struct {}
 
 impl IonEq for Struct {
     fn ion_eq(&self, other: &Self) -> bool {
-        self == other
+        self.ptr_eq(other) || self == other
     }
 }
 
 impl IonOrd for Struct {
     fn ion_cmp(&self, other: &Self) -> Ordering {
+        if self.ptr_eq(other) {
+            return Ordering::Equal;
+        }
         let mut these_fields = self.fields.by_index.iter().collect::<Vec<_>>();
         let mut those_fields = other.fields.by_index.iter().collect::<Vec<_>>();
         these_fields.sort_by(ion_cmp_field);
@@ -320,8 +454,11 @@ fn ion_cmp_field(this: &&(Symbol, Element), that: &&(Symbol, Element)) -> Orderi
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::element::Element;
     use crate::ion_struct;
+    use crate::types::ImportLocation;
+    use rstest::*;
 
     #[test]
     fn for_field_in_struct() {
@@ -335,4 +472,90 @@ mod tests {
         }
         assert_eq!(baz_value, Some(&Element::integer(3)));
     }
+
+    /// Builds a struct with fields named `field_0` through `field_{n-1}`, each of which has its
+    /// position as its value.
+    fn struct_with_n_fields(n: usize) -> Struct {
+        (0..n)
+            .map(|i| (format!("field_{i}"), Element::integer(i as i64)))
+            .collect()
+    }
+
+    #[rstest]
+    #[case::empty(0)]
+    #[case::linear_scan(MAX_LINEAR_SCAN_FIELDS)]
+    #[case::indexed(MAX_LINEAR_SCAN_FIELDS + 1)]
+    #[case::large(100)]
+    fn get_by_name(#[case] num_fields: usize) {
+        let s = struct_with_n_fields(num_fields);
+        for i in 0..num_fields {
+            let name = format!("field_{i}");
+            assert_eq!(s.get(name.as_str()), Some(&Element::integer(i as i64)));
+            assert_eq!(s.get_all(name.as_str()).count(), 1);
+        }
+        assert_eq!(s.get("missing"), None);
+        assert_eq!(s.get_all("missing").count(), 0);
+    }
+
+    #[rstest]
+    #[case::linear_scan(2)]
+    #[case::indexed(MAX_LINEAR_SCAN_FIELDS * 2)]
+    fn get_repeated_field_name(#[case] num_fields: usize) {
+        let mut s = struct_with_n_fields(num_fields);
+        // Look up a field before and after modification to make sure a previously built index
+        // is kept up to date.
+        assert_eq!(s.get("field_0"), Some(&Element::integer(0)));
+        s.add_field("field_0", "hello");
+        s.add_field(Symbol::unknown_text(), "goodbye");
+        assert_eq!(s.get("field_0"), Some(&Element::string("hello")));
+        let values: Vec<_> = s.get_all("field_0").collect();
+        assert_eq!(
+            values,
+            vec![&Element::integer(0), &Element::string("hello")]
+        );
+        assert_eq!(
+            s.get(Symbol::unknown_text()),
+            Some(&Element::string("goodbye"))
+        );
+    }
+
+    #[test]
+    fn equality_does_not_depend_on_indexing() {
+        let s1 = struct_with_n_fields(MAX_LINEAR_SCAN_FIELDS * 2);
+        // Build an equivalent struct with the fields in reverse order
+        let s2: Struct = s1.fields().collect::<Vec<_>>().into_iter().rev().collect();
+        // Force `s1` (but not `s2`) to build its name index
+        assert!(s1.get("field_0").is_some());
+        assert_eq!(s1, s2);
+        assert_eq!(s2, s1);
+        assert_ne!(s1, struct_with_n_fields(MAX_LINEAR_SCAN_FIELDS * 2 - 1));
+    }
+
+    #[rstest]
+    #[case::linear_scan(0)]
+    #[case::indexed(MAX_LINEAR_SCAN_FIELDS)]
+    fn unknown_text_field_names_are_distinguished_by_import_location(
+        #[case] num_other_fields: usize,
+    ) {
+        let a = Symbol::with_import_location(ImportLocation::new("shared", 1));
+        let b = Symbol::with_import_location(ImportLocation::new("shared", 2));
+        let struct_with_field = |name: &Symbol| {
+            let mut s = struct_with_n_fields(num_other_fields);
+            s.add_field(name.clone(), 1);
+            s
+        };
+
+        let s = struct_with_field(&a);
+        assert_eq!(s.get(&a), Some(&Element::integer(1)));
+        assert_eq!(s.get_all(&a).count(), 1);
+        assert_eq!(s.get(&b), None);
+        assert_eq!(s.get(Symbol::unknown_text()), None);
+        assert_eq!(s, struct_with_field(&a));
+        assert_ne!(s, struct_with_field(&b));
+        assert_ne!(s, struct_with_field(&Symbol::unknown_text()));
+
+        let s = struct_with_field(&Symbol::unknown_text());
+        assert_eq!(s.get(Symbol::unknown_text()), Some(&Element::integer(1)));
+        assert_eq!(s.get(&a), None);
+    }
 }
@@ -1,40 +1,90 @@
 use crate::ion_data::{IonEq, IonOrd};
 use crate::result::decoding_error;
+use crate::types::{intern_string, SymbolId};
 use crate::IonResult;
-use std::borrow::Borrow;
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
-use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+
+/// Identifies a symbol with unknown text by the shared symbol table that defined it and its
+/// position within that table. Symbols that are imported from a shared symbol table that is not
+/// available to the reader have an import location but no text.
+///
+/// For more information, see the
+/// [Symbols section of the Ion spec](https://amazon-ion.github.io/ion-docs/docs/symbols.html#symboltokens).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ImportLocation {
+    table_name: Arc<str>,
+    sid: SymbolId,
+}
+
+impl ImportLocation {
+    /// Constructs an `ImportLocation` for the symbol with ID `sid` in the shared symbol table
+    /// named `table_name`. Symbol IDs within a shared symbol table start at 1.
+    pub fn new<A: Into<Arc<str>>>(table_name: A, sid: SymbolId) -> ImportLocation {
+        ImportLocation {
+            table_name: table_name.into(),
+            sid,
+        }
+    }
+
+    /// Returns the name of the shared symbol table that defines the symbol.
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Returns the symbol's ID within the shared symbol table.
+    pub fn sid(&self) -> SymbolId {
+        self.sid
+    }
+}
+
+impl Display for ImportLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}#{}", self.table_name, self.sid)
+    }
+}
 
 /// Stores or points to the text of a given [Symbol].
+///
+/// Known text is always stored in a reference-counted `str`. This keeps `Symbol` to two words
+/// (the `Unknown` case fits in the `Arc`'s null pointer niche) and makes cloning a `Symbol`
+/// cheap, which matters because field names and annotations are cloned frequently.
 #[derive(Debug, Eq)]
 enum SymbolText {
-    // This Symbol refers to a string in the symbol table
+    // This Symbol's text may be shared with a symbol table or other Symbols
     Shared(Arc<str>),
-    // This Symbol owns its own text
-    Owned(String),
-    // This Symbol is equivalent to SID zero (`$0`)
-    Unknown,
+    // This Symbol's text is unknown. If it has no import location, it is equivalent to SID
+    // zero (`$0`).
+    Unknown(Option<Arc<ImportLocation>>),
 }
 
 impl SymbolText {
     fn text(&self) -> Option<&str> {
         let text = match self {
             SymbolText::Shared(s) => s.as_ref(),
-            SymbolText::Owned(s) => s.as_str(),
-            SymbolText::Unknown => return None,
+            SymbolText::Unknown(_) => return None,
         };
         Some(text)
     }
+
+    fn import_location(&self) -> Option<&ImportLocation> {
+        match self {
+            SymbolText::Unknown(Some(location)) => Some(location.as_ref()),
+            _ => None,
+        }
+    }
 }
 
 impl Hash for SymbolText {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
             SymbolText::Shared(text) => text.hash(state),
-            SymbolText::Owned(text) => text.hash(state),
-            SymbolText::Unknown => 0.hash(state),
+            SymbolText::Unknown(None) => 0.hash(state),
+            SymbolText::Unknown(Some(location)) => location.hash(state),
         }
     }
 }
@@ -42,9 +92,8 @@ impl Hash for SymbolText {
 impl Clone for SymbolText {
     fn clone(&self) -> Self {
         match self {
-            SymbolText::Owned(text) => SymbolText::Owned(text.to_owned()),
             SymbolText::Shared(text) => SymbolText::Shared(Arc::clone(text)),
-            SymbolText::Unknown => SymbolText::Unknown,
+            SymbolText::Unknown(location) => SymbolText::Unknown(location.clone()),
         }
     }
 }
@@ -64,28 +113,38 @@ impl PartialOrd<Self> for SymbolText {
 impl Ord for SymbolText {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self.text(), other.text()) {
+            // Interned symbols with the same text share it, so check the pointers first.
+            (Some(s1), Some(s2)) if core::ptr::eq(s1, s2) => Ordering::Equal,
             // If both Symbols have known text, delegate the comparison to their text.
             (Some(s1), Some(s2)) => s1.cmp(s2),
             // Otherwise, $0 (unknown text) is treated as 'less than' known text
             (Some(_), None) => Ordering::Greater,
             (None, Some(_)) => Ordering::Less,
-            (None, None) => Ordering::Equal,
+            // Symbols with unknown text are ordered by their import locations; $0 (which has
+            // no import location) comes first.
+            (None, None) => self.import_location().cmp(&other.import_location()),
         }
     }
 }
 
 /// The text of a fully resolved field name, annotation, or symbol value. If the symbol has known
-/// text (that is: the symbol is not `$0`), it will be stored as either a `String` or a shared
-/// reference to text in a symbol table.
+/// text (that is: the symbol is not `$0`), it will be stored in a reference-counted `str` that may
+/// be shared with a symbol table.
+///
+/// A symbol with unknown text may have an [`ImportLocation`] identifying the shared symbol table
+/// entry that it came from. Two symbols with unknown text are only equal if their import
+/// locations are equal.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct Symbol {
     text: SymbolText,
 }
 
 impl Symbol {
+    /// Constructs a Symbol with the given text. If a [`SymbolInterner`](crate::types::SymbolInterner)
+    /// is active, the text is shared with the other symbols that it has interned.
     pub fn owned<I: Into<String>>(text: I) -> Symbol {
         Symbol {
-            text: SymbolText::Owned(text.into()),
+            text: SymbolText::Shared(intern_string(text.into())),
         }
     }
 
@@ -97,7 +156,15 @@ impl Symbol {
 
     pub fn unknown_text() -> Symbol {
         Symbol {
-            text: SymbolText::Unknown,
+            text: SymbolText::Unknown(None),
+        }
+    }
+
+    /// Constructs a Symbol with unknown text that was defined at `location` in a shared symbol
+    /// table.
+    pub fn with_import_location(location: ImportLocation) -> Symbol {
+        Symbol {
+            text: SymbolText::Unknown(Some(Arc::new(location))),
         }
     }
 
@@ -105,6 +172,12 @@ impl Symbol {
         self.text.text()
     }
 
+    /// If this Symbol has unknown text and was imported from a shared symbol table, returns the
+    /// location of its definition in that table. Otherwise, returns `None`.
+    pub fn import_location(&self) -> Option<&ImportLocation> {
+        self.text.import_location()
+    }
+
     pub fn text_or_error(&self) -> IonResult<&str> {
         match self.text() {
             Some(text) => Ok(text),
@@ -154,10 +227,11 @@ impl<'a> From<&'a Symbol> for Symbol {
 }
 
 impl Display for Symbol {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self.text() {
-            None => write!(f, "$0"),
-            Some(text) => write!(f, "'{text}'"),
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (self.text(), self.import_location()) {
+            (Some(text), _) => write!(f, "'{text}'"),
+            (None, Some(location)) => write!(f, "$0 ({location})"),
+            (None, None) => write!(f, "$0"),
         }
     }
 }
@@ -218,4 +292,42 @@ mod symbol_tests {
         ];
         assert_eq!(symbols, expected)
     }
+
+    #[test]
+    fn unknown_text_with_import_location() {
+        let foo_1 = Symbol::with_import_location(ImportLocation::new("foo", 1));
+        let foo_2 = Symbol::with_import_location(ImportLocation::new("foo", 2));
+        let bar_1 = Symbol::with_import_location(ImportLocation::new("bar", 1));
+        let location = foo_1.import_location().unwrap();
+        assert_eq!((location.table_name(), location.sid()), ("foo", 1));
+        assert_eq!(foo_1.text(), None);
+        assert_eq!(Symbol::unknown_text().import_location(), None);
+        assert_eq!(Symbol::owned("foo").import_location(), None);
+
+        assert_eq!(
+            foo_1,
+            Symbol::with_import_location(ImportLocation::new("foo", 1))
+        );
+        assert!(foo_1.ion_eq(&foo_1.clone()));
+        assert!(!foo_1.ion_eq(&foo_2));
+        assert!(!foo_1.ion_eq(&Symbol::unknown_text()));
+
+        let mut symbols = vec![
+            Symbol::owned("a"),
+            foo_2.clone(),
+            foo_1.clone(),
+            Symbol::unknown_text(),
+            bar_1.clone(),
+        ];
+        symbols.sort();
+        let expected = vec![
+            Symbol::unknown_text(),
+            bar_1,
+            foo_1,
+            foo_2,
+            Symbol::owned("a"),
+        ];
+        assert_eq!(symbols, expected);
+        assert_eq!(expected[1].to_string(), "$0 (bar#1)");
+    }
 }
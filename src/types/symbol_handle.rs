@@ -0,0 +1,54 @@
+use alloc::format;
+
+use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
+use crate::result::{illegal_operation, IonResult};
+use crate::types::SymbolId;
+
+/// A symbol that was added to a [`BinaryWriter`](crate::BinaryWriter)'s local symbol table in
+/// advance by [`BinaryWriter::register_symbol`](crate::BinaryWriter::register_symbol). Passing a
+/// handle to that writer's `set_field_name`, `set_annotations` or `write_symbol` writes its symbol
+/// ID without looking up its text.
+///
+/// A handle can only be used with the writer that registered it, and only until that writer
+/// resets its local symbol table. (See
+/// [`BinaryWriterBuilder::with_max_local_symbols`](crate::BinaryWriterBuilder::with_max_local_symbols)
+/// and
+/// [`BinaryWriterBuilder::with_symbol_table_reset_interval`](crate::BinaryWriterBuilder::with_symbol_table_reset_interval).)
+/// Using it afterward, or with any other writer, is an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SymbolHandle {
+    symbol_id: SymbolId,
+    epoch: usize,
+}
+
+impl SymbolHandle {
+    pub(crate) fn new(symbol_id: SymbolId, epoch: usize) -> SymbolHandle {
+        SymbolHandle { symbol_id, epoch }
+    }
+
+    /// Returns the symbol ID that the handle's text was assigned in the local symbol table.
+    pub fn symbol_id(&self) -> SymbolId {
+        self.symbol_id
+    }
+
+    /// Identifies the local symbol table with which the handle was registered.
+    pub(crate) fn epoch(&self) -> usize {
+        self.epoch
+    }
+}
+
+/// Returns an `Err` if `token` is a [`SymbolHandle`]. Writers other than the [`BinaryWriter`](crate::BinaryWriter)
+/// that registered a handle do not have the local symbol table that defines its symbol ID, so
+/// writing the ID on its own would produce a symbol with unknown text.
+pub(crate) fn reject_symbol_handle<A: AsRawSymbolTokenRef>(
+    token: &A,
+    writer_name: &str,
+) -> IonResult<()> {
+    match token.symbol_handle() {
+        Some(handle) => illegal_operation(format!(
+            "The {writer_name} cannot write SymbolHandle ${}; a handle can only be used with the BinaryWriter that registered it.",
+            handle.symbol_id()
+        )),
+        None => Ok(()),
+    }
+}
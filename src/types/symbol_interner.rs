@@ -0,0 +1,278 @@
+//! Deduplication of symbol text across symbol tables, readers and `Element`s.
+//!
+//! By default, each [`Symbol`] created with [`Symbol::owned`] (or one of the `From` conversions)
+//! allocates its own copy of its text, and each reader's symbol table holds its own copies of the
+//! symbols that it has read. Applications that keep many values from many streams in memory can
+//! end up storing the same handful of field names millions of times.
+//!
+//! A [`SymbolInterner`] keeps a single shared allocation for each distinct text. Interning can be
+//! enabled for the whole process with [`SymbolInterner::set_global_enabled`] or for the duration
+//! of a closure on the current thread with [`SymbolInterner::scope`]. While interning is active,
+//! `Symbol::owned`, the `From` conversions and the symbol tables used by readers all resolve
+//! their text through the interner, so equal symbols share their text and can be compared by
+//! pointer.
+
+use crate::types::Symbol;
+use alloc::string::String;
+use once_cell::sync::Lazy;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+static GLOBAL_INTERNER: Lazy<SymbolInterner> = Lazy::new(SymbolInterner::new);
+static GLOBAL_INTERNING_ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// The interner installed by the innermost call to [`SymbolInterner::scope`] on this thread.
+    static SCOPED_INTERNER: RefCell<Option<Arc<SymbolInterner>>> = const { RefCell::new(None) };
+}
+
+/// A set of symbol texts, each of which is stored in a single shared allocation.
+///
+/// ```
+/// use ion_rs::types::SymbolInterner;
+/// use std::sync::Arc;
+///
+/// let interner = Arc::new(SymbolInterner::new());
+/// let (first, second) = SymbolInterner::scope(&interner, || {
+///     (ion_rs::Symbol::owned("name"), ion_rs::Symbol::from("name"))
+/// });
+/// assert_eq!(first, second);
+/// // Both symbols refer to the copy of "name" that is held by the interner.
+/// assert_eq!(interner.len(), 1);
+/// assert_eq!(first.text().unwrap().as_ptr(), second.text().unwrap().as_ptr());
+/// ```
+#[derive(Debug, Default)]
+pub struct SymbolInterner {
+    texts: Mutex<HashSet<Arc<str>>>,
+}
+
+impl SymbolInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the process-wide interner. Symbols are only interned into it automatically while
+    /// global interning is enabled; see [`SymbolInterner::set_global_enabled`].
+    pub fn global() -> &'static SymbolInterner {
+        &GLOBAL_INTERNER
+    }
+
+    /// Enables or disables interning of all symbol text into the [global](Self::global)
+    /// interner. Disabling global interning does not release the texts that have already been
+    /// interned; use [`SymbolInterner::remove_unused`] for that.
+    pub fn set_global_enabled(enabled: bool) {
+        GLOBAL_INTERNING_ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if global interning is enabled.
+    pub fn is_global_enabled() -> bool {
+        GLOBAL_INTERNING_ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// Calls `f`, interning all of the symbol text created on the current thread in the meantime
+    /// into `interner`. A scoped interner takes precedence over the global one. Scopes can be
+    /// nested; the previous interner is restored when `f` returns or panics.
+    pub fn scope<R>(interner: &Arc<SymbolInterner>, f: impl FnOnce() -> R) -> R {
+        struct RestoreOnDrop(Option<Arc<SymbolInterner>>);
+
+        impl Drop for RestoreOnDrop {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                SCOPED_INTERNER.with(|scoped| *scoped.borrow_mut() = previous);
+            }
+        }
+
+        let previous =
+            SCOPED_INTERNER.with(|scoped| scoped.borrow_mut().replace(Arc::clone(interner)));
+        let _restore = RestoreOnDrop(previous);
+        f()
+    }
+
+    /// Returns the shared copy of `text`, adding it to the interner if necessary.
+    pub fn intern(&self, text: &str) -> Arc<str> {
+        let mut texts = self.texts();
+        if let Some(interned) = texts.get(text) {
+            return Arc::clone(interned);
+        }
+        let interned: Arc<str> = Arc::from(text);
+        texts.insert(Arc::clone(&interned));
+        interned
+    }
+
+    /// Returns a [`Symbol`] whose text is the shared copy of `text`.
+    pub fn symbol(&self, text: &str) -> Symbol {
+        Symbol::shared(self.intern(text))
+    }
+
+    /// Returns the number of distinct texts held by the interner.
+    pub fn len(&self) -> usize {
+        self.texts().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Releases each text that is not referenced by anything other than the interner itself.
+    pub fn remove_unused(&self) {
+        self.texts().retain(|text| Arc::strong_count(text) > 1);
+    }
+
+    fn texts(&self) -> MutexGuard<'_, HashSet<Arc<str>>> {
+        // The set is never left in an inconsistent state, so a panic on another thread while it
+        // held the lock does not prevent us from using it.
+        self.texts.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Calls `f` with the interner that is active on the current thread, if any.
+fn with_active_interner<R>(f: impl FnOnce(Option<&SymbolInterner>) -> R) -> R {
+    SCOPED_INTERNER.with(|scoped| match scoped.borrow().as_deref() {
+        Some(interner) => f(Some(interner)),
+        None if SymbolInterner::is_global_enabled() => f(Some(SymbolInterner::global())),
+        None => f(None),
+    })
+}
+
+/// Converts `text` to an `Arc<str>`, using the active interner if there is one.
+pub(crate) fn intern_str(text: &str) -> Arc<str> {
+    with_active_interner(|interner| match interner {
+        Some(interner) => interner.intern(text),
+        None => Arc::from(text),
+    })
+}
+
+/// Converts `text` to an `Arc<str>`, using the active interner if there is one.
+pub(crate) fn intern_string(text: String) -> Arc<str> {
+    with_active_interner(|interner| match interner {
+        Some(interner) => interner.intern(&text),
+        None => Arc::from(text),
+    })
+}
+
+#[cfg(test)]
+mod symbol_interner_tests {
+    use super::*;
+    use crate::element::Element;
+    use crate::IonResult;
+    use std::thread;
+
+    fn same_text(first: &Symbol, second: &Symbol) -> bool {
+        first.text().unwrap().as_ptr() == second.text().unwrap().as_ptr()
+    }
+
+    #[test]
+    fn intern_returns_shared_text() {
+        let interner = SymbolInterner::new();
+        let first = interner.intern("foo");
+        let second = interner.intern("foo");
+        let other = interner.intern("bar");
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn scoped_symbols_share_text() {
+        let interner = Arc::new(SymbolInterner::new());
+        let (owned, from_string, from_str) = SymbolInterner::scope(&interner, || {
+            (
+                Symbol::owned("foo"),
+                Symbol::from(String::from("foo")),
+                Symbol::from("foo"),
+            )
+        });
+        assert!(same_text(&owned, &from_string));
+        assert!(same_text(&owned, &from_str));
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn nested_scopes_restore_the_previous_interner() {
+        let outer = Arc::new(SymbolInterner::new());
+        let inner = Arc::new(SymbolInterner::new());
+        SymbolInterner::scope(&outer, || {
+            let _ = Symbol::owned("outer");
+            SymbolInterner::scope(&inner, || {
+                let _ = Symbol::owned("inner");
+            });
+            let _ = Symbol::owned("outer again");
+        });
+        assert_eq!(outer.len(), 2);
+        assert_eq!(inner.len(), 1);
+        assert!(SCOPED_INTERNER.with(|scoped| scoped.borrow().is_none()));
+    }
+
+    #[test]
+    fn scope_is_restored_after_a_panic() {
+        let interner = Arc::new(SymbolInterner::new());
+        let result = std::panic::catch_unwind(|| SymbolInterner::scope(&interner, || panic!()));
+        assert!(result.is_err());
+        assert!(SCOPED_INTERNER.with(|scoped| scoped.borrow().is_none()));
+    }
+
+    #[test]
+    fn scope_is_per_thread() {
+        let interner = Arc::new(SymbolInterner::new());
+        SymbolInterner::scope(&interner, || {
+            thread::spawn(|| {
+                let _ = Symbol::owned("other thread");
+            })
+            .join()
+            .unwrap();
+        });
+        assert!(interner.is_empty());
+    }
+
+    #[test]
+    fn elements_from_different_streams_share_field_names() -> IonResult<()> {
+        let interner = Arc::new(SymbolInterner::new());
+        let (text, binary) = SymbolInterner::scope(&interner, || -> IonResult<_> {
+            let text = Element::read_one("{color: red}")?;
+            // $ion_symbol_table::{symbols: ["color", "red"]} {color: red}
+            let binary = Element::read_one([
+                0xE0, 0x01, 0x00, 0xEA, 0xEE, 0x8F, 0x81, 0x83, 0xDC, 0x87, 0xBA, 0x85, 0x63, 0x6F,
+                0x6C, 0x6F, 0x72, 0x83, 0x72, 0x65, 0x64, 0xD3, 0x8A, 0x71, 0x0B,
+            ])?;
+            Ok((text, binary))
+        })?;
+        let text = text.as_struct().unwrap();
+        let binary = binary.as_struct().unwrap();
+        let (text_name, text_value) = text.iter().next().unwrap();
+        let (binary_name, binary_value) = binary.iter().next().unwrap();
+        assert!(same_text(text_name, binary_name));
+        assert!(same_text(
+            text_value.as_symbol().unwrap(),
+            binary_value.as_symbol().unwrap()
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn remove_unused() {
+        let interner = Arc::new(SymbolInterner::new());
+        let kept = SymbolInterner::scope(&interner, || {
+            let _ = Symbol::owned("dropped");
+            Symbol::owned("kept")
+        });
+        assert_eq!(interner.len(), 2);
+        interner.remove_unused();
+        assert_eq!(interner.len(), 1);
+        assert!(same_text(&kept, &interner.symbol("kept")));
+    }
+
+    #[test]
+    fn global_interning() {
+        SymbolInterner::set_global_enabled(true);
+        let first = Symbol::owned("a globally interned symbol");
+        let second = Symbol::owned("a globally interned symbol");
+        SymbolInterner::set_global_enabled(false);
+        assert!(same_text(&first, &second));
+        assert!(same_text(
+            &first,
+            &SymbolInterner::global().symbol("a globally interned symbol")
+        ));
+    }
+}
@@ -0,0 +1,554 @@
+//! Arithmetic, Unix epoch conversions, and instant comparisons for [`Timestamp`].
+//!
+//! Every operation here works on the exact instant that a Timestamp represents. Fractional
+//! seconds are handled as [`Decimal`]s, so digits beyond nanoseconds are never truncated.
+
+use super::{offset_east, Precision, Timestamp, TimestampBuilder, MAX_YEAR, MIN_YEAR};
+use crate::result::{illegal_operation, illegal_operation_raw, IonError, IonResult};
+use crate::types::{Decimal, RoundingMode};
+use alloc::format;
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Months, TimeZone};
+use core::cmp::Ordering;
+
+const SECONDS_PER_MINUTE: i64 = 60;
+const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+const MAX_NANOSECOND_DIGITS: i64 = 9;
+
+impl Timestamp {
+    /// Creates a UTC Timestamp with [`Precision::Second`] from a number of seconds since the
+    /// Unix epoch (`1970-01-01T00:00:00Z`).
+    pub fn from_unix_seconds(seconds: i64) -> IonResult<Timestamp> {
+        Timestamp::from_unix_seconds_decimal(&Decimal::from(seconds))
+    }
+
+    /// Creates a UTC Timestamp with millisecond precision from a number of milliseconds since
+    /// the Unix epoch.
+    pub fn from_unix_millis(milliseconds: i64) -> IonResult<Timestamp> {
+        Timestamp::from_unix_seconds_decimal(&Decimal::new(milliseconds, -3))
+    }
+
+    /// Creates a UTC Timestamp with nanosecond precision from a number of nanoseconds since
+    /// the Unix epoch.
+    pub fn from_unix_nanos(nanoseconds: i128) -> IonResult<Timestamp> {
+        Timestamp::from_unix_seconds_decimal(&Decimal::new(nanoseconds, -9))
+    }
+
+    /// Creates a UTC Timestamp with [`Precision::Second`] from a number of seconds since the
+    /// Unix epoch. The Timestamp's fractional seconds have as many digits as the scale of
+    /// `seconds`, including any digits beyond nanoseconds.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::types::{Decimal, Timestamp};
+    /// let seconds = Decimal::new(1_000_000_000_123_456_789_012i128, -12);
+    /// let timestamp = Timestamp::from_unix_seconds_decimal(&seconds)?;
+    /// assert_eq!(timestamp.to_string(), "2001-09-09T01:46:40.123456789012+00:00");
+    /// assert_eq!(timestamp.unix_seconds_decimal(), seconds);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_unix_seconds_decimal(seconds: &Decimal) -> IonResult<Timestamp> {
+        Timestamp::from_instant(seconds, Some(offset_east(0)), Precision::Second)
+    }
+
+    /// Returns the number of whole seconds between the Unix epoch and this Timestamp, rounding
+    /// toward negative infinity.
+    pub fn unix_seconds(&self) -> i64 {
+        self.date_time.and_utc().timestamp()
+    }
+
+    /// Returns the number of whole milliseconds between the Unix epoch and this Timestamp,
+    /// rounding toward negative infinity.
+    pub fn unix_millis(&self) -> i64 {
+        self.unix_seconds() * 1_000 + self.fraction_in_units_of(-3)
+    }
+
+    /// Returns the number of whole nanoseconds between the Unix epoch and this Timestamp,
+    /// rounding toward negative infinity.
+    pub fn unix_nanos(&self) -> i128 {
+        self.unix_seconds() as i128 * 1_000_000_000 + self.fraction_in_units_of(-9) as i128
+    }
+
+    /// Returns the exact number of seconds between the Unix epoch and this Timestamp. The
+    /// result has the same scale as this Timestamp's fractional seconds.
+    pub fn unix_seconds_decimal(&self) -> Decimal {
+        let seconds = Decimal::from(self.unix_seconds());
+        match self.fractional_seconds_as_decimal() {
+            Some(fraction) => seconds
+                .checked_add(&fraction)
+                .expect("fractional seconds have a representable exponent"),
+            None => seconds,
+        }
+    }
+
+    /// Returns the exact number of seconds that elapsed between `earlier` and this Timestamp.
+    /// The result is negative if `earlier` is actually later than `self`.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::types::{Decimal, Timestamp};
+    /// let start = Timestamp::with_ymd_hms(2023, 3, 1, 12, 0, 0).build_at_offset(0)?;
+    /// let end = Timestamp::with_ymd_hms(2023, 3, 1, 7, 0, 1)
+    ///     .with_milliseconds(500)
+    ///     .build_at_offset(-5 * 60)?;
+    /// assert_eq!(end.seconds_since(&start), Decimal::new(1500, -3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn seconds_since(&self, earlier: &Timestamp) -> Decimal {
+        self.unix_seconds_decimal()
+            .checked_sub(&earlier.unix_seconds_decimal())
+            .expect("fractional seconds have a representable exponent")
+    }
+
+    /// Compares the instants represented by two Timestamps, ignoring their precision and offset
+    /// entirely: `2023T`, `2023-01-01T00:00Z` and `2023-01-01T00:00:00.000-00:00` all represent
+    /// the same instant. This is the same ordering as Timestamp's implementation of [`Ord`].
+    pub fn instant_cmp(&self, other: &Timestamp) -> Ordering {
+        self.cmp(other)
+    }
+
+    /// Returns `true` if both Timestamps represent the same instant, regardless of their
+    /// precision or offset. See [`Timestamp::instant_cmp`].
+    pub fn instant_eq(&self, other: &Timestamp) -> bool {
+        self.instant_cmp(other) == Ordering::Equal
+    }
+
+    /// Adds the specified number of years to this Timestamp's local date. If the resulting
+    /// month is too short to contain the original day (as when adding a year to February 29th),
+    /// the last day of that month is used instead. The precision is unchanged.
+    pub fn checked_add_years(&self, years: i32) -> IonResult<Timestamp> {
+        let months = years.checked_mul(12).ok_or_else(out_of_range)?;
+        let mut timestamp = self.checked_add_months(months)?;
+        timestamp.precision = self.precision;
+        Ok(timestamp)
+    }
+
+    /// Adds the specified number of months to this Timestamp's local date. If the resulting
+    /// month is too short to contain the original day, the last day of that month is used
+    /// instead. Timestamps with [`Precision::Year`] are promoted to [`Precision::Month`];
+    /// otherwise the precision is unchanged.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::types::Timestamp;
+    /// let month = Timestamp::with_year(2023).with_month(11).build()?;
+    /// assert_eq!(month.checked_add_months(3)?.to_string(), "2024-02T");
+    ///
+    /// let day = Timestamp::with_ymd(2024, 1, 31).build()?;
+    /// assert_eq!(day.checked_add_months(1)?.to_string(), "2024-02-29T");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_add_months(&self, months: i32) -> IonResult<Timestamp> {
+        // Months have different lengths, so this is computed using the local date's fields
+        // rather than the instant.
+        let offset = self.offset.unwrap_or_else(|| offset_east(0));
+        let local = offset.from_utc_datetime(&self.date_time).naive_local();
+        let shifted = if months >= 0 {
+            local.checked_add_months(Months::new(months as u32))
+        } else {
+            local.checked_sub_months(Months::new(months.unsigned_abs()))
+        };
+        let date_time = shifted
+            .filter(|local| (MIN_YEAR..=MAX_YEAR).contains(&local.year()))
+            .and_then(|local| offset.from_local_datetime(&local).single())
+            .ok_or_else(out_of_range)?
+            .naive_utc();
+        Ok(Timestamp {
+            date_time,
+            offset: self.offset,
+            precision: self.precision.max(Precision::Month),
+            fractional_seconds: self.fractional_seconds.clone(),
+        })
+    }
+
+    /// Adds the specified number of days to this Timestamp. Timestamps with less than
+    /// [`Precision::Day`] are promoted to it; otherwise the precision is unchanged.
+    pub fn checked_add_days(&self, days: i64) -> IonResult<Timestamp> {
+        let seconds = days.checked_mul(SECONDS_PER_DAY).ok_or_else(out_of_range)?;
+        self.add_whole_seconds(seconds, Precision::Day)
+    }
+
+    /// Adds the specified number of hours to this Timestamp. Timestamps with less than
+    /// [`Precision::HourAndMinute`] are promoted to it; otherwise the precision is unchanged.
+    pub fn checked_add_hours(&self, hours: i64) -> IonResult<Timestamp> {
+        let seconds = hours
+            .checked_mul(SECONDS_PER_HOUR)
+            .ok_or_else(out_of_range)?;
+        self.add_whole_seconds(seconds, Precision::HourAndMinute)
+    }
+
+    /// Adds the specified number of minutes to this Timestamp. Timestamps with less than
+    /// [`Precision::HourAndMinute`] are promoted to it; otherwise the precision is unchanged.
+    pub fn checked_add_minutes(&self, minutes: i64) -> IonResult<Timestamp> {
+        let seconds = minutes
+            .checked_mul(SECONDS_PER_MINUTE)
+            .ok_or_else(out_of_range)?;
+        self.add_whole_seconds(seconds, Precision::HourAndMinute)
+    }
+
+    /// Adds the specified (possibly fractional) number of seconds to this Timestamp. The result
+    /// has [`Precision::Second`] and as many fractional digits as the more precise of `self`
+    /// and `seconds`.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::types::{Decimal, Timestamp};
+    /// let timestamp = Timestamp::with_ymd_hms(2023, 12, 31, 23, 59, 59)
+    ///     .with_milliseconds(900)
+    ///     .build_at_offset(0)?;
+    /// let later = timestamp.checked_add_seconds(&Decimal::new(1_000_000_000_001i64, -12))?;
+    /// assert_eq!(later.to_string(), "2024-01-01T00:00:00.900000000001+00:00");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_add_seconds(&self, seconds: &Decimal) -> IonResult<Timestamp> {
+        let instant = self
+            .unix_seconds_decimal()
+            .checked_add(seconds)
+            .ok_or_else(out_of_range)?;
+        Timestamp::from_instant(&instant, self.offset, Precision::Second)
+    }
+
+    /// Adds a [`Duration`] to this Timestamp. This is equivalent to calling
+    /// [`Timestamp::checked_add_seconds`] with the duration's length, so the result has
+    /// [`Precision::Second`]. Only as many fractional digits as the duration needs are
+    /// added; a duration of exactly 2 seconds does not give the result nanosecond precision.
+    pub fn checked_add_duration(&self, duration: Duration) -> IonResult<Timestamp> {
+        let mut coefficient =
+            duration.num_seconds() as i128 * 1_000_000_000 + duration.subsec_nanos() as i128;
+        let mut exponent = -MAX_NANOSECOND_DIGITS;
+        while exponent < 0 && coefficient % 10 == 0 {
+            coefficient /= 10;
+            exponent += 1;
+        }
+        self.checked_add_seconds(&Decimal::new(coefficient, exponent))
+    }
+
+    /// Adds a whole number of seconds to this Timestamp, promoting it to at least `precision`.
+    fn add_whole_seconds(&self, seconds: i64, precision: Precision) -> IonResult<Timestamp> {
+        let instant = self
+            .unix_seconds_decimal()
+            .checked_add(&Decimal::from(seconds))
+            .ok_or_else(out_of_range)?;
+        Timestamp::from_instant(&instant, self.offset, self.precision.max(precision))
+    }
+
+    /// Returns this Timestamp's fractional seconds as a whole number of units of
+    /// `10^exponent` seconds, truncating any remainder.
+    fn fraction_in_units_of(&self, exponent: i64) -> i64 {
+        self.fraction()
+            .rescale(exponent, RoundingMode::Floor)
+            .coefficient
+            .as_i64()
+            .expect("fractional seconds are less than 1")
+    }
+
+    /// Constructs a Timestamp representing the instant `seconds` after the Unix epoch.
+    fn from_instant(
+        seconds: &Decimal,
+        offset: Option<FixedOffset>,
+        precision: Precision,
+    ) -> IonResult<Timestamp> {
+        // Split the instant into a whole number of seconds and a fraction in the range [0, 1).
+        let whole = seconds
+            .checked_rescale(0, RoundingMode::Floor)
+            .ok_or_else(out_of_range)?;
+        let fraction = seconds.checked_sub(&whole).ok_or_else(out_of_range)?;
+        let date_time = whole
+            .coefficient
+            .as_i64()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            .ok_or_else(out_of_range)?
+            .naive_utc();
+        let local_year = offset
+            .unwrap_or_else(|| offset_east(0))
+            .from_utc_datetime(&date_time)
+            .year();
+        if !(MIN_YEAR..=MAX_YEAR).contains(&local_year) {
+            return illegal_operation(format!(
+                "Timestamp year '{local_year}' out of range (1-9999)"
+            ));
+        }
+
+        let fractional_seconds = if precision < Precision::Second {
+            None
+        } else {
+            TimestampBuilder::validate_fractional_seconds(fraction)?
+        };
+        Ok(Timestamp {
+            date_time,
+            offset,
+            precision,
+            fractional_seconds,
+        })
+    }
+}
+
+fn out_of_range() -> IonError {
+    illegal_operation_raw("Timestamp arithmetic produced a value outside the range 0001T to 9999T")
+}
+
+#[cfg(test)]
+mod arithmetic_tests {
+    use super::*;
+    use crate::ion_data::IonEq;
+    use rstest::*;
+
+    fn utc(seconds: i64) -> Timestamp {
+        Timestamp::from_unix_seconds(seconds).unwrap()
+    }
+
+    #[rstest]
+    #[case::epoch(0, "1970-01-01T00:00:00+00:00")]
+    #[case::positive(1_700_000_000, "2023-11-14T22:13:20+00:00")]
+    #[case::negative(-1, "1969-12-31T23:59:59+00:00")]
+    fn from_unix_seconds(#[case] seconds: i64, #[case] expected: &str) {
+        let timestamp = utc(seconds);
+        assert_eq!(timestamp.to_string(), expected);
+        assert_eq!(timestamp.unix_seconds(), seconds);
+        assert_eq!(timestamp.precision(), Precision::Second);
+    }
+
+    #[rstest]
+    #[case::whole(1_000, "1970-01-01T00:00:01.000+00:00")]
+    #[case::fraction(1_700_000_000_123, "2023-11-14T22:13:20.123+00:00")]
+    #[case::negative(-1, "1969-12-31T23:59:59.999+00:00")]
+    fn from_unix_millis(#[case] millis: i64, #[case] expected: &str) {
+        let timestamp = Timestamp::from_unix_millis(millis).unwrap();
+        assert_eq!(timestamp.to_string(), expected);
+        assert_eq!(timestamp.unix_millis(), millis);
+        assert_eq!(timestamp.unix_nanos(), millis as i128 * 1_000_000);
+    }
+
+    #[test]
+    fn from_unix_nanos() {
+        let nanos = -1_700_000_000_000_000_001i128;
+        let timestamp = Timestamp::from_unix_nanos(nanos).unwrap();
+        assert_eq!(timestamp.to_string(), "1916-02-18T01:46:39.999999999+00:00");
+        assert_eq!(timestamp.unix_nanos(), nanos);
+        assert_eq!(timestamp.unix_millis(), -1_700_000_000_001);
+        assert_eq!(timestamp.unix_seconds(), -1_700_000_001);
+    }
+
+    #[test]
+    fn epoch_conversions_keep_arbitrary_fractional_seconds() {
+        let seconds = Decimal::new(-15_000_000_000_001i64, -13);
+        let timestamp = Timestamp::from_unix_seconds_decimal(&seconds).unwrap();
+        assert_eq!(
+            timestamp.to_string(),
+            "1969-12-31T23:59:58.4999999999999+00:00"
+        );
+        assert_eq!(timestamp.fractional_seconds_scale(), Some(13));
+        assert_eq!(timestamp.unix_seconds_decimal(), seconds);
+        assert_eq!(timestamp.unix_seconds(), -2);
+        assert_eq!(timestamp.unix_nanos(), -1_500_000_001);
+    }
+
+    #[test]
+    fn epoch_conversions_reject_out_of_range_years() {
+        assert!(Timestamp::from_unix_seconds(253_402_300_800).is_err()); // 10000-01-01
+        assert!(Timestamp::from_unix_seconds(-62_135_596_801).is_err()); // 0000-12-31
+        assert!(Timestamp::from_unix_seconds(i64::MAX).is_err());
+        assert!(Timestamp::from_unix_seconds_decimal(&Decimal::new(1, 100)).is_err());
+    }
+
+    #[test]
+    fn unknown_offsets_are_treated_as_utc() {
+        let timestamp = Timestamp::with_ymd_hms(1970, 1, 2, 0, 0, 0)
+            .build_at_unknown_offset()
+            .unwrap();
+        assert_eq!(timestamp.unix_seconds(), SECONDS_PER_DAY);
+        let later = timestamp.checked_add_hours(1).unwrap();
+        assert_eq!(later.offset(), None);
+        assert_eq!(later.to_string(), "1970-01-02T01:00:00-00:00");
+    }
+
+    #[rstest]
+    #[case::year_plus_month(Timestamp::with_year(2023).build(), 13, "2024-02T")]
+    #[case::month(Timestamp::with_year(2023).with_month(11).build(), -11, "2022-12T")]
+    #[case::clamped_day(Timestamp::with_ymd(2023, 3, 31).build(), -1, "2023-02-28T")]
+    #[case::local_date(
+        Timestamp::with_ymd(2023, 1, 31).with_hour_and_minute(20, 0).build_at_offset(-5 * 60),
+        1,
+        "2023-02-28T20:00-05:00"
+    )]
+    fn add_months(
+        #[case] timestamp: IonResult<Timestamp>,
+        #[case] months: i32,
+        #[case] expected: &str,
+    ) {
+        let timestamp = timestamp.unwrap();
+        assert_eq!(
+            timestamp.checked_add_months(months).unwrap().to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn add_years() {
+        let year = Timestamp::with_year(2020).build().unwrap();
+        let later = year.checked_add_years(3).unwrap();
+        assert_eq!(later.to_string(), "2023T");
+        assert_eq!(later.precision(), Precision::Year);
+
+        let leap_day = Timestamp::with_ymd(2020, 2, 29).build().unwrap();
+        assert_eq!(
+            leap_day.checked_add_years(1).unwrap().to_string(),
+            "2021-02-28T"
+        );
+        assert!(year.checked_add_years(8000).is_err());
+        assert!(year.checked_add_years(-2020).is_err());
+        assert!(year.checked_add_years(i32::MAX).is_err());
+    }
+
+    #[rstest]
+    #[case::year_plus_day(Timestamp::with_year(2023).build(), 1, "2023-01-02T")]
+    #[case::leap_year(Timestamp::with_ymd(2024, 2, 28).build(), 2, "2024-03-01T")]
+    #[case::keeps_time(
+        Timestamp::with_ymd(2023, 12, 31).with_hour_and_minute(23, 30).build_at_offset(60),
+        -365,
+        "2022-12-31T23:30+01:00"
+    )]
+    fn add_days(
+        #[case] timestamp: IonResult<Timestamp>,
+        #[case] days: i64,
+        #[case] expected: &str,
+    ) {
+        let timestamp = timestamp.unwrap();
+        assert_eq!(
+            timestamp.checked_add_days(days).unwrap().to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn add_hours_and_minutes() {
+        let day = Timestamp::with_ymd(2023, 6, 1).build().unwrap();
+        let later = day.checked_add_hours(25).unwrap();
+        assert_eq!(later.to_string(), "2023-06-02T01:00-00:00");
+        assert_eq!(later.precision(), Precision::HourAndMinute);
+        assert_eq!(
+            later.checked_add_minutes(-61).unwrap().to_string(),
+            "2023-06-01T23:59-00:00"
+        );
+        assert!(day.checked_add_minutes(i64::MAX).is_err());
+    }
+
+    #[rstest]
+    #[case::whole_seconds(
+        Timestamp::with_ymd(2023, 1, 1).with_hour_and_minute(0, 0).build_at_offset(0),
+        Decimal::new(30, 0),
+        "2023-01-01T00:00:30+00:00"
+    )]
+    #[case::keeps_precision(
+        Timestamp::with_ymd_hms(2023, 1, 1, 0, 0, 0).with_milliseconds(250).build_at_offset(0),
+        Decimal::new(-1, 0),
+        "2022-12-31T23:59:59.250+00:00"
+    )]
+    #[case::increases_precision(
+        Timestamp::with_ymd_hms(2023, 1, 1, 0, 0, 0).with_milliseconds(250).build_at_offset(0),
+        Decimal::new(5, -6),
+        "2023-01-01T00:00:00.250005+00:00"
+    )]
+    #[case::beyond_nanoseconds(
+        Timestamp::with_ymd_hms(2023, 1, 1, 0, 0, 0).build_at_offset(0),
+        Decimal::new(-1, -12),
+        "2022-12-31T23:59:59.999999999999+00:00"
+    )]
+    #[case::back_to_nanoseconds(
+        Timestamp::with_ymd_hms(2023, 1, 1, 0, 0, 0)
+            .with_fractional_seconds(Decimal::new(1, -12))
+            .build_at_offset(0),
+        Decimal::new(-1, -12),
+        "2023-01-01T00:00:00.000000000000+00:00"
+    )]
+    fn add_seconds(
+        #[case] timestamp: IonResult<Timestamp>,
+        #[case] seconds: Decimal,
+        #[case] expected: &str,
+    ) {
+        let timestamp = timestamp.unwrap();
+        assert_eq!(
+            timestamp.checked_add_seconds(&seconds).unwrap().to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn add_duration() {
+        let timestamp = utc(0);
+        assert_eq!(
+            timestamp
+                .checked_add_duration(Duration::seconds(2))
+                .unwrap()
+                .to_string(),
+            "1970-01-01T00:00:02+00:00"
+        );
+        assert_eq!(
+            timestamp
+                .checked_add_duration(Duration::milliseconds(-1500))
+                .unwrap()
+                .to_string(),
+            "1969-12-31T23:59:58.5+00:00"
+        );
+        assert_eq!(
+            timestamp
+                .checked_add_duration(Duration::nanoseconds(7))
+                .unwrap()
+                .to_string(),
+            "1970-01-01T00:00:00.000000007+00:00"
+        );
+    }
+
+    #[test]
+    fn seconds_since() {
+        let start = Timestamp::with_ymd_hms(2023, 1, 1, 0, 0, 0)
+            .with_fractional_seconds(Decimal::new(1, -12))
+            .build_at_offset(0)
+            .unwrap();
+        let end = Timestamp::with_ymd(2023, 1, 2).build().unwrap();
+        assert_eq!(
+            end.seconds_since(&start),
+            Decimal::new(86_399_999_999_999_999i64, -12)
+        );
+        assert_eq!(
+            start.seconds_since(&end),
+            Decimal::new(-86_399_999_999_999_999i64, -12)
+        );
+        assert_eq!(start.seconds_since(&start), Decimal::new(0, 0));
+    }
+
+    #[test]
+    fn instant_comparisons_ignore_precision() {
+        let year = Timestamp::with_year(2023).build().unwrap();
+        let minute = Timestamp::with_ymd(2023, 1, 1)
+            .with_hour_and_minute(1, 0)
+            .build_at_offset(60)
+            .unwrap();
+        let millis = Timestamp::with_ymd_hms(2023, 1, 1, 0, 0, 0)
+            .with_milliseconds(0)
+            .build_at_unknown_offset()
+            .unwrap();
+        let picos = Timestamp::with_ymd_hms(2023, 1, 1, 0, 0, 0)
+            .with_fractional_seconds(Decimal::new(1, -12))
+            .build_at_offset(0)
+            .unwrap();
+        assert!(year.instant_eq(&minute));
+        assert!(year.instant_eq(&millis));
+        assert!(!year.ion_eq(&millis));
+        assert_eq!(year.instant_cmp(&picos), Ordering::Less);
+        assert_eq!(picos.instant_cmp(&millis), Ordering::Greater);
+
+        let mut timestamps = [picos.clone(), year, utc(0)];
+        timestamps.sort_by(Timestamp::instant_cmp);
+        assert!(timestamps[0].instant_eq(&utc(0)));
+        assert!(timestamps[2].instant_eq(&picos));
+    }
+}
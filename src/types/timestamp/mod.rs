@@ -1,18 +1,22 @@
 use crate::ion_data::{IonEq, IonOrd};
-use crate::result::{
-    encoding_error, illegal_operation, illegal_operation_raw, IonError, IonResult,
-};
-use crate::types::Sign::Negative;
-use crate::types::{Decimal, UInt};
+use crate::result::{illegal_operation, illegal_operation_raw, IonError, IonResult};
+use crate::types::{Decimal, RoundingMode};
+use alloc::format;
 use chrono::{
     DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
 };
-use num_bigint::BigUint;
-use num_traits::ToPrimitive;
-use std::cmp::Ordering;
-use std::convert::TryInto;
-use std::fmt::{Debug, Display, Formatter};
-use std::ops::Div;
+use core::cmp::Ordering;
+use core::convert::TryInto;
+use core::fmt::{Debug, Display, Formatter};
+
+mod arithmetic;
+#[cfg(feature = "time")]
+mod time_conversions;
+#[cfg(feature = "chrono-tz")]
+mod time_zones;
+
+#[cfg(feature = "chrono-tz")]
+pub use time_zones::Disambiguation;
 
 /// Indicates the most precise time unit that has been specified in the accompanying [Timestamp].
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default)]
@@ -30,108 +34,9 @@ pub enum Precision {
     Second,
 }
 
-// [Default] cannot be derived for enum types. Providing a manual implementation of this type
-// allows us to derive Default for [Timestamp].
-
-/// Stores the precision of a Timestamp's fractional seconds, if present. This type is not
-/// self-contained; if the Timestamp has a precision that is less than or equal to nanoseconds
-/// (i.e. fewer than 10 digits), the fractional seconds value will be stored in the Timestamp's
-/// NaiveDateTime component and the Mantissa will indicate the number of digits from that value
-/// that should be used. If the precision is 10 or more digits, the Mantissa will store the value
-/// itself as a Decimal with the correct precision.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Mantissa {
-    /// The number of digits of precision in the Timestamp's fractional seconds. For example, a
-    /// value of `3` would indicate millisecond precision. A value of `6` would indicate
-    /// microsecond precision. All precisions less than or equal to nanoseconds should use
-    /// this representation when possible.
-    Digits(u32),
-    /// Specifies the fractional seconds precisely as a `Decimal` in the range `>= 0` and `< 1`.
-    /// The Decimal will have the correct precision; the complete value can and should be used.
-    /// This representation should only be used for precisions greater than nanoseconds as can
-    /// require allocations.
-    Arbitrary(Decimal),
-}
-
-impl Mantissa {
-    fn decimals_equal(d1: &Decimal, d2: &Decimal) -> bool {
-        // See the [EmptyMantissa] trait for details about `is_empty()`
-        (d1.is_empty() && d2.is_empty())
-            // Exact equality test
-            || d1.eq(d2)
-            // Coefficient zeros' signs don't have to match for fractional seconds.
-            || (d1.coefficient.is_zero() && d2.coefficient.is_zero() && d1.exponent == d2.exponent)
-    }
-
-    fn decimals_compare(d1: &Decimal, d2: &Decimal) -> Ordering {
-        // See the [EmptyMantissa] trait for details about `is_empty()`
-        if d1.is_empty() && d2.is_empty() {
-            Ordering::Equal
-        } else if d1.coefficient.is_zero() && d2.coefficient.is_zero() {
-            // Coefficient zeros' signs don't have to be compared for fractional seconds.
-            d1.exponent.cmp(&d2.exponent)
-        } else {
-            // Exact comparison test
-            d1.cmp(d2)
-        }
-    }
-}
-
-trait EmptyMantissa {
-    /// Returns true if the Mantissa's value is equivalent to not having specified a
-    /// sub-second precision at all. For example, `Mantissa::Digits(0)` or
-    /// `Mantissa::Arbitrary(Decimal::new(0, 0))`.
-    fn is_empty(&self) -> bool;
-
-    /// Returns true if the Mantissa's value is equivalent to a zero value
-    /// For example, `Mantissa::Digits(0)` or `Mantissa::Arbitrary(Decimal::new(0, x))`.
-    /// For Decimal, it ignores the exponent value for a zero coefficient.
-    fn is_zero(&self) -> bool;
-}
-
-impl EmptyMantissa for Decimal {
-    fn is_empty(&self) -> bool {
-        self.coefficient.is_zero() && self.exponent == 0
-    }
-
-    fn is_zero(&self) -> bool {
-        // if the coefficient is zero then ignore the exponent value
-        self.coefficient.is_zero()
-    }
-}
-
-impl EmptyMantissa for Mantissa {
-    fn is_empty(&self) -> bool {
-        match self {
-            // Look at zero digits of the DateTime's nanoseconds
-            Mantissa::Digits(0) => true,
-            // Or a Decimal with a coefficient of zero (any sign) and an exponent of zero.
-            Mantissa::Arbitrary(d) => d.is_empty(),
-            _ => false,
-        }
-    }
-
-    fn is_zero(&self) -> bool {
-        match self {
-            // Look at zero digits of the DateTime's nanoseconds
-            Mantissa::Digits(0) => true,
-            // Or a Decimal with a coefficient of zero (any sign).
-            Mantissa::Arbitrary(d) => d.is_zero(),
-            _ => false,
-        }
-    }
-}
-
-/// Returns the first `num_digits` digits of the specified `value`.
-// This is used in Timestamp's implementation of [PartialEq].
-fn first_n_digits_of(num_digits: u32, value: u32) -> u32 {
-    let total_digits = super::num_decimal_digits_in_u64(value as u64) as u32;
-    if total_digits <= num_digits {
-        return value;
-    }
-    // Truncate the trailing digits
-    value / 10u32.pow(total_digits - num_digits)
-}
+/// The range of local years that an Ion timestamp can represent.
+const MIN_YEAR: i32 = 1;
+const MAX_YEAR: i32 = 9999;
 
 /// Constructs a [FixedOffset] at the specified offset seconds from UTC. If the specified offset
 /// is out of bounds, this method will panic.
@@ -141,21 +46,25 @@ fn offset_east(seconds_east: i32) -> FixedOffset {
         .expect("seconds_east was outside the supported range")
 }
 
-/// Constructs a [`DateTime<FixedOffset>`] at the specified offset using the fields of
-/// [`NaiveDateTime`] representing the desired UTC datetime.
-fn datetime_at_offset(utc_datetime: &NaiveDateTime, seconds_east: i32) -> DateTime<FixedOffset> {
-    offset_east(seconds_east).from_utc_datetime(utc_datetime)
-}
-
 /// Represents a point in time to a specified degree of precision. Unlike `chrono`'s [NaiveDateTime]
 /// and [DateTime], a `Timestamp` has variable precision ranging from a year to fractional seconds
 /// of an arbitrary unit.
+///
+/// A `Timestamp` can represent any value in the Ion data model: local years from 0001 to 9999,
+/// a known offset between `-23:59` and `+23:59` or an unknown offset (`-00:00`), and fractional
+/// seconds with any number of digits.
 #[derive(Debug, Clone)]
 pub struct Timestamp {
+    // The whole seconds of the timestamp, in UTC. The nanoseconds field is always zero; see
+    // `fractional_seconds`. Near the ends of the Ion timestamp range, the UTC year can be 0 or
+    // 10000 even though the local year is valid; chrono's range comfortably includes both.
     pub(crate) date_time: NaiveDateTime,
     pub(crate) offset: Option<FixedOffset>,
     pub(crate) precision: Precision,
-    pub(crate) fractional_seconds: Option<Mantissa>,
+    // The exact fractional seconds, if any, in the range [0, 1). This is always `None` if the
+    // precision is less than `Second`. If it is `Some`, the Decimal has a positive sign and a
+    // negative exponent; its scale is the number of digits of sub-second precision.
+    pub(crate) fractional_seconds: Option<Decimal>,
 }
 
 impl Timestamp {
@@ -179,49 +88,21 @@ impl Timestamp {
     ///
     /// For example, a Timestamp with 553 milliseconds would return a Decimal scale of 3.
     pub fn fractional_seconds_scale(&self) -> Option<i64> {
-        // This function is used when comparing two Timestamps with different Mantissa representations.
-        use Mantissa::*;
-        match self.fractional_seconds.as_ref() {
-            // number_of_digits represent number of digits of precision in the Timestamp's fractional seconds.
-            // this is equivalent to the decimal scale when we convert the fractional seconds into a decimal
-            // and return its scale
-            Some(Digits(number_of_digits)) => Some(*number_of_digits as i64),
-            // This timestamp already stores its fractional seconds as a Decimal; return the scale of this Decimal.
-            Some(Arbitrary(decimal)) => Some(decimal.scale()),
-            // This Timestamp's precision is too low to have a fractional seconds field.
-            None => None,
-        }
+        self.fractional_seconds.as_ref().map(Decimal::scale)
     }
 
-    /// If the precision is [Precision::Second], returns a Decimal representation of this Timestamp's
-    /// fractional seconds; otherwise, returns None.
+    /// Returns this Timestamp's fractional seconds as a Decimal in the range `>= 0` and `< 1`,
+    /// or `None` if it does not have any. The Decimal has as many digits as the Timestamp's
+    /// fractional seconds.
     ///
     /// For example, a Timestamp with 553 milliseconds would return a Decimal with
     /// coefficient 553, exponent -3.
+    pub fn fractional_seconds(&self) -> Option<&Decimal> {
+        self.fractional_seconds.as_ref()
+    }
+
     pub(crate) fn fractional_seconds_as_decimal(&self) -> Option<Decimal> {
-        // This function is used when comparing two Timestamps with different Mantissa representations.
-        use Mantissa::*;
-        match self.fractional_seconds.as_ref() {
-            // This timestamp stores its fractional seconds in its `date_time` field.
-            // We'll need to convert the date_time's nanoseconds to a Decimal and return it.
-            Some(Digits(number_of_digits)) => {
-                const MAX_NANOSECOND_DIGITS: u32 = 9; // If it were 10, it'd be > a second
-                let nanoseconds = self.date_time.nanosecond();
-                let leading_zeros = MAX_NANOSECOND_DIGITS
-                    - super::num_decimal_digits_in_u64(nanoseconds as u64) as u32;
-                let coefficient = if leading_zeros >= *number_of_digits {
-                    0
-                } else {
-                    first_n_digits_of(*number_of_digits - leading_zeros, nanoseconds)
-                };
-                let exponent = -i64::from(*number_of_digits);
-                Some(Decimal::new(coefficient, exponent))
-            }
-            // This timestamp already stores its fractional seconds as a Decimal; return a clone.
-            Some(Arbitrary(decimal)) => Some(decimal.clone()),
-            // This Timestamp's precision is too low to have a fractional seconds field.
-            None => None,
-        }
+        self.fractional_seconds.clone()
     }
 
     /// If the precision is [Precision::Second], returns a u32 representing
@@ -233,206 +114,53 @@ impl Timestamp {
     fn fractional_seconds_as_nanoseconds(&self) -> Option<u32> {
         // This function is used when converting a Timestamp to a DateTime<FixedOffset> or
         // NaiveDateTime.
-        use Mantissa::*;
-        match self.fractional_seconds.as_ref() {
-            // This timestamp already stores its fractional seconds in its `date_time` field.
-            // We can ignore the `number_of_digits` (which tracks its precision) and simply return
-            // `self.date_time`'s nanoseconds.
-            Some(Digits(_number_of_digits)) => Some(self.date_time.nanosecond()),
-            // This timestamp stores its fractional seconds as a Decimal. Down-convert it to a u32.
-            Some(Arbitrary(decimal)) => {
-                const NANOSECONDS_EXPONENT: i64 = -9;
-                let exponent_delta = decimal.exponent - NANOSECONDS_EXPONENT;
-                let magnitude = match decimal.coefficient.magnitude() {
-                    UInt::U64(magnitude) => *magnitude,
-                    UInt::BigUInt(magnitude) => {
-                        // If the magnitude is small enough to fit in a u64, do the conversion.
-                        if let Ok(small_magnitude) = u64::try_from(magnitude) {
-                            small_magnitude
-                        } else {
-                            // Otherwise, the magnitude is definitely bigger than 999,999,999
-                            // (the max number of nanoseconds). We'll need to scale it down to
-                            // nanoseconds, losing precision in the process.
-                            let nanoseconds = magnitude
-                                .div(BigUint::from(10f64.powi(exponent_delta.abs() as i32) as u64))
-                                .to_u32()
-                                .expect("failed to convert BigUint magnitude to u32");
-                            return Some(nanoseconds);
-                        }
-                    }
-                };
-                let nanoseconds = (magnitude as f64 * 10f64.powi(exponent_delta as i32)) as u32;
-                Some(nanoseconds)
-            }
-            // This Timestamp's precision is too low to have a fractional seconds field.
-            None => None,
-        }
+        let fraction = self.fractional_seconds.as_ref()?;
+        let nanoseconds = fraction.rescale(-9, RoundingMode::Down);
+        let nanoseconds = u32::try_from(nanoseconds.coefficient.magnitude())
+            .expect("fractional seconds are less than a billion nanoseconds");
+        Some(nanoseconds)
     }
 
-    /// Tests the fractional seconds fields of two timestamps for ordering. This function will
-    /// only be called if both Timestamps have a precision of [Precision::Second].
-    fn fractional_seconds_compare(&self, other: &Timestamp) -> Ordering {
-        use Mantissa::*;
-        match (
-            self.fractional_seconds.as_ref(),
-            other.fractional_seconds.as_ref(),
-        ) {
-            (None, None) => Ordering::Equal,
-            (Some(m), None) => {
-                if m.is_zero() {
-                    Ordering::Equal
-                } else {
-                    Ordering::Greater
-                }
-            }
-            (None, Some(m)) => {
-                if m.is_zero() {
-                    Ordering::Equal
-                } else {
-                    Ordering::Less
-                }
-            }
-            (Some(Digits(_d1)), Some(Digits(_d2))) => {
-                let d1 = self.date_time.nanosecond();
-                let d2 = other.date_time.nanosecond();
-                d1.cmp(&d2)
-            }
-            (Some(Arbitrary(d1)), Some(Arbitrary(d2))) => Mantissa::decimals_compare(d1, d2),
-            (Some(Digits(_d1)), Some(Arbitrary(d2))) => {
-                let d1 = &self.fractional_seconds_as_decimal().unwrap();
-                Mantissa::decimals_compare(d1, d2)
-            }
-            (Some(Arbitrary(d1)), Some(Digits(_d2))) => {
-                let d2 = &other.fractional_seconds_as_decimal().unwrap();
-                Mantissa::decimals_compare(d1, d2)
-            }
-        }
+    /// Returns this Timestamp's fractional seconds, or zero if it does not have any.
+    fn fraction(&self) -> Decimal {
+        self.fractional_seconds_as_decimal()
+            .unwrap_or_else(|| Decimal::new(0, 0))
     }
 
-    /// Tests the fractional seconds fields of two timestamps for equality. This function will
-    /// only be called if both Timestamps have a precision of [Precision::Second].
-    fn fractional_seconds_equal(&self, other: &Timestamp) -> bool {
-        use Mantissa::*;
-
-        // TODO: make Timestamp::fractional_seconds to be Mantissa when creating a Timestamp to get rid of below conversion
-        // convert Option<&Mantissa> to &Mantissa
-        let m1 = match &self.fractional_seconds {
-            None => &Mantissa::Digits(0),
-            Some(m) => m,
-        };
-
-        let m2 = match &other.fractional_seconds {
-            None => &Mantissa::Digits(0),
-            Some(m) => m,
-        };
-
-        // compare fractional seconds
-        match (m1, m2) {
-            (Digits(d1), Digits(d2)) => {
-                if d1 != d2 {
-                    // Different precisions
-                    return false;
-                }
-                let d1 = first_n_digits_of(*d1, self.date_time.nanosecond());
-                let d2 = first_n_digits_of(*d2, other.date_time.nanosecond());
-                d1 == d2
-            }
-            (Arbitrary(d1), Arbitrary(d2)) => Mantissa::decimals_equal(d1, d2),
-            (Digits(_d1), Arbitrary(d2)) => {
-                let d1 = match self.fractional_seconds_as_decimal() {
-                    Some(decimal_value) => decimal_value,
-                    None => Decimal::new(0, 0),
-                };
-                Mantissa::decimals_equal(&d1, d2)
-            }
-            (Arbitrary(d1), Digits(_d2)) => {
-                let d2 = match other.fractional_seconds_as_decimal() {
-                    Some(decimal_value) => decimal_value,
-                    None => Decimal::new(0, 0),
-                };
-                Mantissa::decimals_equal(d1, &d2)
-            }
+    /// Returns the fields of this Timestamp in its local time. If the offset is unknown, the
+    /// fields are those of UTC.
+    fn local_date_time(&self) -> NaiveDateTime {
+        match self.offset {
+            Some(offset) => self.date_time + offset,
+            None => self.date_time,
         }
     }
 
     /// Writes the fractional seconds portion of a text timestamp, including a leading `.`.
-    fn format_fractional_seconds<W: std::fmt::Write>(&self, output: &mut W) -> IonResult<()> {
-        if self.fractional_seconds.is_none() {
+    fn format_fractional_seconds<W: core::fmt::Write>(&self, output: &mut W) -> IonResult<()> {
+        let fraction = match self.fractional_seconds.as_ref() {
+            Some(fraction) => fraction,
             // Nothing to do.
-            return Ok(());
-        }
-        let mantissa = self.fractional_seconds.as_ref().unwrap();
-        if mantissa.is_empty() {
-            // No need to write anything.
-            return Ok(());
-        }
-        match mantissa {
-            Mantissa::Digits(num_digits) => {
-                // Scale the nanoseconds down to the requested number of digits.
-                // Example: if `num_digits` is 3 (that is: millisecond precision), we need to
-                // divide the nanoseconds by 10^(9-3) to get the correct precision:
-                //      123,000,000 nanoseconds / 10^(9-3) = 123 milliseconds
-                let scaled = self.date_time.nanosecond() / 10u32.pow(9 - *num_digits);
-                // If our scaled number has fewer digits than the precision states, add leading
-                // zeros to the output to make up the difference.
-                // Example: `num_digits` is 6 (microsecond precision) but our number of microseconds
-                // is `9500` (only 4 digits), we need to add two leading zeros to make: `009500`.
-                let actual_num_digits = super::num_decimal_digits_in_u64(scaled as u64);
-                let num_leading_zeros = (*num_digits as u64) - actual_num_digits;
-                write!(output, ".")?;
-                for _ in 0..num_leading_zeros {
-                    write!(output, "0")?;
-                }
-                write!(output, "{scaled}")?;
-                Ok(())
-            }
-            Mantissa::Arbitrary(decimal) => {
-                let exponent = decimal.exponent;
-                let coefficient = &decimal.coefficient;
-                if exponent >= 0 {
-                    // We know that the coefficient is non-zero (the mantissa was not empty),
-                    // so having a positive exponent would result in an illegal fractional
-                    // seconds value.
-                    return encoding_error("found fractional seconds decimal that was >= 1.");
-                }
-
-                let num_digits = decimal.coefficient.number_of_decimal_digits();
-                let abs_exponent = decimal.exponent.unsigned_abs();
-                // At this point, we know that the abs_exponent is greater than num_digits because
-                // the decimal has to be < 1.
-                let num_leading_zeros = abs_exponent - num_digits;
-                write!(output, ".")?;
-                for _ in 0..num_leading_zeros {
-                    write!(output, "0")?;
-                }
-                if coefficient.is_negative_zero() {
-                    write!(output, "0")?;
-                } else if coefficient.sign == Negative {
-                    return encoding_error(
-                        "fractional seconds cannot have a negative coefficient (other than -0)",
-                    );
-                } else {
-                    write!(output, "{}", decimal.coefficient)?;
-                }
-                Ok(())
-            }
+            None => return Ok(()),
+        };
+        // The fraction is less than one, so its scale is at least as large as its number of
+        // digits. Any difference is made up with leading zeros.
+        // Example: 9500d-6 (9,500 microseconds) needs two leading zeros to make: `.009500`.
+        let num_digits = fraction.coefficient.number_of_decimal_digits();
+        let num_leading_zeros = fraction.scale() as u64 - num_digits;
+        write!(output, ".")?;
+        for _ in 0..num_leading_zeros {
+            write!(output, "0")?;
         }
+        write!(output, "{}", fraction.coefficient.magnitude())?;
+        Ok(())
     }
 
-    pub(crate) fn format<W: std::fmt::Write>(&self, output: &mut W) -> IonResult<()> {
-        let (offset_minutes, datetime) = if let Some(minutes) = self.offset {
-            // Create a datetime with the appropriate offset that we can use for formatting.
-            let datetime: DateTime<FixedOffset> = self.clone().try_into()?;
-            // Convert the offset to minutes --v
-            (Some(minutes.local_minus_utc() / 60), datetime)
-        } else {
-            // Our timestamp has an unknown offset. Per the spec, this means it makes no
-            // assertions about *where* it was recorded, but its fields are still in UTC.
-            // Create a UTC datetime that we can use for formatting.
-            let datetime: NaiveDateTime = self.clone().try_into()?;
-            let datetime: DateTime<FixedOffset> = datetime_at_offset(&datetime, 0);
-            (None, datetime)
-        };
+    pub(crate) fn format<W: core::fmt::Write>(&self, output: &mut W) -> IonResult<()> {
+        // If the offset is unknown, the spec says that the timestamp makes no assertions about
+        // *where* it was recorded, but its fields are still in UTC.
+        let offset_minutes = self.offset();
+        let datetime = self.local_date_time();
 
         write!(output, "{:0>4}", datetime.year())?;
         //                  ^-- 0-padded, right aligned, 4-digit year
@@ -474,7 +202,7 @@ impl Timestamp {
         Ok(())
     }
 
-    fn format_offset<W: std::fmt::Write>(
+    fn format_offset<W: core::fmt::Write>(
         &self,
         offset_minutes: Option<i32>,
         output: &mut W,
@@ -571,68 +299,33 @@ impl Timestamp {
 
     /// Returns the year that has been specified in the [Timestamp].
     pub fn year(&self) -> i32 {
-        // verify if the timestamp has an offset
-        if let Some(offset) = self.offset {
-            // `NaiveDateTime#hours()` returns hours normalized as per UTC
-            // for local time we need to +/- the difference
-            let local_date_time = DateTime::<FixedOffset>::from_utc(self.date_time, offset);
-            return local_date_time.year();
-        }
-        self.date_time.year()
+        self.local_date_time().year()
     }
 
     /// Returns the month that has been specified in the [Timestamp].
     /// Returns the month number starting from 1.
     /// The return value ranges from 1 to 12.
     pub fn month(&self) -> u32 {
-        // verify if the timestamp has an offset
-        if let Some(offset) = self.offset {
-            // `NaiveDateTime#hours()` returns hours normalized as per UTC
-            // for local time we need to +/- the difference
-            let local_date_time = DateTime::<FixedOffset>::from_utc(self.date_time, offset);
-            return local_date_time.month();
-        }
-        self.date_time.month()
+        self.local_date_time().month()
     }
 
     /// Returns the day that has been specified in the [Timestamp].
     /// Returns the day of month starting from 1.
     // The return value ranges from 1 to 31. (The last day of month differs by months.)
     pub fn day(&self) -> u32 {
-        // verify if the timestamp has an offset
-        if let Some(offset) = self.offset {
-            // `NaiveDateTime#hours()` returns hours normalized as per UTC
-            // for local time we need to +/- the difference
-            let local_date_time = DateTime::<FixedOffset>::from_utc(self.date_time, offset);
-            return local_date_time.day();
-        }
-        self.date_time.day()
+        self.local_date_time().day()
     }
 
     /// Returns the hour(s) that has been specified in the [Timestamp].
     /// Returns the hour number from 0 to 23.
     pub fn hour(&self) -> u32 {
-        // verify if the timestamp has an offset
-        if let Some(offset) = self.offset {
-            // `NaiveDateTime#hours()` returns hours normalized as per UTC
-            // for local time we need to +/- the difference
-            let local_date_time = DateTime::<FixedOffset>::from_utc(self.date_time, offset);
-            return local_date_time.hour();
-        }
-        self.date_time.hour()
+        self.local_date_time().hour()
     }
 
     /// Returns the minute(s) that has been specified in the [Timestamp].
     /// Returns the minute number from 0 to 59.
     pub fn minute(&self) -> u32 {
-        // verify if the timestamp has an offset
-        if let Some(offset) = self.offset {
-            // `NaiveDateTime#hours()` returns minutes normalized as per UTC
-            // for local time we need to +/- the difference
-            let local_date_time = DateTime::<FixedOffset>::from_utc(self.date_time, offset);
-            return local_date_time.minute();
-        }
-        self.date_time.minute()
+        self.local_date_time().minute()
     }
 
     /// Returns the second(s) that has been specified in the [Timestamp].
@@ -643,7 +336,7 @@ impl Timestamp {
 
     /// Return a UTC timestamp for this [Timestamp]
     pub fn to_utc(&self) -> Timestamp {
-        self.date_time.into()
+        downconvert_to_naive_datetime_with_nanoseconds(self).into()
     }
 
     /// Returns this Timestamp's fractional seconds in nanoseconds
@@ -669,8 +362,8 @@ impl Timestamp {
 
 /// Formats an ISO-8601 timestamp of appropriate precision and offset.
 impl Display for Timestamp {
-    fn fmt(&self, output: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        self.format(output).map_err(|_| std::fmt::Error)?;
+    fn fmt(&self, output: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        self.format(output).map_err(|_| core::fmt::Error)?;
         Ok(())
     }
 }
@@ -681,30 +374,15 @@ impl PartialOrd for Timestamp {
     }
 }
 
+/// Timestamps are ordered by the instant in time that they represent. Precision and offset are
+/// ignored, which makes this ordering consistent with [PartialEq].
 impl Ord for Timestamp {
     fn cmp(&self, other: &Self) -> Ordering {
-        let self_datetime = self.date_time.with_nanosecond(0).unwrap();
-        let other_datetime = other.date_time.with_nanosecond(0).unwrap();
-
-        let self_datetime = self
-            .offset
-            .map(|offset| offset.from_utc_datetime(&self_datetime))
-            .unwrap_or_else(|| datetime_at_offset(&self_datetime, 0));
-        let other_datetime = other
-            .offset
-            .map(|offset| offset.from_utc_datetime(&other_datetime))
-            .unwrap_or_else(|| datetime_at_offset(&other_datetime, 0));
-
-        let date_time_comparison = self_datetime.cmp(&other_datetime);
-
-        match date_time_comparison {
-            // if the datetime comparison is Ordering::Equal,
-            // then return fractional seconds comparison result
-            Ordering::Equal => self.fractional_seconds_compare(other),
-            // if datetime comparison is not equal,
-            // then no need to check for fractional seconds comparison
-            _ => date_time_comparison,
-        }
+        // `date_time` is always in UTC and never has a fractional component, so comparing the
+        // whole seconds and then the exact fractional seconds compares the instants.
+        self.date_time
+            .cmp(&other.date_time)
+            .then_with(|| self.fraction().cmp(&other.fraction()))
     }
 }
 
@@ -716,37 +394,7 @@ impl Ord for Timestamp {
 /// * `2022T-05-11T12:00:00.000Z` == `2022T-05-11T07:00:00.000-05:00`
 impl PartialEq for Timestamp {
     fn eq(&self, other: &Self) -> bool {
-        // First, compare the two Timestamps' fractional seconds. We do this first because
-        // Timestamps with different Mantissa representations are a bit tricky to compare. Once
-        // we've established that the fractional seconds match, we can compare all of the other
-        // fields in the timestamp by comparing their respective `DateTime`s.
-        if !self.fractional_seconds_equal(other) {
-            return false;
-        }
-
-        // When a Timestamp is created, any fields beyond its precision are set to the lowest
-        // legal value for that field. So the Timestamp `2022-05T` (which has `Month` precision)
-        // would have a `day` field of `1` and hour, minute, and seconds fields of `0`. This makes
-        // it easy to compare Timestamps with different precisions.
-
-        // Make copies of their respective DateTime values but with the fractional seconds zeroed
-        // out. We're not modifying `self` or `other`, and we've already compared their fractional
-        // seconds so it's ok to ignore them from here on.
-        let self_datetime = self.date_time.with_nanosecond(0).unwrap();
-        let other_datetime = other.date_time.with_nanosecond(0).unwrap();
-
-        // Apply each Timestamp's offset to the DateTime. If there's no offset, set it to UTC.
-        let self_datetime = self
-            .offset
-            .map(|offset| offset.from_utc_datetime(&self_datetime))
-            .unwrap_or_else(|| datetime_at_offset(&self_datetime, 0));
-        let other_datetime = other
-            .offset
-            .map(|offset| offset.from_utc_datetime(&other_datetime))
-            .unwrap_or_else(|| datetime_at_offset(&other_datetime, 0));
-
-        // Compare the resulting `DateTime<FixedOffset>`s
-        self_datetime == other_datetime
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -781,14 +429,17 @@ impl IonEq for Timestamp {
             return true;
         }
 
-        if self_dt.second() != other_dt.second() || !self.fractional_seconds_equal(other) {
+        if self_dt.second() != other_dt.second() {
             return false;
         }
-
-        true
+        // Fractional seconds must have the same value *and* the same number of digits.
+        match (&self.fractional_seconds, &other.fractional_seconds) {
+            (None, None) => true,
+            (Some(f1), Some(f2)) => f1.ion_eq(f2),
+            _ => false,
+        }
     }
 }
-
 impl IonOrd for Timestamp {
     fn ion_cmp(&self, other: &Self) -> Ordering {
         // Compare by point in time
@@ -844,8 +495,7 @@ struct TimestampBuilder {
     hour: Option<u8>,
     minute: Option<u8>,
     second: Option<u8>,
-    fractional_seconds: Option<Mantissa>,
-    nanoseconds: Option<u32>,
+    fractional_seconds: Option<Decimal>,
 }
 
 impl TimestampBuilder {
@@ -856,7 +506,15 @@ impl TimestampBuilder {
     where
         D: Datelike + Timelike + Debug,
     {
-        if self.year == 0 || self.year > 9999 {
+        // If the fields are in UTC, applying the offset can move the year by one in either
+        // direction. For example, `0001-01-01T00:00+00:01` has a UTC year of 0. The local year
+        // is validated once the offset has been applied.
+        let (min_year, max_year) = if self.fields_are_utc {
+            (MIN_YEAR - 1, MAX_YEAR + 1)
+        } else {
+            (MIN_YEAR, MAX_YEAR)
+        };
+        if !(min_year..=max_year).contains(&(self.year as i32)) {
             return illegal_operation(format!(
                 "Timestamp year '{}' out of range (1-9999)",
                 self.year
@@ -906,20 +564,21 @@ impl TimestampBuilder {
             illegal_operation_raw(format!("provided second ('{second}') is invalid."))
         })?;
 
-        // ...along with the fractional seconds.
-        // If fractional seconds is Digit, self.nanoseconds will be Some(_).
-        // If it's Arbitrary, self.nanoseconds will be None and we should set the nanoseconds
-        // field to 0. The real value will be stored in the Timestamp alongside the DateTime
-        // as a Decimal.
-        datetime = datetime
-            .with_nanosecond(self.nanoseconds.unwrap_or(0))
-            .ok_or_else(|| {
-                illegal_operation_raw(format!("provided nanosecond ('{second}') is invalid"))
-            })?;
-
+        // Fractional seconds are stored alongside the datetime as a Decimal.
         Ok(datetime)
     }
 
+    /// Returns a [`NaiveDateTime`] with each of the fields configured on the builder.
+    fn naive_datetime(&mut self) -> IonResult<NaiveDateTime> {
+        // Start with a clean slate NaiveDateTime that we can configure. (These are cheap to copy.)
+        let datetime: NaiveDateTime = NaiveDate::from_ymd_opt(0, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        // Set all of the time fields on the datetime using the data from our TimestampBuilder
+        self.configure_datetime(datetime)
+    }
+
     // A [NaiveDateTime] has no offset. This function attempts to apply the provided offset to the
     // NaiveDateTime, producing a DateTime<FixedOffset>. If the offset is invalid or the combination
     // of offset and datetime would produce an invalid Timestamp, this function will return Err.
@@ -964,51 +623,66 @@ impl TimestampBuilder {
         }
     }
 
+    /// Validates the fractional seconds configured on the builder. Returns `None` if they are
+    /// equivalent to not having specified any fractional seconds at all; that is, a zero with an
+    /// exponent of zero or greater.
+    fn validate_fractional_seconds(fractional_seconds: Decimal) -> IonResult<Option<Decimal>> {
+        if fractional_seconds.is_less_than_zero() {
+            return illegal_operation("cannot create a timestamp with negative fractional seconds");
+        }
+        if fractional_seconds.is_greater_than_or_equal_to_one() {
+            return illegal_operation("cannot create a timestamp with a fractional seconds >= 1.0");
+        }
+        if fractional_seconds.exponent >= 0 {
+            // The value is less than one, so it must be zero.
+            return Ok(None);
+        }
+        if fractional_seconds.coefficient.is_negative_zero() {
+            // The sign of a zero has no meaning in fractional seconds.
+            return Ok(Some(Decimal::new(0, fractional_seconds.exponent)));
+        }
+        Ok(Some(fractional_seconds))
+    }
+
     /// Attempt to construct a [Timestamp] using the values configured on the [TimestampBuilder].
     /// If any of the individual fields are invalid (for example, a `month` value that is greater
     /// than `12`) or if the resulting timestamp would represent a non-existent point in time
     /// (like those bypassed by daylight saving time), this method will return an `Err(IonError)`.
     fn build(mut self) -> IonResult<Timestamp> {
-        // Start with a clean slate NaiveDateTime that we can configure. (These are cheap to copy.)
-        let mut datetime: NaiveDateTime = NaiveDate::from_ymd_opt(0, 1, 1)
-            .unwrap()
-            .and_hms_nano_opt(0, 0, 0, 0)
-            .unwrap();
-        // Set all of the time fields on the datetime using the data from our TimestampBuilder
-        datetime = self.configure_datetime(datetime)?;
+        let datetime = self.naive_datetime()?;
         // If the timestamp we're building has a known offset...
-        let mut timestamp: Timestamp = if let Some(offset_minutes) = self.offset {
-            // ...apply the offset to our NaiveDateTime, producing a DateTime<FixedOffset>
+        let (date_time, offset) = if let Some(offset_minutes) = self.offset {
+            // ...apply the offset to our NaiveDateTime, producing a DateTime<FixedOffset>...
             let datetime_with_offset: DateTime<FixedOffset> =
                 Self::apply_offset(offset_minutes, self.fields_are_utc, datetime)?;
-            // ...and convert the DateTime<FixedOffset> into a full Timestamp.
-            Timestamp::from_datetime(datetime_with_offset, self.precision)
+            // ...and make sure that its local year is in range. Its UTC year may not be.
+            let local_year = datetime_with_offset.year();
+            if !(MIN_YEAR..=MAX_YEAR).contains(&local_year) {
+                return illegal_operation(format!(
+                    "Timestamp year '{local_year}' out of range (1-9999)"
+                ));
+            }
+            (
+                datetime_with_offset.naive_utc(),
+                Some(*datetime_with_offset.offset()),
+            )
         } else {
-            // Otherwise, there's not a known offset. We can directly convert our NaiveDateTime
-            // into a full Timestamp.
-            Timestamp::from_datetime(datetime, self.precision)
+            // Otherwise, there's not a known offset. The fields of our NaiveDateTime are in UTC.
+            (datetime, None)
         };
 
-        // Copy the fractional seconds from the builder to the Timestamp.
-        if self.precision == Precision::Second {
-            timestamp.fractional_seconds = self.fractional_seconds;
-            if let Some(Mantissa::Arbitrary(ref decimal)) = &timestamp.fractional_seconds {
-                if decimal.is_less_than_zero() {
-                    return illegal_operation(
-                        "cannot create a timestamp with negative fractional seconds",
-                    );
-                }
-                if decimal.is_greater_than_or_equal_to_one() {
-                    return illegal_operation(
-                        "cannot create a timestamp with a fractional seconds >= 1.0",
-                    );
-                }
-                if decimal.is_zero() && decimal.exponent >= 0 {
-                    timestamp.fractional_seconds = None;
-                }
+        let fractional_seconds = match self.fractional_seconds {
+            Some(fractional_seconds) if self.precision == Precision::Second => {
+                Self::validate_fractional_seconds(fractional_seconds)?
             }
-        }
-        Ok(timestamp)
+            _ => None,
+        };
+        Ok(Timestamp {
+            date_time,
+            offset,
+            precision: self.precision,
+            fractional_seconds,
+        })
     }
 }
 
@@ -1160,40 +834,40 @@ impl FractionalSecondSetter {
     // `Precision::Second`.
     pub fn with_nanoseconds(self, nanosecond: u32) -> FractionalSecondSetter {
         let mut builder = self.builder;
-        builder.fractional_seconds = Some(Mantissa::Digits(9));
-        builder.nanoseconds = Some(nanosecond);
+        builder.fractional_seconds = Some(Decimal::new(nanosecond, -9));
         FractionalSecondSetter { builder }
     }
 
     pub fn with_microseconds(self, microsecond: u32) -> FractionalSecondSetter {
         let mut builder = self.builder;
-        builder.fractional_seconds = Some(Mantissa::Digits(6));
-        builder.nanoseconds = Some(microsecond * 1000);
+        builder.fractional_seconds = Some(Decimal::new(microsecond, -6));
         FractionalSecondSetter { builder }
     }
 
     pub fn with_milliseconds(self, millisecond: u32) -> FractionalSecondSetter {
         let mut builder = self.builder;
-        builder.fractional_seconds = Some(Mantissa::Digits(3));
-        builder.nanoseconds = Some(millisecond * 1_000_000);
+        builder.fractional_seconds = Some(Decimal::new(millisecond, -3));
         FractionalSecondSetter { builder }
     }
 
+    /// Sets the fractional seconds to the first `precision_digits` digits of `nanoseconds`.
+    /// For example, 123,456,789 nanoseconds with a precision of 3 digits is `.123`. If
+    /// `precision_digits` is greater than 9, the additional digits are zeros.
     pub fn with_nanoseconds_and_precision(
         self,
         nanoseconds: u32,
         precision_digits: u32,
     ) -> FractionalSecondSetter {
         let mut builder = self.builder;
-        builder.fractional_seconds = Some(Mantissa::Digits(precision_digits));
-        builder.nanoseconds = Some(nanoseconds);
+        let fractional_seconds =
+            Decimal::new(nanoseconds, -9).rescale(-i64::from(precision_digits), RoundingMode::Down);
+        builder.fractional_seconds = Some(fractional_seconds);
         FractionalSecondSetter { builder }
     }
 
     pub fn with_fractional_seconds(self, fractional_seconds: Decimal) -> FractionalSecondSetter {
         let mut builder = self.builder;
-        builder.fractional_seconds = Some(Mantissa::Arbitrary(fractional_seconds));
-        builder.nanoseconds = None;
+        builder.fractional_seconds = Some(fractional_seconds);
         FractionalSecondSetter { builder }
     }
 
@@ -1279,14 +953,24 @@ impl TryInto<DateTime<FixedOffset>> for Timestamp {
     }
 }
 
+/// Splits a chrono datetime's nanoseconds off into a Decimal, leaving whole seconds behind.
+fn split_nanoseconds(date_time: NaiveDateTime) -> (NaiveDateTime, Option<Decimal>) {
+    // chrono represents a leap second as a nanosecond value of 1,000,000,000 or more. Ion
+    // timestamps cannot represent leap seconds, so the fraction is capped just below one.
+    let nanoseconds = date_time.nanosecond().min(999_999_999);
+    let whole_seconds = date_time.with_nanosecond(0).unwrap();
+    (whole_seconds, Some(Decimal::new(nanoseconds, -9)))
+}
+
 // Allows a NaiveDateTime to be converted to a Timestamp with an unknown offset.
 impl From<NaiveDateTime> for Timestamp {
     fn from(date_time: NaiveDateTime) -> Self {
+        let (date_time, fractional_seconds) = split_nanoseconds(date_time);
         Timestamp {
             date_time,
             offset: None,
             precision: Precision::Second,
-            fractional_seconds: Some(Mantissa::Digits(9)),
+            fractional_seconds,
         }
     }
 }
@@ -1295,11 +979,10 @@ impl From<NaiveDateTime> for Timestamp {
 impl From<DateTime<FixedOffset>> for Timestamp {
     fn from(fixed_offset_date_time: DateTime<FixedOffset>) -> Self {
         // Discard the offset
-        let date_time = fixed_offset_date_time.naive_utc();
+        let (date_time, fractional_seconds) = split_nanoseconds(fixed_offset_date_time.naive_utc());
         // Get a copy of the offset to store separately
         let offset = Some(*fixed_offset_date_time.offset());
         let precision = Precision::Second;
-        let fractional_seconds = Some(Mantissa::Digits(9));
         Timestamp {
             date_time,
             offset,
@@ -1314,13 +997,12 @@ mod timestamp_tests {
     use super::*;
     use crate::ion_data::IonEq;
     use crate::result::IonResult;
-    use crate::types::{Decimal, Mantissa, Precision, Timestamp};
+    use crate::types::{Decimal, Precision, Timestamp};
     use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
     use rstest::*;
     use std::cmp::Ordering;
     use std::convert::TryInto;
     use std::io::Write;
-    use std::str::FromStr;
 
     #[test]
     fn test_timestamps_with_same_ymd_hms_millis_at_known_offset_are_equal() -> IonResult<()> {
@@ -1686,7 +1368,10 @@ mod timestamp_tests {
             .unwrap_or_else(|e| panic!("Couldn't build timestamp: {e:?}"));
 
         assert_eq!(timestamp1.precision, Precision::Second);
-        assert_eq!(timestamp1.fractional_seconds, Some(Mantissa::Digits(3)));
+        assert!(timestamp1
+            .fractional_seconds()
+            .unwrap()
+            .ion_eq(&Decimal::new(194, -3)));
         assert_eq!(timestamp1, timestamp2);
         assert_eq!(timestamp2, timestamp3);
 
@@ -1916,29 +1601,23 @@ mod timestamp_tests {
         Ok(())
     }
 
-    #[test]
-    fn test_first_n_digits_of() {
-        assert_eq!(0, super::first_n_digits_of(1, 0));
-        assert_eq!(1, super::first_n_digits_of(1, 1));
-        assert_eq!(2, super::first_n_digits_of(1, 2));
-
-        assert_eq!(0, super::first_n_digits_of(3, 0));
-        assert_eq!(1, super::first_n_digits_of(3, 1));
-        assert_eq!(2, super::first_n_digits_of(3, 2));
-        assert_eq!(99, super::first_n_digits_of(9, 99));
-        assert_eq!(999, super::first_n_digits_of(9, 999));
-        assert_eq!(9999, super::first_n_digits_of(9, 9999));
-
-        assert_eq!(0, super::first_n_digits_of(0, 123456789));
-        assert_eq!(1, super::first_n_digits_of(1, 123456789));
-        assert_eq!(12, super::first_n_digits_of(2, 123456789));
-        assert_eq!(123, super::first_n_digits_of(3, 123456789));
-        assert_eq!(1234, super::first_n_digits_of(4, 123456789));
-        assert_eq!(12345, super::first_n_digits_of(5, 123456789));
-        assert_eq!(123456, super::first_n_digits_of(6, 123456789));
-        assert_eq!(1234567, super::first_n_digits_of(7, 123456789));
-        assert_eq!(12345678, super::first_n_digits_of(8, 123456789));
-        assert_eq!(123456789, super::first_n_digits_of(9, 123456789));
+    #[rstest]
+    #[case::truncated(123_456_789, 3, Decimal::new(123, -3))]
+    #[case::leading_zeros(9_500_000, 6, Decimal::new(9500, -6))]
+    #[case::zero_digits(0, 3, Decimal::new(0, -3))]
+    #[case::all_digits(123_456_789, 9, Decimal::new(123_456_789, -9))]
+    #[case::extra_digits(123_456_789, 12, Decimal::new(123_456_789_000i64, -12))]
+    fn test_nanoseconds_and_precision(
+        #[case] nanoseconds: u32,
+        #[case] digits: u32,
+        #[case] expected: Decimal,
+    ) -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd_hms(2021, 4, 6, 10, 15, 0)
+            .with_nanoseconds_and_precision(nanoseconds, digits)
+            .build_at_offset(0)?;
+        let fractional_seconds = timestamp.fractional_seconds().unwrap();
+        assert!(fractional_seconds.ion_eq(&expected));
+        Ok(())
     }
 
     #[rstest]
@@ -1960,40 +1639,85 @@ mod timestamp_tests {
         assert_eq!(this.cmp(&other), expected)
     }
 
+    #[rstest]
+    #[case::utc_year_zero(0, 12, 31, 23, 59, 1, "0001-01-01T00:00+00:01")]
+    #[case::utc_year_ten_thousand(10000, 1, 1, 0, 0, -1, "9999-12-31T23:59-00:01")]
+    fn build_utc_fields_outside_local_year_range(
+        #[case] year: u32,
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] hour: u32,
+        #[case] minute: u32,
+        #[case] offset_minutes: i32,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Timestamp::with_year(year)
+            .with_month(month)
+            .with_day(day)
+            .with_hour_and_minute(hour, minute)
+            .build_utc_fields_at_offset(offset_minutes)?;
+        assert_eq!(timestamp.to_string(), expected);
+        assert_eq!(timestamp.year(), expected[..4].parse::<i32>().unwrap());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::local_year_zero(0, 12, 31, 23, 59, -1)]
+    #[case::local_year_ten_thousand(10000, 1, 1, 0, 0, 1)]
+    fn build_utc_fields_rejects_local_year_out_of_range(
+        #[case] year: u32,
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] hour: u32,
+        #[case] minute: u32,
+        #[case] offset_minutes: i32,
+    ) {
+        let result = Timestamp::with_year(year)
+            .with_month(month)
+            .with_day(day)
+            .with_hour_and_minute(hour, minute)
+            .build_utc_fields_at_offset(offset_minutes);
+        assert!(result.is_err());
+    }
+
     #[test]
-    fn ion_eq_fraction_seconds_mixed_mantissa() {
-        let t1 = Timestamp {
-            date_time: NaiveDateTime::from_str("1857-05-29T19:25:59.100").unwrap(),
-            offset: Some(offset_east(60 * 60 * 23 + 60 * 59)),
-            precision: Precision::Second,
-            fractional_seconds: Some(Mantissa::Digits(1)),
-        };
-        let t2 = Timestamp {
-            date_time: NaiveDateTime::from_str("1857-05-29T19:25:59").unwrap(),
-            offset: Some(offset_east(60 * 60 * 23 + 60 * 59)),
-            precision: Precision::Second,
-            fractional_seconds: Some(Mantissa::Arbitrary(Decimal::new(1u64, -1))),
-        };
+    fn ion_eq_fractional_seconds_from_nanoseconds_and_decimal() -> IonResult<()> {
+        let t1 = Timestamp::with_ymd_hms(1857, 5, 29, 19, 25, 59)
+            .with_nanoseconds_and_precision(100_000_000, 1)
+            .build_utc_fields_at_offset(23 * 60 + 59)?;
+        let t2 = Timestamp::with_ymd_hms(1857, 5, 29, 19, 25, 59)
+            .with_fractional_seconds(Decimal::new(1u64, -1))
+            .build_utc_fields_at_offset(23 * 60 + 59)?;
         assert_eq!(t1, t2);
         assert!(t1.ion_eq(&t2));
+        Ok(())
     }
 
     #[test]
-    fn ion_eq_fraction_seconds_mixed_mantissa_2() {
-        let t1 = Timestamp {
-            date_time: NaiveDateTime::from_str("2001-08-01T18:18:49.006").unwrap(),
-            offset: Some(offset_east(60 * 60 + 60)),
-            precision: Precision::Second,
-            fractional_seconds: Some(Mantissa::Digits(5)),
-        };
-        let t2 = Timestamp {
-            date_time: NaiveDateTime::from_str("2001-08-01T18:18:49").unwrap(),
-            offset: Some(offset_east(60 * 60 + 60)),
-            precision: Precision::Second,
-            fractional_seconds: Some(Mantissa::Arbitrary(Decimal::new(600u64, -5))),
-        };
+    fn ion_eq_fractional_seconds_from_nanoseconds_and_decimal_2() -> IonResult<()> {
+        let t1 = Timestamp::with_ymd_hms(2001, 8, 1, 18, 18, 49)
+            .with_nanoseconds_and_precision(6_000_000, 5)
+            .build_utc_fields_at_offset(61)?;
+        let t2 = Timestamp::with_ymd_hms(2001, 8, 1, 18, 18, 49)
+            .with_fractional_seconds(Decimal::new(600u64, -5))
+            .build_utc_fields_at_offset(61)?;
         assert_eq!(t1, t2);
         assert!(t1.ion_eq(&t2));
+        Ok(())
+    }
+
+    #[test]
+    fn fractional_seconds_with_different_precisions_are_equal_but_not_ion_eq() -> IonResult<()> {
+        let t1 = Timestamp::with_ymd_hms(2001, 8, 1, 18, 18, 49)
+            .with_milliseconds(100)
+            .build_at_offset(0)?;
+        let t2 = Timestamp::with_ymd_hms(2001, 8, 1, 18, 18, 49)
+            .with_fractional_seconds(Decimal::new(1, -1))
+            .build_at_offset(0)?;
+        assert_eq!(t1, t2);
+        assert_eq!(t1.cmp(&t2), Ordering::Equal);
+        assert!(!t1.ion_eq(&t2));
+        Ok(())
     }
 
     #[rstest]
@@ -0,0 +1,205 @@
+//! Conversions between [`Timestamp`] and the date and time types of the [`time`] crate. These
+//! are only available when the `time` feature is enabled.
+//!
+//! Like the `chrono` conversions, a [`PrimitiveDateTime`] corresponds to a `Timestamp` with an
+//! unknown offset and an [`OffsetDateTime`] corresponds to a `Timestamp` with a known offset.
+//! Values created from a `time` type have nanosecond precision. Converting a `Timestamp` to a
+//! `time` type fails rather than discard any fractional seconds that are more precise than that.
+
+use super::{FractionalSecondSetter, Timestamp};
+use crate::result::{illegal_operation, illegal_operation_raw, IonError, IonResult};
+use crate::types::RoundingMode;
+use chrono::{Datelike, Timelike};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+impl Timestamp {
+    /// Returns this Timestamp's fractional seconds as a number of nanoseconds. If the fractional
+    /// seconds cannot be represented as a whole number of nanoseconds, returns an error.
+    fn exact_nanoseconds(&self) -> IonResult<u32> {
+        let fraction = match self.fractional_seconds.as_ref() {
+            Some(fraction) => fraction,
+            None => return Ok(0),
+        };
+        if fraction.rescale(-9, RoundingMode::Down) != *fraction {
+            return illegal_operation(format!(
+                "cannot convert {self} to a `time` type without losing sub-nanosecond precision"
+            ));
+        }
+        Ok(self.fractional_seconds_as_nanoseconds().unwrap_or(0))
+    }
+
+    /// Returns this Timestamp's fields in its local time as a [`PrimitiveDateTime`]. Fields that
+    /// are not specified by the Timestamp's precision take their default values.
+    fn local_primitive_date_time(&self) -> IonResult<PrimitiveDateTime> {
+        let local = self.local_date_time();
+        // Every valid Timestamp has a local year in the range 1-9999, which `time` supports.
+        let month = Month::try_from(local.month() as u8).expect("chrono months are 1-12");
+        let date = Date::from_calendar_date(local.year(), month, local.day() as u8)
+            .expect("chrono dates are valid");
+        let time = Time::from_hms_nano(
+            local.hour() as u8,
+            local.minute() as u8,
+            local.second() as u8,
+            self.exact_nanoseconds()?,
+        )
+        .expect("chrono times are valid");
+        Ok(PrimitiveDateTime::new(date, time))
+    }
+}
+
+/// Creates a builder that has been configured with each of the fields in `date_time`.
+fn timestamp_builder(date_time: PrimitiveDateTime) -> IonResult<FractionalSecondSetter> {
+    let year = u32::try_from(date_time.year()).map_err(|_| {
+        illegal_operation_raw(format!(
+            "year {} is out of range for a Timestamp (1-9999)",
+            date_time.year()
+        ))
+    })?;
+    Ok(Timestamp::with_ymd(
+        year,
+        u8::from(date_time.month()).into(),
+        date_time.day().into(),
+    )
+    .with_hms(
+        date_time.hour().into(),
+        date_time.minute().into(),
+        date_time.second().into(),
+    )
+    .with_nanoseconds(date_time.nanosecond()))
+}
+
+// Allows a PrimitiveDateTime to be converted to a Timestamp with an unknown offset.
+impl TryFrom<PrimitiveDateTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(date_time: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        timestamp_builder(date_time)?.build_at_unknown_offset()
+    }
+}
+
+// Allows an OffsetDateTime to be converted to a Timestamp with the same offset.
+impl TryFrom<OffsetDateTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(date_time: OffsetDateTime) -> Result<Self, Self::Error> {
+        let offset = date_time.offset();
+        if offset.seconds_past_minute() != 0 {
+            return illegal_operation(format!(
+                "cannot convert offset {offset} to a Timestamp offset, which is in whole minutes"
+            ));
+        }
+        let local = PrimitiveDateTime::new(date_time.date(), date_time.time());
+        timestamp_builder(local)?.build_at_offset(offset.whole_minutes().into())
+    }
+}
+
+// Allows a Timestamp with an unknown offset to be converted to a PrimitiveDateTime.
+impl TryFrom<Timestamp> for PrimitiveDateTime {
+    type Error = IonError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        if timestamp.offset.is_some() {
+            return illegal_operation(
+                "cannot convert a Timestamp with a known offset into a PrimitiveDateTime",
+            );
+        }
+        timestamp.local_primitive_date_time()
+    }
+}
+
+// Allows a Timestamp with a known offset to be converted to an OffsetDateTime.
+impl TryFrom<Timestamp> for OffsetDateTime {
+    type Error = IonError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let offset_seconds = match timestamp.offset {
+            Some(offset) => offset.local_minus_utc(),
+            None => {
+                return illegal_operation(
+                    "cannot convert a Timestamp with an unknown offset into an OffsetDateTime",
+                )
+            }
+        };
+        // Ion offsets are less than 24 hours, which `time` supports.
+        let offset = UtcOffset::from_whole_seconds(offset_seconds).expect("offset is in range");
+        Ok(timestamp.local_primitive_date_time()?.assume_offset(offset))
+    }
+}
+
+#[cfg(test)]
+mod time_conversions_tests {
+    use super::*;
+    use crate::ion_data::IonEq;
+    use crate::types::{Decimal, Precision};
+    use rstest::*;
+    use time::macros::{datetime, offset};
+
+    #[test]
+    fn offset_date_time_round_trip() -> IonResult<()> {
+        let date_time = datetime!(2021-02-05 16:43:51.192_000_001 -05:00);
+        let timestamp = Timestamp::try_from(date_time)?;
+        let expected = Timestamp::with_ymd(2021, 2, 5)
+            .with_hms(16, 43, 51)
+            .with_nanoseconds(192_000_001)
+            .build_at_offset(-5 * 60)?;
+        assert!(timestamp.ion_eq(&expected));
+        assert_eq!(OffsetDateTime::try_from(timestamp)?, date_time);
+        Ok(())
+    }
+
+    #[test]
+    fn primitive_date_time_round_trip() -> IonResult<()> {
+        let date_time = datetime!(2021-02-05 16:43:51);
+        let timestamp = Timestamp::try_from(date_time)?;
+        assert_eq!(timestamp.offset(), None);
+        assert_eq!(timestamp.precision(), Precision::Second);
+        assert_eq!(PrimitiveDateTime::try_from(timestamp)?, date_time);
+        Ok(())
+    }
+
+    #[test]
+    fn lower_precision_timestamps_use_default_fields() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 2, 5).build()?;
+        assert_eq!(
+            PrimitiveDateTime::try_from(timestamp)?,
+            datetime!(2021-02-05 00:00)
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::year_zero(datetime!(0000-12-31 23:59 UTC))]
+    #[case::negative_year(datetime!(-0001-01-01 00:00 UTC))]
+    #[case::offset_seconds(datetime!(2021-02-05 16:43:51).assume_offset(offset!(+01:00:30)))]
+    #[case::offset_too_large(datetime!(2021-02-05 16:43:51 +25:00))]
+    fn unrepresentable_offset_date_times_are_rejected(#[case] date_time: OffsetDateTime) {
+        assert!(Timestamp::try_from(date_time).is_err());
+    }
+
+    #[test]
+    fn conversions_that_would_lose_information_are_rejected() -> IonResult<()> {
+        let sub_nanosecond = Timestamp::with_ymd(2021, 2, 5)
+            .with_hms(16, 43, 51)
+            .with_fractional_seconds(Decimal::new(1, -10))
+            .build_at_offset(0)?;
+        assert!(OffsetDateTime::try_from(sub_nanosecond).is_err());
+
+        // Trailing zeros beyond nanosecond precision do not lose any information.
+        let trailing_zeros = Timestamp::with_ymd(2021, 2, 5)
+            .with_hms(16, 43, 51)
+            .with_fractional_seconds(Decimal::new(5_000, -12))
+            .build_at_offset(0)?;
+        assert_eq!(
+            OffsetDateTime::try_from(trailing_zeros)?,
+            datetime!(2021-02-05 16:43:51.000_000_005 UTC)
+        );
+
+        let unknown_offset = Timestamp::with_ymd(2021, 2, 5).build()?;
+        assert!(OffsetDateTime::try_from(unknown_offset).is_err());
+        let known_offset = Timestamp::with_ymd(2021, 2, 5)
+            .with_hour_and_minute(0, 0)
+            .build_at_offset(60)?;
+        assert!(PrimitiveDateTime::try_from(known_offset).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+//! Conversions between [`Timestamp`] and the IANA time zones of the [`chrono_tz`] crate. These
+//! are only available when the `chrono-tz` feature is enabled.
+//!
+//! An Ion timestamp only records a fixed offset from UTC. Converting to or from a time zone
+//! resolves the zone's offset at that instant (accounting for daylight saving time) and stores
+//! it in the resulting `Timestamp`; the zone itself is not retained.
+
+use super::{FractionalSecondSetter, Precision, SecondSetter, Timestamp, TimestampBuilder};
+use super::{MAX_YEAR, MIN_YEAR};
+use crate::result::{illegal_operation, IonResult};
+use chrono::{Datelike, FixedOffset, LocalResult, Offset, TimeZone};
+use chrono_tz::Tz;
+
+/// Indicates which instant to use when a local date and time occurs more than once in a time
+/// zone. This happens when clocks are set back, such as at the end of daylight saving time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disambiguation {
+    /// Use the earlier of the two instants (the one with the larger offset, if clocks were set
+    /// back for the end of daylight saving time).
+    Earlier,
+    /// Use the later of the two instants.
+    Later,
+    /// Return an error.
+    Reject,
+}
+
+/// Converts a chrono offset to the whole number of minutes that an Ion timestamp stores.
+fn offset_minutes(offset: FixedOffset, zone: Tz) -> IonResult<i32> {
+    let seconds = offset.local_minus_utc();
+    if seconds % 60 != 0 {
+        // Historical local mean time offsets, like New York's -04:56:02 before 1883, are not in
+        // whole minutes.
+        return illegal_operation(format!(
+            "the offset of {zone} at this time ({offset}) cannot be represented in whole minutes"
+        ));
+    }
+    Ok(seconds / 60)
+}
+
+impl Timestamp {
+    /// Returns a Timestamp representing the same instant as this one in the local time of `zone`.
+    /// Its offset is the one that `zone` observed at that instant. The precision and fractional
+    /// seconds are unchanged.
+    ///
+    /// ```
+    /// # #[cfg(feature = "chrono-tz")]
+    /// # fn main() -> ion_rs::IonResult<()> {
+    /// use chrono_tz::America::New_York;
+    /// use ion_rs::Timestamp;
+    ///
+    /// let timestamp = Timestamp::with_ymd(2021, 7, 1).with_hms(12, 0, 0).build_at_offset(0)?;
+    /// let new_york = timestamp.to_time_zone(New_York)?;
+    /// assert_eq!(new_york.to_string(), "2021-07-01T08:00:00-04:00");
+    /// assert_eq!(new_york, timestamp);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "chrono-tz"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// Returns an error if this Timestamp does not have at least minute precision (timestamps with
+    /// a precision of a day or less cannot have an offset), if the zone's offset at this instant
+    /// is not a whole number of minutes, or if the local year would be outside the range 1-9999.
+    pub fn to_time_zone(&self, zone: Tz) -> IonResult<Timestamp> {
+        if self.precision < Precision::HourAndMinute {
+            return illegal_operation(format!(
+                "cannot convert {self} to a time zone; it has no time or offset"
+            ));
+        }
+        let offset = zone.offset_from_utc_datetime(&self.date_time).fix();
+        offset_minutes(offset, zone)?;
+        let local_year = (self.date_time + offset).year();
+        if !(MIN_YEAR..=MAX_YEAR).contains(&local_year) {
+            return illegal_operation(format!(
+                "Timestamp year '{local_year}' out of range (1-9999)"
+            ));
+        }
+        Ok(Timestamp {
+            offset: Some(offset),
+            ..self.clone()
+        })
+    }
+}
+
+impl TimestampBuilder {
+    /// Interprets the configured fields as a local time in `zone` and builds a Timestamp with the
+    /// offset that the zone observed at that time.
+    fn build_in_time_zone(
+        mut self,
+        zone: Tz,
+        disambiguation: Disambiguation,
+    ) -> IonResult<Timestamp> {
+        let local = self.naive_datetime()?;
+        let date_time = match zone.from_local_datetime(&local) {
+            LocalResult::Single(date_time) => date_time,
+            LocalResult::Ambiguous(earlier, later) => match disambiguation {
+                Disambiguation::Earlier => earlier,
+                Disambiguation::Later => later,
+                Disambiguation::Reject => {
+                    return illegal_operation(format!(
+                        "local time {local} occurs more than once in {zone}"
+                    ))
+                }
+            },
+            LocalResult::None => {
+                return illegal_operation(format!(
+                    "local time {local} does not exist in {zone}; it was skipped by a change in \
+                    offset"
+                ))
+            }
+        };
+        self.offset = Some(offset_minutes(date_time.offset().fix(), zone)?);
+        self.build()
+    }
+}
+
+impl SecondSetter {
+    /// Builds a Timestamp whose fields are a local time in the time zone `zone`. The
+    /// Timestamp's offset is the one that `zone` observed at that time; `disambiguation`
+    /// selects between the candidate offsets when the local time occurs more than once.
+    ///
+    /// This builds a Timestamp with minute precision; use
+    /// [`FractionalSecondSetter::build_in_time_zone`] for a more precise one.
+    ///
+    /// ```
+    /// # #[cfg(feature = "chrono-tz")]
+    /// # fn main() -> ion_rs::IonResult<()> {
+    /// use chrono_tz::Europe::Berlin;
+    /// use ion_rs::types::Disambiguation;
+    /// use ion_rs::Timestamp;
+    ///
+    /// let timestamp = Timestamp::with_ymd(2021, 7, 1)
+    ///     .with_hour_and_minute(12, 30)
+    ///     .build_in_time_zone(Berlin, Disambiguation::Reject)?;
+    /// assert_eq!(timestamp.to_string(), "2021-07-01T12:30+02:00");
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "chrono-tz"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// Returns an error if the local time does not exist in `zone` (for example, because it was
+    /// skipped at the start of daylight saving time) or if the zone's offset is not a whole number
+    /// of minutes.
+    pub fn build_in_time_zone(
+        self,
+        zone: Tz,
+        disambiguation: Disambiguation,
+    ) -> IonResult<Timestamp> {
+        self.builder.build_in_time_zone(zone, disambiguation)
+    }
+}
+
+impl FractionalSecondSetter {
+    /// Builds a Timestamp whose fields are a local time in the time zone `zone`. See
+    /// [`SecondSetter::build_in_time_zone`].
+    pub fn build_in_time_zone(
+        self,
+        zone: Tz,
+        disambiguation: Disambiguation,
+    ) -> IonResult<Timestamp> {
+        self.builder.build_in_time_zone(zone, disambiguation)
+    }
+}
+
+#[cfg(test)]
+mod time_zones_tests {
+    use super::*;
+    use crate::ion_data::IonEq;
+    use crate::types::Decimal;
+    use chrono_tz::America::New_York;
+    use chrono_tz::Europe::Berlin;
+    use chrono_tz::Pacific::Kiritimati;
+    use rstest::*;
+
+    #[rstest]
+    #[case::summer(
+        Timestamp::with_ymd(2021, 7, 1).with_hms(12, 0, 0).build_at_offset(0),
+        "2021-07-01T08:00:00-04:00"
+    )]
+    #[case::winter(
+        Timestamp::with_ymd(2021, 1, 1).with_hour_and_minute(12, 0).build_at_offset(60),
+        "2021-01-01T06:00-05:00"
+    )]
+    #[case::unknown_offset(
+        Timestamp::with_ymd(2021, 1, 1).with_hms(12, 0, 0).build_at_unknown_offset(),
+        "2021-01-01T07:00:00-05:00"
+    )]
+    #[case::fractional_seconds(
+        Timestamp::with_ymd(2021, 1, 1)
+            .with_hms(12, 0, 0)
+            .with_fractional_seconds(Decimal::new(1, -12))
+            .build_at_offset(0),
+        "2021-01-01T07:00:00.000000000001-05:00"
+    )]
+    fn to_time_zone(#[case] timestamp: IonResult<Timestamp>, #[case] expected: &str) {
+        let timestamp = timestamp.unwrap();
+        let converted = timestamp.to_time_zone(New_York).unwrap();
+        assert_eq!(converted.to_string(), expected);
+        assert_eq!(converted, timestamp);
+        assert_eq!(converted.precision(), timestamp.precision());
+    }
+
+    #[rstest]
+    #[case::day_precision(Timestamp::with_ymd(2021, 1, 1).build(), New_York)]
+    #[case::local_mean_time(
+        Timestamp::with_ymd(1800, 1, 1).with_hms(12, 0, 0).build_at_offset(0),
+        New_York
+    )]
+    #[case::local_year_out_of_range(
+        Timestamp::with_ymd(9999, 12, 31).with_hms(23, 0, 0).build_at_offset(0),
+        Kiritimati
+    )]
+    fn to_time_zone_rejects(#[case] timestamp: IonResult<Timestamp>, #[case] zone: Tz) {
+        assert!(timestamp.unwrap().to_time_zone(zone).is_err());
+    }
+
+    #[rstest]
+    #[case::summer(7, 1, 12, Disambiguation::Reject, "2021-07-01T12:30:15+02:00")]
+    #[case::winter(1, 1, 12, Disambiguation::Reject, "2021-01-01T12:30:15+01:00")]
+    #[case::ambiguous_earlier(10, 31, 2, Disambiguation::Earlier, "2021-10-31T02:30:15+02:00")]
+    #[case::ambiguous_later(10, 31, 2, Disambiguation::Later, "2021-10-31T02:30:15+01:00")]
+    fn build_in_time_zone(
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] hour: u32,
+        #[case] disambiguation: Disambiguation,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, month, day)
+            .with_hms(hour, 30, 15)
+            .build_in_time_zone(Berlin, disambiguation)?;
+        assert_eq!(timestamp.to_string(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::summer(7, 1, 12, Disambiguation::Reject, "2021-07-01T12:30+02:00")]
+    #[case::winter(1, 1, 12, Disambiguation::Reject, "2021-01-01T12:30+01:00")]
+    #[case::ambiguous_earlier(10, 31, 2, Disambiguation::Earlier, "2021-10-31T02:30+02:00")]
+    #[case::ambiguous_later(10, 31, 2, Disambiguation::Later, "2021-10-31T02:30+01:00")]
+    fn build_in_time_zone_at_minute_precision(
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] hour: u32,
+        #[case] disambiguation: Disambiguation,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, month, day)
+            .with_hour_and_minute(hour, 30)
+            .build_in_time_zone(Berlin, disambiguation)?;
+        assert_eq!(timestamp.to_string(), expected);
+        assert_eq!(timestamp.precision(), Precision::HourAndMinute);
+        Ok(())
+    }
+
+    #[test]
+    fn build_in_time_zone_preserves_precision() -> IonResult<()> {
+        let minutes = Timestamp::with_ymd(2021, 7, 1)
+            .with_hour_and_minute(12, 30)
+            .build_in_time_zone(Berlin, Disambiguation::Reject)?;
+        assert!(minutes.ion_eq(
+            &Timestamp::with_ymd(2021, 7, 1)
+                .with_hour_and_minute(12, 30)
+                .build_at_offset(120)?
+        ));
+        let millis = Timestamp::with_ymd(2021, 7, 1)
+            .with_hms(12, 30, 0)
+            .with_milliseconds(250)
+            .build_in_time_zone(Berlin, Disambiguation::Reject)?;
+        assert_eq!(millis.to_string(), "2021-07-01T12:30:00.250+02:00");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::ambiguous(10, 31, Disambiguation::Reject)]
+    #[case::skipped(3, 28, Disambiguation::Earlier)]
+    fn build_in_time_zone_rejects(
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] disambiguation: Disambiguation,
+    ) {
+        let result = Timestamp::with_ymd(2021, month, day)
+            .with_hms(2, 30, 0)
+            .build_in_time_zone(Berlin, disambiguation);
+        assert!(result.is_err());
+    }
+}
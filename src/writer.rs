@@ -1,6 +1,15 @@
+#[cfg(feature = "std")]
+use crate::io::Write;
 use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
 use crate::result::IonResult;
-use crate::types::{Decimal, Int, IonType, Timestamp};
+use crate::types::{Decimal, FloatWidth, Int, IonType, Timestamp};
+#[cfg(feature = "std")]
+use crate::{
+    element::writer::{Format, TextKind},
+    BinaryWriter, BinaryWriterBuilder, SharedSymbolTable, TextWriter, TextWriterBuilder,
+};
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
 
 /**
  * This trait captures the format-agnostic encoding functionality needed to write native Rust types
@@ -21,12 +30,16 @@ pub trait IonWriter {
     /// symbol values directly as text; otherwise, returns `false`.
     ///
     /// If this method returns `false`, passing a [crate::RawSymbolToken::Text] to the
-    /// [Self::set_annotations], [Self::set_field_name], or [Self::write_symbol] methods may result
-    /// in a panic.
+    /// [Self::set_annotations], [Self::set_field_name], or [Self::write_symbol] methods will
+    /// result in an `Err`.
     fn supports_text_symbol_tokens(&self) -> bool;
 
     /// Sets a list of annotations that will be applied to the next value that is written.
-    fn set_annotations<I, A>(&mut self, annotations: I)
+    ///
+    /// Returns an `Err` if any of the annotations cannot be written, for example because it is a
+    /// symbol ID that is not defined in the writer's symbol table. In that case, none of the
+    /// provided annotations will be applied.
+    fn set_annotations<I, A>(&mut self, annotations: I) -> IonResult<()>
     where
         A: AsRawSymbolTokenRef,
         I: IntoIterator<Item = A>;
@@ -41,6 +54,16 @@ pub trait IonWriter {
     /// Writes an Ion `integer` with the specified value to the output stream.
     fn write_i64(&mut self, value: i64) -> IonResult<()>;
 
+    /// Writes an Ion `integer` with the specified value to the output stream.
+    fn write_u64(&mut self, value: u64) -> IonResult<()> {
+        self.write_int(&Int::from(value))
+    }
+
+    /// Writes an Ion `integer` with the specified value to the output stream.
+    fn write_i128(&mut self, value: i128) -> IonResult<()> {
+        self.write_int(&Int::from(value))
+    }
+
     /// Writes an Ion `integer` with the specified value to the output stream.
     fn write_int(&mut self, value: &Int) -> IonResult<()>;
 
@@ -50,6 +73,16 @@ pub trait IonWriter {
     /// Writes an Ion `float` with the specified value to the output stream.
     fn write_f64(&mut self, value: f64) -> IonResult<()>;
 
+    /// Writes an Ion `float` with the specified value to the output stream using an encoding of
+    /// the specified [`FloatWidth`]. Formats that do not distinguish between float widths
+    /// write the value as they would for [`write_f64`](Self::write_f64).
+    fn write_float_with_width(&mut self, value: f64, width: FloatWidth) -> IonResult<()> {
+        match width {
+            FloatWidth::F32 => self.write_f32(value as f32),
+            FloatWidth::Empty | FloatWidth::F64 => self.write_f64(value),
+        }
+    }
+
     /// Writes an Ion `decimal` with the specified value to the output stream.
     fn write_decimal(&mut self, value: &Decimal) -> IonResult<()>;
 
@@ -68,6 +101,20 @@ pub trait IonWriter {
     /// Writes an Ion `blob` with the specified value to the output stream.
     fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()>;
 
+    /// Writes the specified [`Uuid`](uuid::Uuid) to the output stream as a 16-byte Ion `blob`.
+    #[cfg(feature = "uuid")]
+    fn write_uuid_blob(&mut self, value: &uuid::Uuid) -> IonResult<()> {
+        self.write_blob(value.as_bytes())
+    }
+
+    /// Writes the specified [`Uuid`](uuid::Uuid) to the output stream as an Ion `string` in its
+    /// lowercase, hyphenated form (for example, `"67e55044-10b1-426f-9247-bb680e5fe0c8"`).
+    #[cfg(feature = "uuid")]
+    fn write_uuid_string(&mut self, value: &uuid::Uuid) -> IonResult<()> {
+        let mut buffer = uuid::Uuid::encode_buffer();
+        self.write_string(value.hyphenated().encode_lower(&mut buffer))
+    }
+
     /// Starts a new Ion container with the specified type.
     /// The only valid IonType values are:
     /// * [IonType::List]
@@ -76,10 +123,12 @@ pub trait IonWriter {
     /// Passing any other IonType will result in an `Err`.
     fn step_in(&mut self, container_type: IonType) -> IonResult<()>;
 
-    /// Sets the current field name to `name`. If the TextWriter is currently positioned inside
-    /// of a struct, the field name will be written before the next value. Otherwise, it will be
-    /// ignored.
-    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A);
+    /// Sets the current field name to `name`. The field name will be written before the next value.
+    ///
+    /// Returns an `Err` if the writer is not positioned inside of a struct or if `name` cannot be
+    /// written, for example because it is a symbol ID that is not defined in the writer's
+    /// symbol table.
+    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) -> IonResult<()>;
 
     /// If the writer is positioned at the top level, returns `None`. Otherwise, returns
     /// `Some(_)` with the parent container's [IonType].
@@ -128,3 +177,314 @@ pub trait IonWriter {
     /// behavior or invalid data. It is not recommended for most use cases.
     fn output_mut(&mut self) -> &mut Self::Output;
 }
+
+#[cfg(feature = "std")]
+/// Configures and constructs a [`Writer`] whose [`Format`] is selected at runtime.
+///
+/// ```
+/// use ion_rs::element::writer::{ElementWriter, Format, TextKind};
+/// use ion_rs::element::Element;
+/// use ion_rs::{IonResult, IonWriter, WriterBuilder};
+/// # fn main() -> IonResult<()> {
+/// # let use_binary = false;
+/// let format = if use_binary {
+///     Format::Binary
+/// } else {
+///     Format::Text(TextKind::Compact)
+/// };
+/// let mut writer = WriterBuilder::new(format).build(Vec::new())?;
+/// writer.write_element(&Element::read_one("{foo: [1, 2, 3]}")?)?;
+/// writer.flush()?;
+/// assert_eq!(writer.output().as_slice(), b"{foo: [1, 2, 3]}".as_slice());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WriterBuilder {
+    format: Format,
+    imports: Vec<SharedSymbolTable>,
+}
+
+#[cfg(feature = "std")]
+impl WriterBuilder {
+    pub fn new(format: Format) -> WriterBuilder {
+        WriterBuilder {
+            format,
+            imports: Vec::new(),
+        }
+    }
+
+    /// Imports the symbols of `table` into the stream's local symbol table when writing binary
+    /// Ion. See [`BinaryWriterBuilder::with_import`]. Text writers always write symbol text
+    /// directly, so this has no effect on them.
+    pub fn with_import(mut self, table: SharedSymbolTable) -> WriterBuilder {
+        self.imports.push(table);
+        self
+    }
+
+    /// Returns the [`Format`] of the writers constructed by this builder.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Constructs a new [`Writer`] that writes values to the provided io::Write implementation.
+    pub fn build<W: Write>(self, sink: W) -> IonResult<Writer<W>> {
+        let writer = match self.format {
+            Format::Text(kind) => {
+                let builder = match kind {
+                    TextKind::Compact => TextWriterBuilder::new(),
+                    TextKind::Lines => TextWriterBuilder::lines(),
+                    TextKind::Pretty => TextWriterBuilder::pretty(),
+                };
+                Writer::Text(builder.build(sink)?)
+            }
+            Format::Binary => {
+                let builder = self
+                    .imports
+                    .into_iter()
+                    .fold(BinaryWriterBuilder::new(), BinaryWriterBuilder::with_import);
+                Writer::Binary(builder.build(sink)?)
+            }
+        };
+        Ok(writer)
+    }
+}
+
+#[cfg(feature = "std")]
+/// An [`IonWriter`] that writes either text or binary Ion, as selected at runtime by the
+/// [`Format`] passed to a [`WriterBuilder`]. This allows a single code path to handle all of the
+/// formats without being generic over the writer type.
+// A program typically has only a handful of writers, so the size difference between the
+// variants is not worth an extra allocation and indirection on every write.
+#[allow(clippy::large_enum_variant)]
+pub enum Writer<W: Write> {
+    Text(TextWriter<W>),
+    Binary(BinaryWriter<W>),
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> Writer<W> {
+    /// Returns the [`Format`] that this writer produces.
+    pub fn format(&self) -> Format {
+        match self {
+            Writer::Text(writer) => Format::Text(writer.text_kind()),
+            Writer::Binary(_) => Format::Binary,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+/// Calls the same method on whichever writer `$self` holds.
+macro_rules! dispatch {
+    ($self:expr, $writer:ident => $call:expr) => {
+        match $self {
+            Writer::Text($writer) => $call,
+            Writer::Binary($writer) => $call,
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> IonWriter for Writer<W> {
+    type Output = W;
+
+    fn ion_version(&self) -> (u8, u8) {
+        dispatch!(self, writer => writer.ion_version())
+    }
+
+    fn write_ion_version_marker(&mut self, major: u8, minor: u8) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_ion_version_marker(major, minor))
+    }
+
+    fn supports_text_symbol_tokens(&self) -> bool {
+        dispatch!(self, writer => writer.supports_text_symbol_tokens())
+    }
+
+    fn set_annotations<I, A>(&mut self, annotations: I) -> IonResult<()>
+    where
+        A: AsRawSymbolTokenRef,
+        I: IntoIterator<Item = A>,
+    {
+        dispatch!(self, writer => writer.set_annotations(annotations))
+    }
+
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_null(ion_type))
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_bool(value))
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_i64(value))
+    }
+
+    fn write_u64(&mut self, value: u64) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_u64(value))
+    }
+
+    fn write_i128(&mut self, value: i128) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_i128(value))
+    }
+
+    fn write_int(&mut self, value: &Int) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_int(value))
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_f32(value))
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_f64(value))
+    }
+
+    fn write_float_with_width(&mut self, value: f64, width: FloatWidth) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_float_with_width(value, width))
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_decimal(value))
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_timestamp(value))
+    }
+
+    fn write_symbol<A: AsRawSymbolTokenRef>(&mut self, value: A) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_symbol(value))
+    }
+
+    fn write_string<A: AsRef<str>>(&mut self, value: A) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_string(value))
+    }
+
+    fn write_clob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_clob(value))
+    }
+
+    fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        dispatch!(self, writer => writer.write_blob(value))
+    }
+
+    fn step_in(&mut self, container_type: IonType) -> IonResult<()> {
+        dispatch!(self, writer => writer.step_in(container_type))
+    }
+
+    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) -> IonResult<()> {
+        dispatch!(self, writer => writer.set_field_name(name))
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        dispatch!(self, writer => writer.parent_type())
+    }
+
+    fn depth(&self) -> usize {
+        dispatch!(self, writer => writer.depth())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        dispatch!(self, writer => writer.step_out())
+    }
+
+    fn flush(&mut self) -> IonResult<()> {
+        dispatch!(self, writer => writer.flush())
+    }
+
+    fn output(&self) -> &Self::Output {
+        dispatch!(self, writer => writer.output())
+    }
+
+    fn output_mut(&mut self) -> &mut Self::Output {
+        dispatch!(self, writer => writer.output_mut())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod writer_tests {
+    use super::*;
+    use crate::element::writer::ElementWriter;
+    use crate::element::Element;
+    use crate::ion_data::IonEq;
+    use crate::types::ImportLocation;
+    use crate::{IonError, RawBinaryWriter, RawTextWriter};
+    use rstest::*;
+
+    #[test]
+    fn writers_are_send_and_sync_when_their_output_is() {
+        fn assert_send_and_sync<T: Send + Sync>() {}
+        assert_send_and_sync::<Writer<Vec<u8>>>();
+        assert_send_and_sync::<TextWriter<Vec<u8>>>();
+        assert_send_and_sync::<BinaryWriter<Vec<u8>>>();
+        assert_send_and_sync::<RawTextWriter<Vec<u8>>>();
+        assert_send_and_sync::<RawBinaryWriter<Vec<u8>>>();
+    }
+
+    const ION_DATA: &str = r#"
+        null true 0 1e0 2.0 2022T foo::bar "baz" (a b c) [1, 2, 3] {foo: true, bar: [false]}
+    "#;
+
+    #[rstest]
+    #[case::compact(Format::Text(TextKind::Compact))]
+    #[case::lines(Format::Text(TextKind::Lines))]
+    #[case::pretty(Format::Text(TextKind::Pretty))]
+    #[case::binary(Format::Binary)]
+    fn write_elements(#[case] format: Format) -> IonResult<()> {
+        let elements = Element::read_all(ION_DATA)?;
+        let mut writer = WriterBuilder::new(format).build(Vec::new())?;
+        assert_eq!(writer.format(), format);
+        writer.write_elements(&elements)?;
+        writer.flush()?;
+        let output = writer.output();
+        assert_eq!(
+            output.starts_with(&[0xE0, 0x01, 0x00, 0xEA]),
+            format == Format::Binary
+        );
+        assert!(Element::read_all(output)?.ion_eq(&elements));
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_with_imports() -> IonResult<()> {
+        let table = SharedSymbolTable::new("shared".to_string(), 1, vec![Some("foo".to_string())])?;
+        let mut writer = WriterBuilder::new(Format::Binary)
+            .with_import(table)
+            .build(Vec::new())?;
+        writer.write_symbol("foo")?;
+        writer.flush()?;
+        // Without a catalog, the reader cannot resolve the text of the imported symbol.
+        let element = Element::read_one(writer.output())?;
+        let location = element.as_symbol().unwrap().import_location();
+        assert_eq!(location, Some(&ImportLocation::new("shared", 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn text_writer_rejects_symbol_handles() -> IonResult<()> {
+        let mut binary_writer = BinaryWriterBuilder::new().build(Vec::new())?;
+        let handle = binary_writer.register_symbol("foo");
+        let mut writer = WriterBuilder::new(Format::Text(TextKind::Compact)).build(Vec::new())?;
+        assert!(matches!(
+            writer.set_annotations([handle]),
+            Err(IonError::IllegalOperation { .. })
+        ));
+        assert!(matches!(
+            writer.write_symbol(handle),
+            Err(IonError::IllegalOperation { .. })
+        ));
+        writer.step_in(IonType::Struct)?;
+        assert!(matches!(
+            writer.set_field_name(handle),
+            Err(IonError::IllegalOperation { .. })
+        ));
+        writer.step_out()?;
+        writer.flush()?;
+        // Nothing that was rejected was written.
+        assert_eq!(
+            Element::read_all(writer.output())?,
+            Element::read_all("{}")?
+        );
+        Ok(())
+    }
+}
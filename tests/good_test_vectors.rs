@@ -29,10 +29,6 @@ const GOOD_TEST_FILES_SKIP_LIST: &[&str] = &[
     "ion-tests/iontestdata/good/valueFollowedByNopPad.10n",
     "ion-tests/iontestdata/good/valuePrecededByNopPad.10n",
     "ion-tests/iontestdata/good/valueBetweenNopPads.10n",
-    // Timestamp support
-    "ion-tests/iontestdata/good/equivs/timestampSuperfluousOffset.10n",
-    "ion-tests/iontestdata/good/timestamp/timestamp2011-02.10n",
-    "ion-tests/iontestdata/good/timestamp/timestamp2011.10n",
     // Integer representation limits
     "ion-tests/iontestdata/good/equivs/intsLargeNegative1.10n",
     "ion-tests/iontestdata/good/equivs/intsLargeNegative2.10n",
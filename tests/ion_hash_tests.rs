@@ -320,6 +320,7 @@ pub enum IonHashTestError {
 
 mod unknown_symbol_text_tests {
     use super::*;
+    use ion_rs::types::ImportLocation;
     use ion_rs::Symbol;
 
     #[test]
@@ -361,4 +362,23 @@ mod unknown_symbol_text_tests {
         let actual_string = format!("{:02x?}", without_trailing_zeros(&digest[..]));
         assert_eq!(expected_string, actual_string)
     }
+
+    #[test]
+    fn test_unknown_symbol_text_with_import_location() {
+        let location = ImportLocation::new("foo", 2);
+        let symbol = Element::from(Symbol::with_import_location(location));
+        let digest = IdentityDigest::hash_element(&symbol).unwrap();
+
+        // The representation is the table name ("foo") followed by the SID as a UInt (2).
+        let expected_string = format!("{:02x?}", &[0x0b, 0x71, 0x66, 0x6f, 0x6f, 0x02, 0x0e]);
+        let actual_string = format!("{:02x?}", without_trailing_zeros(&digest[..]));
+        assert_eq!(expected_string, actual_string);
+
+        let other_location =
+            Element::from(Symbol::with_import_location(ImportLocation::new("foo", 3)));
+        assert_ne!(
+            IdentityDigest::hash_element(&other_location).unwrap()[..],
+            digest[..]
+        );
+    }
 }